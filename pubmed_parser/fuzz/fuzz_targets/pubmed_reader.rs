@@ -0,0 +1,12 @@
+#![no_main]
+use libfuzzer_sys::fuzz_target;
+use pubmed_parser::PubmedReader;
+
+// The reader must return errors on malformed documents, never panic.
+fuzz_target!(|data: &[u8]| {
+    for lenient in [false, true] {
+        PubmedReader::new(data, "fuzz")
+            .with_lenient(lenient)
+            .for_each(drop);
+    }
+});
@@ -1,115 +1,937 @@
+use crate::text_normalization::TextNormalization;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
 pub struct Date {
     pub(crate) year: Option<u16>,
     pub(crate) month: Option<String>,
     pub(crate) day: Option<u8>,
+    pub(crate) season: Option<String>,
+    pub(crate) medline_date: Option<String>,
 }
 
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
 pub struct JournalIssue {
+    pub(crate) cited_medium: Option<String>,
     pub(crate) volume: Option<String>,
     pub(crate) issue: Option<String>,
     pub(crate) pubblication_date: Date,
 }
 
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
 pub struct Journal {
     pub(crate) issn: Option<String>,
+    pub(crate) issn_type: Option<String>,
     pub(crate) title: String,
     pub(crate) iso_abbreviation: Option<String>,
     pub(crate) journal_issue: JournalIssue,
 }
 
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
 pub struct Chemical {
     pub(crate) registry_number: String,
     pub(crate) name_of_substance: String,
     pub(crate) code: String,
 }
 
-#[derive(Debug)]
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
 pub struct MeshTopic {
     pub(crate) name: String,
     pub(crate) code: String,
     pub(crate) is_major_topic: bool,
 }
 
-#[derive(Debug)]
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
 pub struct Keyword {
     pub(crate) name: String,
     pub(crate) is_major_topic: bool,
+    pub(crate) owner: String,
 }
 
-#[derive(Debug)]
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+pub struct MedlineJournalInfo {
+    pub(crate) country: Option<String>,
+    pub(crate) medline_ta: Option<String>,
+    pub(crate) nlm_unique_id: Option<String>,
+    pub(crate) issn_linking: Option<String>,
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
 pub struct Mesh {
     pub(crate) descriptor: MeshTopic,
-    pub(crate) qualifier: Option<MeshTopic>,
+    pub(crate) qualifiers: Vec<MeshTopic>,
 }
 
-#[derive(Debug)]
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
 pub struct SupplMesh {
     pub(crate) code: String,
     pub(crate) name: String,
     pub(crate) mesh_type: String,
 }
 
-#[derive(Debug)]
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+pub struct PublicationType {
+    pub(crate) code: String,
+    pub(crate) name: String,
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
 pub struct Abstract {
     pub(crate) text: String,
     pub(crate) abstract_type: Option<String>,
     pub(crate) language: Option<String>,
 }
-#[derive(Debug)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct ArticleId {
     pub(crate) id_type: String,
     pub(crate) value: String,
 }
 
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+pub struct Reference {
+    pub(crate) citation: Option<String>,
+    pub(crate) article_ids: Vec<ArticleId>,
+}
+
+impl Reference {
+    pub fn citation(&self) -> Option<&str> {
+        self.citation.as_deref()
+    }
+
+    pub fn article_ids(&self) -> &[ArticleId] {
+        &self.article_ids
+    }
+
+    /// Returns the value of the first identifier of the provided type.
+    pub fn article_id(&self, id_type: &str) -> Option<&str> {
+        self.article_ids
+            .iter()
+            .find(|article_id| article_id.id_type == id_type)
+            .map(|article_id| article_id.value.as_str())
+    }
+
+    /// Returns the PMID of the cited article, if it is a well-formed one.
+    pub fn pubmed_id(&self) -> Option<u32> {
+        self.article_id("pubmed")?.trim().parse::<u32>().ok()
+    }
+
+    pub fn doi(&self) -> Option<&str> {
+        self.article_id("doi")
+    }
+
+    pub fn pmcid(&self) -> Option<&str> {
+        self.article_id("pmc")
+    }
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+pub struct Pagination {
+    pub(crate) start_page: Option<String>,
+    pub(crate) end_page: Option<String>,
+    pub(crate) medline_pgn: Option<String>,
+}
+
+impl Pagination {
+    /// Returns the first page range of the MedlinePgn, as in `117-26` for `117-26; discussion 127`.
+    fn medline_pgn_range(&self) -> Option<(&str, Option<&str>)> {
+        let range = self
+            .medline_pgn
+            .as_deref()?
+            .split([';', ','])
+            .next()?
+            .trim();
+        if range.is_empty() {
+            return None;
+        }
+        Some(match range.split_once('-') {
+            Some((start, end)) => (start.trim(), Some(end.trim())),
+            None => (range, None),
+        })
+    }
+
+    /// Returns the start page, falling back to the one within the MedlinePgn.
+    pub fn start_page(&self) -> Option<&str> {
+        self.start_page
+            .as_deref()
+            .or_else(|| self.medline_pgn_range().map(|(start, _)| start))
+    }
+
+    /// Returns the end page, falling back to the one within the MedlinePgn.
+    ///
+    /// The MedlinePgn abbreviates the end page by omitting the digits it shares
+    /// with the start page, as in `117-26`, which are restored here.
+    pub fn end_page(&self) -> Option<String> {
+        if let Some(end_page) = self.end_page.as_ref() {
+            return Some(end_page.clone());
+        }
+        let (start, end) = self.medline_pgn_range()?;
+        let end = end.filter(|end| !end.is_empty())?;
+        if end.len() < start.len()
+            && start.is_ascii()
+            && end.chars().all(|character| character.is_ascii_digit())
+        {
+            Some(format!("{}{}", &start[..start.len() - end.len()], end))
+        } else {
+            Some(end.to_string())
+        }
+    }
+
+    pub fn medline_pgn(&self) -> Option<&str> {
+        self.medline_pgn.as_deref()
+    }
+}
+
+#[derive(Debug)]
 pub struct Node {
     pub(crate) node_name: String,
     pub(crate) node_type: String,
     pub(crate) description: String,
 }
 
+#[derive(Debug)]
 pub struct Edge {
     pub(crate) subject: String,
+    pub(crate) subject_type: String,
     pub(crate) object: String,
+    pub(crate) object_type: String,
     pub(crate) edge_type: String,
 }
 
+impl Date {
+    pub fn year(&self) -> Option<u16> {
+        self.year
+    }
+
+    pub fn month(&self) -> Option<&str> {
+        self.month.as_deref()
+    }
+
+    pub fn day(&self) -> Option<u8> {
+        self.day
+    }
+
+    /// Returns the season of the date, as in `Spring`, given instead of its month.
+    pub fn season(&self) -> Option<&str> {
+        self.season.as_deref()
+    }
+
+    /// Returns the free-text date given when it does not fit the other fields, as in `1998 Dec-1999 Jan`.
+    pub fn medline_date(&self) -> Option<&str> {
+        self.medline_date.as_deref()
+    }
+}
+
+impl JournalIssue {
+    /// Returns the medium the article is cited from, `Internet` or `Print`.
+    pub fn cited_medium(&self) -> Option<&str> {
+        self.cited_medium.as_deref()
+    }
+
+    pub fn volume(&self) -> Option<&str> {
+        self.volume.as_deref()
+    }
+
+    pub fn issue(&self) -> Option<&str> {
+        self.issue.as_deref()
+    }
+
+    pub fn pubblication_date(&self) -> &Date {
+        &self.pubblication_date
+    }
+}
+
+impl Journal {
+    pub fn issn(&self) -> Option<&str> {
+        self.issn.as_deref()
+    }
+
+    /// Returns the type of the ISSN, `Electronic` or `Print`.
+    pub fn issn_type(&self) -> Option<&str> {
+        self.issn_type.as_deref()
+    }
+
+    pub fn title(&self) -> &str {
+        &self.title
+    }
+
+    pub fn iso_abbreviation(&self) -> Option<&str> {
+        self.iso_abbreviation.as_deref()
+    }
+
+    pub fn journal_issue(&self) -> &JournalIssue {
+        &self.journal_issue
+    }
+}
+
+impl Chemical {
+    pub fn registry_number(&self) -> &str {
+        &self.registry_number
+    }
+
+    pub fn name_of_substance(&self) -> &str {
+        &self.name_of_substance
+    }
+
+    pub fn code(&self) -> &str {
+        &self.code
+    }
+}
+
+impl MeshTopic {
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn code(&self) -> &str {
+        &self.code
+    }
+
+    pub fn is_major_topic(&self) -> bool {
+        self.is_major_topic
+    }
+}
+
+impl Keyword {
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn is_major_topic(&self) -> bool {
+        self.is_major_topic
+    }
+
+    /// Returns the `Owner` of the keyword list, such as `PIP` or `KIE`.
+    pub fn owner(&self) -> &str {
+        &self.owner
+    }
+}
+
+impl MedlineJournalInfo {
+    pub fn country(&self) -> Option<&str> {
+        self.country.as_deref()
+    }
+
+    /// Returns the abbreviated title of the journal used by MEDLINE, as in `Biochem Med`.
+    pub fn medline_ta(&self) -> Option<&str> {
+        self.medline_ta.as_deref()
+    }
+
+    pub fn nlm_unique_id(&self) -> Option<&str> {
+        self.nlm_unique_id.as_deref()
+    }
+
+    pub fn issn_linking(&self) -> Option<&str> {
+        self.issn_linking.as_deref()
+    }
+}
+
+impl Mesh {
+    pub fn descriptor(&self) -> &MeshTopic {
+        &self.descriptor
+    }
+
+    pub fn qualifiers(&self) -> &[MeshTopic] {
+        &self.qualifiers
+    }
+}
+
+impl SupplMesh {
+    pub fn code(&self) -> &str {
+        &self.code
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn mesh_type(&self) -> &str {
+        &self.mesh_type
+    }
+}
+
+impl PublicationType {
+    pub fn code(&self) -> &str {
+        &self.code
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+}
+
+impl Abstract {
+    pub fn text(&self) -> &str {
+        &self.text
+    }
+
+    pub fn abstract_type(&self) -> Option<&str> {
+        self.abstract_type.as_deref()
+    }
+
+    pub fn language(&self) -> Option<&str> {
+        self.language.as_deref()
+    }
+}
+
+impl ArticleId {
+    pub fn id_type(&self) -> &str {
+        &self.id_type
+    }
+
+    pub fn value(&self) -> &str {
+        &self.value
+    }
+}
+
+impl Node {
+    pub fn node_name(&self) -> &str {
+        &self.node_name
+    }
+
+    pub fn node_type(&self) -> &str {
+        &self.node_type
+    }
+
+    pub fn description(&self) -> &str {
+        &self.description
+    }
+}
+
+impl Edge {
+    pub fn subject(&self) -> &str {
+        &self.subject
+    }
+
+    /// Returns the type of the node of the subject.
+    pub fn subject_type(&self) -> &str {
+        &self.subject_type
+    }
+
+    pub fn object(&self) -> &str {
+        &self.object
+    }
+
+    /// Returns the type of the node of the object.
+    pub fn object_type(&self) -> &str {
+        &self.object_type
+    }
+
+    pub fn edge_type(&self) -> &str {
+        &self.edge_type
+    }
+}
+
+#[derive(Debug)]
+pub struct Book {
+    pub(crate) publisher_name: Option<String>,
+    pub(crate) publisher_location: Option<String>,
+    pub(crate) title: Option<String>,
+    pub(crate) pubblication_date: Option<Date>,
+}
+
+impl Book {
+    pub fn publisher_name(&self) -> Option<&str> {
+        self.publisher_name.as_deref()
+    }
+
+    pub fn publisher_location(&self) -> Option<&str> {
+        self.publisher_location.as_deref()
+    }
+
+    pub fn title(&self) -> Option<&str> {
+        self.title.as_deref()
+    }
+
+    pub fn pubblication_date(&self) -> Option<&Date> {
+        self.pubblication_date.as_ref()
+    }
+}
+
+/// A `PubmedBookArticle`, such as a chapter of the NCBI Bookshelf.
+#[derive(Debug)]
+pub struct BookArticle {
+    pub(crate) pubmed_id: u32,
+    pub(crate) pmid_version: u16,
+    pub(crate) article_ids: Vec<ArticleId>,
+    pub(crate) book: Book,
+    pub(crate) location_label: Option<String>,
+    pub(crate) title: Option<String>,
+    pub(crate) vernacular_title: Option<String>,
+    pub(crate) pagination: Option<Pagination>,
+    pub(crate) languages: Vec<String>,
+    pub(crate) abstract_text: Option<Abstract>,
+    pub(crate) sections: Vec<String>,
+    pub(crate) contribution_date: Option<Date>,
+    pub(crate) revision_date: Option<Date>,
+    pub(crate) citation_string: Option<String>,
+}
+
+impl BookArticle {
+    pub fn pubmed_id(&self) -> u32 {
+        self.pubmed_id
+    }
+
+    pub fn pmid_version(&self) -> u16 {
+        self.pmid_version
+    }
+
+    /// Returns the identifiers of both the `BookDocument` and the `PubmedBookData`.
+    pub fn article_ids(&self) -> &[ArticleId] {
+        &self.article_ids
+    }
+
+    pub fn book(&self) -> &Book {
+        &self.book
+    }
+
+    pub fn location_label(&self) -> Option<&str> {
+        self.location_label.as_deref()
+    }
+
+    pub fn title(&self) -> Option<&str> {
+        self.title.as_deref()
+    }
+
+    pub fn vernacular_title(&self) -> Option<&str> {
+        self.vernacular_title.as_deref()
+    }
+
+    pub fn pagination(&self) -> Option<&Pagination> {
+        self.pagination.as_ref()
+    }
+
+    pub fn languages(&self) -> &[String] {
+        &self.languages
+    }
+
+    pub fn abstract_text(&self) -> Option<&Abstract> {
+        self.abstract_text.as_ref()
+    }
+
+    /// Returns the titles of the sections, nested sections included.
+    pub fn sections(&self) -> &[String] {
+        &self.sections
+    }
+
+    pub fn contribution_date(&self) -> Option<&Date> {
+        self.contribution_date.as_ref()
+    }
+
+    pub fn revision_date(&self) -> Option<&Date> {
+        self.revision_date.as_ref()
+    }
+
+    /// Returns how the book article is cited, as in `Seattle (WA): University of Washington; 1993`.
+    pub fn citation_string(&self) -> Option<&str> {
+        self.citation_string.as_deref()
+    }
+
+    /// Applies the provided normalization to every textual field of the book article.
+    pub fn normalize_text(&mut self, normalization: &TextNormalization) {
+        if normalization.is_noop() {
+            return;
+        }
+        for text in [
+            self.book.publisher_name.as_mut(),
+            self.book.publisher_location.as_mut(),
+            self.book.title.as_mut(),
+            self.location_label.as_mut(),
+            self.title.as_mut(),
+            self.vernacular_title.as_mut(),
+            self.citation_string.as_mut(),
+        ]
+        .into_iter()
+        .flatten()
+        {
+            normalization.normalize_in_place(text);
+        }
+        if let Some(abstract_text) = self.abstract_text.as_mut() {
+            normalization.normalize_in_place(&mut abstract_text.text);
+        }
+        for section in self.sections.iter_mut() {
+            normalization.normalize_in_place(section);
+        }
+    }
+}
+
+/// A citation removed from PubMed by a `DeleteCitation` block of an update file.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct DeletedCitation {
+    pub(crate) pubmed_id: u32,
+    pub(crate) version: u16,
+}
+
+impl DeletedCitation {
+    pub fn pubmed_id(&self) -> u32 {
+        self.pubmed_id
+    }
+
+    pub fn version(&self) -> u16 {
+        self.version
+    }
+}
+
+/// How the description of the Paper node handles articles not written in English.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum NonEnglishDescription {
+    /// The description is emitted as for any other article.
+    #[default]
+    Keep,
+    /// The description is left empty.
+    Omit,
+    /// The description is prefixed by the languages of the article, as in `[fre] ...`.
+    Tag,
+}
+
+/// Options of the nodes and edges derived from an article.
+///
+/// References are always emitted when they have a PMID. The references
+/// without one can also be emitted by DOI, by PMCID or, failing that, as
+/// unresolved reference nodes described by their citation string.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ExportOptions {
+    non_english_description: NonEnglishDescription,
+    references_by_doi: bool,
+    references_by_pmcid: bool,
+    unresolved_references: bool,
+}
+
+impl ExportOptions {
+    pub fn new() -> Self {
+        ExportOptions::default()
+    }
+
+    pub fn with_non_english_description(
+        mut self,
+        non_english_description: NonEnglishDescription,
+    ) -> Self {
+        self.non_english_description = non_english_description;
+        self
+    }
+
+    pub fn with_references_by_doi(mut self, references_by_doi: bool) -> Self {
+        self.references_by_doi = references_by_doi;
+        self
+    }
+
+    pub fn with_references_by_pmcid(mut self, references_by_pmcid: bool) -> Self {
+        self.references_by_pmcid = references_by_pmcid;
+        self
+    }
+
+    pub fn with_unresolved_references(mut self, unresolved_references: bool) -> Self {
+        self.unresolved_references = unresolved_references;
+        self
+    }
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
 pub struct Article {
+    pub(crate) status: Option<String>,
+    pub(crate) owner: Option<String>,
+    pub(crate) indexing_method: Option<String>,
+    pub(crate) citation_subsets: Vec<String>,
     pub(crate) completion_date: Option<Date>,
     pub(crate) revision_date: Option<Date>,
     pub(crate) pubmed_id: u32,
+    pub(crate) pmid_version: u16,
     pub(crate) article_ids: Vec<ArticleId>,
+    pub(crate) elocation_ids: Vec<ArticleId>,
+    pub(crate) pagination: Option<Pagination>,
+    pub(crate) pub_model: Option<String>,
     pub(crate) journal: Journal,
+    pub(crate) medline_journal_info: Option<MedlineJournalInfo>,
     pub(crate) title: Option<String>,
+    pub(crate) vernacular_title: Option<String>,
+    pub(crate) languages: Vec<String>,
+    pub(crate) publication_types: Vec<PublicationType>,
     pub(crate) abstract_text: Option<Abstract>,
     pub(crate) other_abstract_texts: Vec<Abstract>,
     pub(crate) chemical_list: Vec<Chemical>,
     pub(crate) gene_symbol_list: Vec<String>,
     pub(crate) mesh_list: Vec<Mesh>,
     pub(crate) suppl_mesh_list: Vec<SupplMesh>,
-    pub(crate) references: Vec<usize>,
+    pub(crate) references: Vec<Reference>,
     pub(crate) keywords: Vec<Keyword>,
+    pub(crate) publication_status: Option<String>,
+    pub(crate) extras: BTreeMap<String, Vec<String>>,
 }
 
 impl Article {
+    /// Returns the `Status` of the `MedlineCitation`, such as `MEDLINE` or `In-Process`.
+    pub fn status(&self) -> Option<&str> {
+        self.status.as_deref()
+    }
+
+    /// Returns the `Owner` of the `MedlineCitation`, such as `NLM`.
+    pub fn owner(&self) -> Option<&str> {
+        self.owner.as_deref()
+    }
+
+    /// Returns the `IndexingMethod` of the `MedlineCitation`, such as `Automated` or `Curated`.
+    ///
+    /// The attribute is missing when the MeSH terms were assigned by human indexers.
+    pub fn indexing_method(&self) -> Option<&str> {
+        self.indexing_method.as_deref()
+    }
+
+    /// Returns the citation subsets of the article, such as `AIM` and `IM`.
+    pub fn citation_subsets(&self) -> &[String] {
+        &self.citation_subsets
+    }
+
+    /// Returns whether the MeSH indexing of the article has been completed.
+    pub fn is_mesh_indexed(&self) -> bool {
+        matches!(self.status(), Some("MEDLINE") | Some("OLDMEDLINE"))
+    }
+
+    /// Returns whether the MeSH terms of the article were assigned by an automated process.
+    pub fn is_automatically_indexed(&self) -> bool {
+        self.indexing_method() == Some("Automated")
+    }
+
+    pub fn completion_date(&self) -> Option<&Date> {
+        self.completion_date.as_ref()
+    }
+
+    pub fn revision_date(&self) -> Option<&Date> {
+        self.revision_date.as_ref()
+    }
+
+    pub fn pubmed_id(&self) -> u32 {
+        self.pubmed_id
+    }
+
+    /// Returns the version of the PMID, as provided by its `Version` attribute.
+    pub fn pmid_version(&self) -> u16 {
+        self.pmid_version
+    }
+
+    /// Returns the identifiers of the article.
+    ///
+    /// The identifiers of the `ArticleIdList` are followed by the ones only
+    /// provided by the `ELocationID` elements.
+    pub fn article_ids(&self) -> &[ArticleId] {
+        &self.article_ids
+    }
+
+    /// Returns the identifiers provided by the `ELocationID` elements.
+    pub fn elocation_ids(&self) -> &[ArticleId] {
+        &self.elocation_ids
+    }
+
+    /// Returns the value of the first identifier of the provided type.
+    pub fn article_id(&self, id_type: &str) -> Option<&str> {
+        self.article_ids
+            .iter()
+            .find(|article_id| article_id.id_type == id_type)
+            .map(|article_id| article_id.value.as_str())
+    }
+
+    pub fn doi(&self) -> Option<&str> {
+        self.article_id("doi")
+    }
+
+    pub fn pii(&self) -> Option<&str> {
+        self.article_id("pii")
+    }
+
+    pub fn pagination(&self) -> Option<&Pagination> {
+        self.pagination.as_ref()
+    }
+
+    /// Returns the `PubModel` of the article, such as `Print` or `Electronic`.
+    pub fn pub_model(&self) -> Option<&str> {
+        self.pub_model.as_deref()
+    }
+
+    pub fn journal(&self) -> &Journal {
+        &self.journal
+    }
+
+    pub fn medline_journal_info(&self) -> Option<&MedlineJournalInfo> {
+        self.medline_journal_info.as_ref()
+    }
+
+    pub fn title(&self) -> Option<&str> {
+        self.title.as_deref()
+    }
+
+    pub fn vernacular_title(&self) -> Option<&str> {
+        self.vernacular_title.as_deref()
+    }
+
+    /// Returns the ISO 639-2 codes of the languages the article is written in.
+    pub fn languages(&self) -> &[String] {
+        &self.languages
+    }
+
+    /// Returns whether the article is written in English.
+    ///
+    /// Articles without any language are considered to be in English.
+    pub fn is_english(&self) -> bool {
+        self.languages.is_empty() || self.languages.iter().any(|language| language == "eng")
+    }
+
+    /// Returns the publication types of the article, such as `Journal Article` or `Review`.
+    pub fn publication_types(&self) -> &[PublicationType] {
+        &self.publication_types
+    }
+
+    pub fn abstract_text(&self) -> Option<&Abstract> {
+        self.abstract_text.as_ref()
+    }
+
+    pub fn other_abstract_texts(&self) -> &[Abstract] {
+        &self.other_abstract_texts
+    }
+
+    pub fn chemical_list(&self) -> &[Chemical] {
+        &self.chemical_list
+    }
+
+    pub fn gene_symbol_list(&self) -> &[String] {
+        &self.gene_symbol_list
+    }
+
+    pub fn mesh_list(&self) -> &[Mesh] {
+        &self.mesh_list
+    }
+
+    pub fn suppl_mesh_list(&self) -> &[SupplMesh] {
+        &self.suppl_mesh_list
+    }
+
+    pub fn references(&self) -> &[Reference] {
+        &self.references
+    }
+
+    pub fn keywords(&self) -> &[Keyword] {
+        &self.keywords
+    }
+
+    /// Returns the `PublicationStatus` of the `PubmedData`, such as `ppublish` or `epublish`.
+    pub fn publication_status(&self) -> Option<&str> {
+        self.publication_status.as_deref()
+    }
+
+    /// Returns the text of the elements collected in lenient mode, keyed by their path,
+    /// and the values of the registered element builders, keyed by their name.
+    pub fn extras(&self) -> &BTreeMap<String, Vec<String>> {
+        &self.extras
+    }
+
+    /// Applies the provided normalization to every textual field of the article.
+    pub fn normalize_text(&mut self, normalization: &TextNormalization) {
+        if normalization.is_noop() {
+            return;
+        }
+        if let Some(title) = self.title.as_mut() {
+            normalization.normalize_in_place(title);
+        }
+        if let Some(vernacular_title) = self.vernacular_title.as_mut() {
+            normalization.normalize_in_place(vernacular_title);
+        }
+        normalization.normalize_in_place(&mut self.journal.title);
+        if let Some(iso_abbreviation) = self.journal.iso_abbreviation.as_mut() {
+            normalization.normalize_in_place(iso_abbreviation);
+        }
+        for abstract_text in self
+            .abstract_text
+            .iter_mut()
+            .chain(self.other_abstract_texts.iter_mut())
+        {
+            normalization.normalize_in_place(&mut abstract_text.text);
+        }
+        for chemical in self.chemical_list.iter_mut() {
+            normalization.normalize_in_place(&mut chemical.name_of_substance);
+        }
+        for gene in self.gene_symbol_list.iter_mut() {
+            normalization.normalize_in_place(gene);
+        }
+        for mesh in self.mesh_list.iter_mut() {
+            normalization.normalize_in_place(&mut mesh.descriptor.name);
+            for qualifier in mesh.qualifiers.iter_mut() {
+                normalization.normalize_in_place(&mut qualifier.name);
+            }
+        }
+        for suppl_mesh in self.suppl_mesh_list.iter_mut() {
+            normalization.normalize_in_place(&mut suppl_mesh.name);
+        }
+        for keyword in self.keywords.iter_mut() {
+            normalization.normalize_in_place(&mut keyword.name);
+        }
+        for citation in self
+            .references
+            .iter_mut()
+            .filter_map(|reference| reference.citation.as_mut())
+        {
+            normalization.normalize_in_place(citation);
+        }
+    }
+
+    pub fn paper_description(&self, non_english_description: NonEnglishDescription) -> String {
+        let description = format!(
+            "{}|{}|{}",
+            self.title.as_ref().unwrap_or(&"".to_string()),
+            self.abstract_text
+                .as_ref()
+                .map(|abs| abs.text.to_string())
+                .unwrap_or("".to_string()),
+            self.other_abstract_texts
+                .iter()
+                .map(|abs| { abs.text.to_string() })
+                .collect::<Vec<String>>()
+                .join("|")
+        )
+        .trim_matches('|')
+        .to_string();
+
+        match non_english_description {
+            NonEnglishDescription::Keep => description,
+            _ if self.is_english() => description,
+            NonEnglishDescription::Omit => "".to_string(),
+            NonEnglishDescription::Tag => {
+                format!("[{}] {}", self.languages.join(","), description)
+            }
+        }
+    }
+
+    /// Returns the name of the node of the reference and, unless it is a paper, its type.
+    fn reference_node(
+        &self,
+        index: usize,
+        reference: &Reference,
+        options: &ExportOptions,
+    ) -> Option<(String, Option<&'static str>)> {
+        if let Some(pubmed_id) = reference.pubmed_id() {
+            return Some((format!("PMID:{}", pubmed_id), None));
+        }
+        if let (true, Some(doi)) = (options.references_by_doi, reference.doi()) {
+            return Some((format!("DOI:{}", doi), Some("Reference")));
+        }
+        if let (true, Some(pmcid)) = (options.references_by_pmcid, reference.pmcid()) {
+            return Some((format!("PMCID:{}", pmcid), Some("Reference")));
+        }
+        if options.unresolved_references {
+            return Some((
+                format!("REF:{}:{}", self.pubmed_id, index + 1),
+                Some("UnresolvedReference"),
+            ));
+        }
+        None
+    }
+
     pub fn to_nodes(&self) -> Vec<Node> {
+        self.to_nodes_with_options(&ExportOptions::default())
+    }
+
+    pub fn to_nodes_with_options(&self, options: &ExportOptions) -> Vec<Node> {
         let mut nodes = vec![Node {
             node_name: format!("PMID:{}", self.pubmed_id),
             node_type: "Paper".to_string(),
-            description: format!(
-                "{}|{}|{}",
-                self.title.as_ref().unwrap_or(&"".to_string()),
-                self.abstract_text
-                    .as_ref()
-                    .map(|abs| abs.text.to_string())
-                    .unwrap_or("".to_string()),
-                self.other_abstract_texts
-                    .iter()
-                    .map(|abs| { abs.text.to_string() })
-                    .collect::<Vec<String>>()
-                    .join("|")
-            )
-            .trim_matches('|')
-            .to_string(),
+            description: self.paper_description(options.non_english_description),
         }];
 
         for chemical in self.chemical_list.iter() {
@@ -134,7 +956,7 @@ impl Article {
                 node_type: "Mesh".to_string(),
                 description: mesh.descriptor.name.clone(),
             });
-            if let Some(qualifier) = &mesh.qualifier {
+            for qualifier in mesh.qualifiers.iter() {
                 nodes.push(Node {
                     node_name: qualifier.code.clone(),
                     node_type: "Mesh".to_string(),
@@ -159,16 +981,34 @@ impl Article {
             });
         }
 
+        for (index, reference) in self.references.iter().enumerate() {
+            if let Some((node_name, Some(node_type))) =
+                self.reference_node(index, reference, options)
+            {
+                nodes.push(Node {
+                    node_name,
+                    node_type: node_type.to_string(),
+                    description: reference.citation.clone().unwrap_or_default(),
+                });
+            }
+        }
+
         nodes
     }
 
     pub fn to_edges(&self) -> Vec<Edge> {
+        self.to_edges_with_options(&ExportOptions::default())
+    }
+
+    pub fn to_edges_with_options(&self, options: &ExportOptions) -> Vec<Edge> {
         let mut edges = vec![];
 
         for chemical in self.chemical_list.iter() {
             edges.push(Edge {
                 subject: format!("PMID:{}", self.pubmed_id),
+                subject_type: "Paper".to_string(),
                 object: chemical.code.clone(),
+                object_type: "Chemical".to_string(),
                 edge_type: "PaperToChemical".to_string(),
             });
         }
@@ -176,7 +1016,9 @@ impl Article {
         for gene in self.gene_symbol_list.iter() {
             edges.push(Edge {
                 subject: format!("PMID:{}", self.pubmed_id),
+                subject_type: "Paper".to_string(),
                 object: gene.clone(),
+                object_type: "Gene".to_string(),
                 edge_type: "PaperToGene".to_string(),
             });
         }
@@ -184,13 +1026,17 @@ impl Article {
         for mesh in self.mesh_list.iter() {
             edges.push(Edge {
                 subject: format!("PMID:{}", self.pubmed_id),
+                subject_type: "Paper".to_string(),
                 object: mesh.descriptor.code.clone(),
+                object_type: "Mesh".to_string(),
                 edge_type: "PaperToMesh".to_string(),
             });
-            if let Some(qualifier) = &mesh.qualifier {
+            for qualifier in mesh.qualifiers.iter() {
                 edges.push(Edge {
                     subject: format!("PMID:{}", self.pubmed_id),
+                    subject_type: "Paper".to_string(),
                     object: qualifier.code.clone(),
+                    object_type: "Mesh".to_string(),
                     edge_type: "PaperToMesh".to_string(),
                 });
             }
@@ -199,7 +1045,9 @@ impl Article {
         for suppl_mesh in self.suppl_mesh_list.iter() {
             edges.push(Edge {
                 subject: format!("PMID:{}", self.pubmed_id),
+                subject_type: "Paper".to_string(),
                 object: suppl_mesh.code.clone(),
+                object_type: suppl_mesh.mesh_type.clone(),
                 edge_type: format!("PaperTo{}", suppl_mesh.mesh_type),
             });
         }
@@ -207,17 +1055,23 @@ impl Article {
         for keyword in self.keywords.iter() {
             edges.push(Edge {
                 subject: format!("PMID:{}", self.pubmed_id),
+                subject_type: "Paper".to_string(),
                 object: keyword.name.clone(),
+                object_type: "Keyword".to_string(),
                 edge_type: "PaperToKeyword".to_string(),
             });
         }
 
-        for reference in self.references.iter() {
-            edges.push(Edge {
-                subject: format!("PMID:{}", self.pubmed_id),
-                object: format!("PMID:{}", reference.to_string()),
-                edge_type: "Citation".to_string(),
-            });
+        for (index, reference) in self.references.iter().enumerate() {
+            if let Some((node_name, node_type)) = self.reference_node(index, reference, options) {
+                edges.push(Edge {
+                    subject: format!("PMID:{}", self.pubmed_id),
+                    subject_type: "Paper".to_string(),
+                    object: node_name,
+                    object_type: node_type.unwrap_or("Paper").to_string(),
+                    edge_type: "Citation".to_string(),
+                });
+            }
         }
 
         edges
@@ -1,5 +1,26 @@
 use crate::article::*;
-use std::{collections::HashMap, fmt::Debug, str::FromStr};
+use crate::text_normalization::decode_entities;
+use std::{
+    collections::{BTreeMap, HashMap},
+    fmt::Debug,
+    str::FromStr,
+};
+
+/// A builder for an element of a `PubmedArticle`, fed one trimmed line at a time.
+///
+/// Builders registered with [`crate::PubmedReader::with_element_builder`] are offered
+/// every line of an article before the built-in builders, and the values they build
+/// are stored in [`Article::extras`] under the name they were registered with.
+pub trait ElementBuilder: Send {
+    /// Returns whether the line belongs to the element handled by the builder.
+    fn parse(&mut self, line: &str) -> Result<bool, String>;
+
+    /// Returns whether the builder holds a complete element.
+    fn can_build(&self) -> bool;
+
+    /// Returns the values of the element, only called when [`ElementBuilder::can_build`] holds.
+    fn build(self: Box<Self>) -> Result<Vec<String>, String>;
+}
 
 #[derive(Debug)]
 struct XMLHelper {
@@ -67,10 +88,26 @@ impl XMLHelper {
         self.tag_closed && self.openings == 0
     }
 
+    /// Returns the value of the provided attribute of the last opened tag.
+    pub fn attribute(&self, name: &str) -> Result<String, String> {
+        self.attributes
+            .get(name)
+            .cloned()
+            .ok_or_else(|| format!("The tag {} has no {} attribute.", self.tag, name))
+    }
+
+    /// Returns whether the MajorTopicYN attribute is set, which defaults to `N`.
+    pub fn is_major_topic(&self) -> bool {
+        self.attributes
+            .get("MajorTopicYN")
+            .is_some_and(|major_topic| major_topic == "Y")
+    }
+
     pub fn parse<'a>(&'a mut self, line: &'a str) -> Result<&'a str, String> {
         let opening_tag = format!("<{}", self.tag);
-        let line = if line.starts_with(&opening_tag)
-            && [">", " "].contains(&&line[opening_tag.len()..opening_tag.len() + 1])
+        let line = if line
+            .strip_prefix(&opening_tag)
+            .is_some_and(|rest| rest.starts_with(['>', ' ']))
         {
             if !self.allow_reopening && self.tag_opened && !self.just_opened {
                 return Err(format!(
@@ -78,29 +115,24 @@ impl XMLHelper {
                     self.tag, line
                 ));
             }
-            if line.ends_with("/>") {
+            let tag_length = line.find('>').ok_or_else(|| {
+                format!(
+                    "The opening tag {} is not closed on the line {}.",
+                    self.tag, line
+                )
+            })?;
+            // Self-closing tags hold no value.
+            if line[..tag_length].ends_with('/') {
                 return Ok("");
             }
-            let tag_length = line.find(">").unwrap();
             let attributes_portion = &line[opening_tag.len()..tag_length];
 
             let attributes = attributes_portion
                 .trim()
                 .split(" ")
                 .filter_map(|attribute| {
-                    if attribute.is_empty() || !attribute.contains('=') {
-                        return None;
-                    }
-                    let key_and_value = attribute
-                        .split("=")
-                        .map(|a| a.to_string())
-                        .collect::<Vec<String>>();
-                    if key_and_value.len() < 2 {
-                        panic!("{:?}, {}", key_and_value, line);
-                    }
-                    let first = key_and_value[0].clone();
-                    let second = key_and_value[1].clone();
-                    Some((first, second.trim_matches('\"').to_string()))
+                    let (key, value) = attribute.split_once('=')?;
+                    Some((key.to_string(), decode_entities(value.trim_matches('\"'))))
                 })
                 .collect::<HashMap<String, String>>();
 
@@ -120,7 +152,7 @@ impl XMLHelper {
             self.tag_opened = true;
             self.tag_closed = false;
             self.just_closed = false;
-            self.openings += 1;
+            self.openings = self.openings.saturating_add(1);
             self.just_opened = true;
 
             &line[tag_length + 1..]
@@ -146,7 +178,7 @@ impl XMLHelper {
             }
             self.tag_closed = true;
             self.just_closed = true;
-            self.openings -= 1;
+            self.openings = self.openings.saturating_sub(1);
 
             &line[..line.len() - closing_tag.len()]
         } else {
@@ -174,14 +206,6 @@ impl<T: FromStr + Debug> ObjectBuilder<T> {
         }
     }
 
-    pub fn with_attributes(tag: &str, mandatory_attributes: HashMap<String, String>) -> Self {
-        ObjectBuilder {
-            xml_helper: XMLHelper::with_attributes(tag, mandatory_attributes),
-            textual_value: "".to_string(),
-            value: None,
-        }
-    }
-
     pub fn can_build(&self) -> bool {
         self.xml_helper.can_build()
     }
@@ -196,14 +220,17 @@ impl<T: FromStr + Debug> ObjectBuilder<T> {
             };
         }
         if self.can_build() {
-            self.value = Some(T::from_str(self.textual_value.trim()).map_err(|_| {
-                format!(
-                    concat!(
-                        "Something went wrong while trying to convert the value `{}` in tag {}."
-                    ),
-                    self.textual_value, self.xml_helper.tag
-                )
-            })?);
+            self.value = Some(
+                T::from_str(&decode_entities(self.textual_value.trim())).map_err(|_| {
+                    format!(
+                        concat!(
+                            "Something went wrong while trying to convert ",
+                            "the value `{}` in tag {}."
+                        ),
+                        self.textual_value, self.xml_helper.tag
+                    )
+                })?,
+            );
         }
         Ok(
             self.xml_helper.tag_opened && !self.xml_helper.tag_closed
@@ -225,6 +252,8 @@ struct DateBuilder {
     year_builder: ObjectBuilder<u16>,
     month_builder: ObjectBuilder<String>,
     day_builder: ObjectBuilder<u8>,
+    season_builder: ObjectBuilder<String>,
+    medline_date_builder: ObjectBuilder<String>,
 }
 
 impl DateBuilder {
@@ -234,6 +263,8 @@ impl DateBuilder {
             year_builder: ObjectBuilder::new("Year"),
             month_builder: ObjectBuilder::new("Month"),
             day_builder: ObjectBuilder::new("Day"),
+            season_builder: ObjectBuilder::new("Season"),
+            medline_date_builder: ObjectBuilder::new("MedlineDate"),
         }
     }
 
@@ -248,6 +279,12 @@ impl DateBuilder {
         if !self.day_builder.can_build() && self.day_builder.parse(line)? {
             return Ok(true);
         }
+        if !self.season_builder.can_build() && self.season_builder.parse(line)? {
+            return Ok(true);
+        }
+        if !self.medline_date_builder.can_build() && self.medline_date_builder.parse(line)? {
+            return Ok(true);
+        }
         Ok(
             self.xml_helper.tag_opened && !self.xml_helper.tag_closed
                 || self.xml_helper.just_closed,
@@ -273,6 +310,8 @@ impl DateBuilder {
             year: self.year_builder.build(),
             month: self.month_builder.build(),
             day: self.day_builder.build(),
+            season: self.season_builder.build(),
+            medline_date: self.medline_date_builder.build(),
         })
     }
 }
@@ -319,12 +358,14 @@ impl JournalIssueBuilder {
 
     pub fn build(self) -> Result<JournalIssue, String> {
         if !self.xml_helper.can_build() {
-            return Err(format!(concat!(
+            return Err(concat!(
                 "Build method was called on JournalBuilder ",
                 "but the object is not yet ready to build."
-            )));
+            )
+            .to_string());
         }
         Ok(JournalIssue {
+            cited_medium: self.xml_helper.attributes.get("CitedMedium").cloned(),
             volume: self.volume_builder.build(),
             issue: self.issue_builder.build(),
             pubblication_date: self.pubblication_date_builder.build()?,
@@ -379,14 +420,24 @@ impl JournalBuilder {
 
     pub fn build(self) -> Result<Journal, String> {
         if !self.xml_helper.can_build() {
-            return Err(format!(concat!(
+            return Err(concat!(
                 "Build method was called on JournalBuilder ",
                 "but the object is not yet ready to build."
-            )));
+            )
+            .to_string());
         }
         Ok(Journal {
+            issn_type: self
+                .issn_builder
+                .xml_helper
+                .attributes
+                .get("IssnType")
+                .cloned(),
             issn: self.issn_builder.build(),
-            title: self.title_builder.build().unwrap(),
+            title: self
+                .title_builder
+                .build()
+                .ok_or_else(|| "The journal has no title.".to_string())?,
             iso_abbreviation: self.iso_abbreviation_builder.build(),
             journal_issue: self.journal_issue_builder.build()?,
         })
@@ -421,14 +472,14 @@ impl AbstractBuilder {
         }
         self.abstract_builder.parse(line)?;
         if self.abstract_builder.can_build() {
-            self.abstract_test.push(
-                core::mem::replace(
-                    &mut self.abstract_builder,
-                    ObjectBuilder::new("AbstractText"),
-                )
-                .build()
-                .unwrap(),
-            );
+            if let Some(abstract_text) = core::mem::replace(
+                &mut self.abstract_builder,
+                ObjectBuilder::new("AbstractText"),
+            )
+            .build()
+            {
+                self.abstract_test.push(abstract_text);
+            }
         }
 
         Ok(!self.xml_helper.tag_closed)
@@ -436,22 +487,15 @@ impl AbstractBuilder {
 
     pub fn build(self) -> Result<Abstract, String> {
         if !self.xml_helper.can_build() {
-            return Err(format!(concat!(
+            return Err(concat!(
                 "Build method was called on AbstractBuilder ",
                 "but the object is not yet ready to build."
-            )));
+            )
+            .to_string());
         }
         Ok(Abstract {
-            language: self
-                .xml_helper
-                .attributes
-                .get("Language")
-                .map(|val| val.clone()),
-            abstract_type: self
-                .xml_helper
-                .attributes
-                .get("Type")
-                .map(|val| val.clone()),
+            language: self.xml_helper.attributes.get("Language").cloned(),
+            abstract_type: self.xml_helper.attributes.get("Type").cloned(),
             text: self.abstract_test.join(" "),
         })
     }
@@ -498,15 +542,15 @@ impl ChemicalBuilder {
 
     pub fn build(self) -> Result<Chemical, String> {
         Ok(Chemical {
-            registry_number: self.registry_number_builder.build().unwrap(),
-            code: self
+            registry_number: self
+                .registry_number_builder
+                .build()
+                .ok_or_else(|| "The chemical has no registry number.".to_string())?,
+            code: self.name_of_substance_builder.xml_helper.attribute("UI")?,
+            name_of_substance: self
                 .name_of_substance_builder
-                .xml_helper
-                .attributes
-                .get("UI")
-                .unwrap()
-                .clone(),
-            name_of_substance: self.name_of_substance_builder.build().unwrap(),
+                .build()
+                .ok_or_else(|| "The chemical has no name.".to_string())?,
         })
     }
 }
@@ -536,9 +580,7 @@ impl ChemicalListBuilder {
         self.chemical_builder.parse(line)?;
         if self.chemical_builder.can_build() {
             self.chemicals.push(
-                core::mem::replace(&mut self.chemical_builder, ChemicalBuilder::new())
-                    .build()
-                    .unwrap(),
+                core::mem::replace(&mut self.chemical_builder, ChemicalBuilder::new()).build()?,
             );
         }
 
@@ -550,10 +592,11 @@ impl ChemicalListBuilder {
 
     pub fn build(self) -> Result<Vec<Chemical>, String> {
         if !self.xml_helper.can_build() && !self.chemicals.is_empty() {
-            return Err(format!(concat!(
+            return Err(concat!(
                 "Build method was called on ChemicalListBuilder ",
                 "but the object is not yet ready to build."
-            )));
+            )
+            .to_string());
         }
         Ok(self.chemicals)
     }
@@ -568,6 +611,7 @@ struct MeshBuilder {
     xml_helper: XMLHelper,
     descriptor_builder: ObjectBuilder<String>,
     qualifier_builder: ObjectBuilder<String>,
+    qualifiers: Vec<MeshTopic>,
 }
 
 impl MeshBuilder {
@@ -576,6 +620,7 @@ impl MeshBuilder {
             xml_helper: XMLHelper::new("MeshHeading"),
             descriptor_builder: ObjectBuilder::new("DescriptorName"),
             qualifier_builder: ObjectBuilder::new("QualifierName"),
+            qualifiers: Vec::new(),
         }
     }
 
@@ -584,7 +629,20 @@ impl MeshBuilder {
         if !self.descriptor_builder.can_build() && self.descriptor_builder.parse(line)? {
             return Ok(true);
         }
-        if !self.qualifier_builder.can_build() && self.qualifier_builder.parse(line)? {
+        if self.qualifier_builder.parse(line)? {
+            if self.qualifier_builder.can_build() {
+                let qualifier_builder = core::mem::replace(
+                    &mut self.qualifier_builder,
+                    ObjectBuilder::new("QualifierName"),
+                );
+                self.qualifiers.push(MeshTopic {
+                    code: qualifier_builder.xml_helper.attribute("UI")?,
+                    is_major_topic: qualifier_builder.xml_helper.is_major_topic(),
+                    name: qualifier_builder
+                        .build()
+                        .ok_or_else(|| "The MeSH qualifier has no name.".to_string())?,
+                });
+            }
             return Ok(true);
         }
         Ok(
@@ -599,50 +657,17 @@ impl MeshBuilder {
 
     pub fn build(self) -> Result<Mesh, String> {
         let descriptor = MeshTopic {
-            code: self
+            code: self.descriptor_builder.xml_helper.attribute("UI")?,
+            is_major_topic: self.descriptor_builder.xml_helper.is_major_topic(),
+            name: self
                 .descriptor_builder
-                .xml_helper
-                .attributes
-                .get("UI")
-                .unwrap()
-                .clone(),
-            is_major_topic: self
-                .descriptor_builder
-                .xml_helper
-                .attributes
-                .get("MajorTopicYN")
-                .unwrap()
-                .clone()
-                == "Y",
-            name: self.descriptor_builder.build().unwrap(),
-        };
-
-        let qualifier = if self.qualifier_builder.can_build() {
-            Some(MeshTopic {
-                code: self
-                    .qualifier_builder
-                    .xml_helper
-                    .attributes
-                    .get("UI")
-                    .unwrap()
-                    .clone(),
-                is_major_topic: self
-                    .qualifier_builder
-                    .xml_helper
-                    .attributes
-                    .get("MajorTopicYN")
-                    .unwrap()
-                    .clone()
-                    == "Y",
-                name: self.qualifier_builder.build().unwrap(),
-            })
-        } else {
-            None
+                .build()
+                .ok_or_else(|| "The MeSH heading has no descriptor.".to_string())?,
         };
 
         Ok(Mesh {
             descriptor,
-            qualifier,
+            qualifiers: self.qualifiers,
         })
     }
 }
@@ -671,11 +696,8 @@ impl MeshListBuilder {
         }
         self.mesh_builder.parse(line)?;
         if self.mesh_builder.can_build() {
-            self.meshes.push(
-                core::mem::replace(&mut self.mesh_builder, MeshBuilder::new())
-                    .build()
-                    .unwrap(),
-            );
+            self.meshes
+                .push(core::mem::replace(&mut self.mesh_builder, MeshBuilder::new()).build()?);
         }
 
         Ok(
@@ -706,23 +728,23 @@ impl MeshListBuilder {
 #[derive(Debug)]
 struct KeywordListBuilder {
     xml_helper: XMLHelper,
-    keywords: Vec<Keyword>,
     owner: String,
+    keywords: Vec<Keyword>,
     keyword_builder: ObjectBuilder<String>,
 }
 
 impl KeywordListBuilder {
     pub fn new(owner: &str) -> Self {
         KeywordListBuilder {
-            xml_helper: XMLHelper::new("KeywordList"),
-            keywords: Vec::new(),
-            owner: owner.to_string(),
-            keyword_builder: ObjectBuilder::with_attributes(
-                "Keyword",
+            xml_helper: XMLHelper::with_attributes(
+                "KeywordList",
                 [("Owner".to_string(), owner.to_string())]
                     .into_iter()
                     .collect(),
             ),
+            owner: owner.to_string(),
+            keywords: Vec::new(),
+            keyword_builder: ObjectBuilder::new("Keyword"),
         }
     }
 
@@ -734,25 +756,16 @@ impl KeywordListBuilder {
         }
         self.keyword_builder.parse(line)?;
         if self.keyword_builder.can_build() {
-            let keyword_builder = core::mem::replace(
-                &mut self.keyword_builder,
-                ObjectBuilder::with_attributes(
-                    "Keyword",
-                    [("Owner".to_string(), self.owner.clone())]
-                        .into_iter()
-                        .collect(),
-                ),
-            );
-            self.keywords.push(Keyword {
-                is_major_topic: keyword_builder
-                    .xml_helper
-                    .attributes
-                    .get("MajorTopicYN")
-                    .unwrap()
-                    .clone()
-                    == "Y",
-                name: keyword_builder.build().unwrap(),
-            })
+            let keyword_builder =
+                core::mem::replace(&mut self.keyword_builder, ObjectBuilder::new("Keyword"));
+            let is_major_topic = keyword_builder.xml_helper.is_major_topic();
+            if let Some(name) = keyword_builder.build() {
+                self.keywords.push(Keyword {
+                    is_major_topic,
+                    name,
+                    owner: self.owner.clone(),
+                })
+            }
         }
 
         Ok(
@@ -813,15 +826,10 @@ impl ArticleIdsBuilder {
                 &mut self.article_id_builder,
                 ObjectBuilder::new("ArticleId"),
             );
-            self.article_ids.push(ArticleId {
-                id_type: article_id_builder
-                    .xml_helper
-                    .attributes
-                    .get("IdType")
-                    .unwrap()
-                    .clone(),
-                value: article_id_builder.build().unwrap(),
-            })
+            let id_type = article_id_builder.xml_helper.attribute("IdType")?;
+            if let Some(value) = article_id_builder.build() {
+                self.article_ids.push(ArticleId { id_type, value })
+            }
         }
 
         Ok(
@@ -849,6 +857,181 @@ impl ArticleIdsBuilder {
     }
 }
 
+#[derive(Debug)]
+struct ELocationIdsBuilder {
+    elocation_ids: Vec<ArticleId>,
+    elocation_id_builder: ObjectBuilder<String>,
+}
+
+impl ELocationIdsBuilder {
+    pub fn new() -> Self {
+        ELocationIdsBuilder {
+            elocation_ids: Vec::new(),
+            elocation_id_builder: ObjectBuilder::new("ELocationID"),
+        }
+    }
+
+    pub fn parse(&mut self, line: &str) -> Result<bool, String> {
+        let parsed = self.elocation_id_builder.parse(line)?;
+        if self.elocation_id_builder.can_build() {
+            let elocation_id_builder = core::mem::replace(
+                &mut self.elocation_id_builder,
+                ObjectBuilder::new("ELocationID"),
+            );
+            let attributes = &elocation_id_builder.xml_helper.attributes;
+            // Identifiers flagged as not valid by NLM are dropped.
+            let is_valid = attributes.get("ValidYN").map(String::as_str) != Some("N");
+            if let (true, Some(id_type)) = (is_valid, attributes.get("EIdType").cloned()) {
+                if let Some(value) = elocation_id_builder.build() {
+                    self.elocation_ids.push(ArticleId { id_type, value });
+                }
+            }
+        }
+        Ok(parsed)
+    }
+
+    pub fn build(self) -> Result<Vec<ArticleId>, String> {
+        if self.elocation_id_builder.xml_helper.tag_opened {
+            return Err(format!(
+                concat!(
+                    "Build method was called on ELocationIdsBuilder ",
+                    "but the object is not yet ready to build. ",
+                    "The object currently looks like {:?}"
+                ),
+                self
+            ));
+        }
+        Ok(self.elocation_ids)
+    }
+}
+
+#[derive(Debug)]
+struct MedlineJournalInfoBuilder {
+    xml_helper: XMLHelper,
+    country_builder: ObjectBuilder<String>,
+    medline_ta_builder: ObjectBuilder<String>,
+    nlm_unique_id_builder: ObjectBuilder<String>,
+    issn_linking_builder: ObjectBuilder<String>,
+}
+
+impl MedlineJournalInfoBuilder {
+    pub fn new() -> Self {
+        MedlineJournalInfoBuilder {
+            xml_helper: XMLHelper::new("MedlineJournalInfo"),
+            country_builder: ObjectBuilder::new("Country"),
+            medline_ta_builder: ObjectBuilder::new("MedlineTA"),
+            nlm_unique_id_builder: ObjectBuilder::new("NlmUniqueID"),
+            issn_linking_builder: ObjectBuilder::new("ISSNLinking"),
+        }
+    }
+
+    pub fn parse(&mut self, line: &str) -> Result<bool, String> {
+        let line = self.xml_helper.parse(line)?;
+        if line.is_empty() {
+            return Ok(self.xml_helper.tag_opened && !self.xml_helper.tag_closed
+                || self.xml_helper.just_closed);
+        }
+        if !self.country_builder.can_build() && self.country_builder.parse(line)? {
+            return Ok(true);
+        }
+        if !self.medline_ta_builder.can_build() && self.medline_ta_builder.parse(line)? {
+            return Ok(true);
+        }
+        if !self.nlm_unique_id_builder.can_build() && self.nlm_unique_id_builder.parse(line)? {
+            return Ok(true);
+        }
+        if !self.issn_linking_builder.can_build() && self.issn_linking_builder.parse(line)? {
+            return Ok(true);
+        }
+        Ok(
+            self.xml_helper.tag_opened && !self.xml_helper.tag_closed
+                || self.xml_helper.just_closed,
+        )
+    }
+
+    pub fn can_build(&self) -> bool {
+        self.xml_helper.can_build()
+    }
+
+    pub fn build(self) -> Result<MedlineJournalInfo, String> {
+        if !self.xml_helper.can_build() {
+            return Err(concat!(
+                "Build method was called on MedlineJournalInfoBuilder ",
+                "but the object is not yet ready to build."
+            )
+            .to_string());
+        }
+        Ok(MedlineJournalInfo {
+            country: self.country_builder.build(),
+            medline_ta: self.medline_ta_builder.build(),
+            nlm_unique_id: self.nlm_unique_id_builder.build(),
+            issn_linking: self.issn_linking_builder.build(),
+        })
+    }
+}
+
+#[derive(Debug)]
+struct PaginationBuilder {
+    xml_helper: XMLHelper,
+    start_page_builder: ObjectBuilder<String>,
+    end_page_builder: ObjectBuilder<String>,
+    medline_pgn_builder: ObjectBuilder<String>,
+}
+
+impl PaginationBuilder {
+    pub fn new() -> Self {
+        PaginationBuilder {
+            xml_helper: XMLHelper::new("Pagination"),
+            start_page_builder: ObjectBuilder::new("StartPage"),
+            end_page_builder: ObjectBuilder::new("EndPage"),
+            medline_pgn_builder: ObjectBuilder::new("MedlinePgn"),
+        }
+    }
+
+    pub fn parse(&mut self, line: &str) -> Result<bool, String> {
+        let line = self.xml_helper.parse(line)?;
+        if line.is_empty() {
+            return Ok(self.xml_helper.tag_opened && !self.xml_helper.tag_closed
+                || self.xml_helper.just_closed);
+        }
+        if !self.start_page_builder.can_build() && self.start_page_builder.parse(line)? {
+            return Ok(true);
+        }
+        if !self.end_page_builder.can_build() && self.end_page_builder.parse(line)? {
+            return Ok(true);
+        }
+        if !self.medline_pgn_builder.can_build() && self.medline_pgn_builder.parse(line)? {
+            return Ok(true);
+        }
+        Ok(
+            self.xml_helper.tag_opened && !self.xml_helper.tag_closed
+                || self.xml_helper.just_closed,
+        )
+    }
+
+    pub fn can_build(&self) -> bool {
+        self.xml_helper.can_build()
+    }
+
+    pub fn build(self) -> Result<Pagination, String> {
+        if !self.xml_helper.can_build() {
+            return Err(format!(
+                concat!(
+                    "Build method was called on PaginationBuilder ",
+                    "but the object is not yet ready to build. ",
+                    "The current status is: {:?}."
+                ),
+                self
+            ));
+        }
+        Ok(Pagination {
+            start_page: self.start_page_builder.build(),
+            end_page: self.end_page_builder.build(),
+            medline_pgn: self.medline_pgn_builder.build(),
+        })
+    }
+}
+
 #[derive(Debug)]
 struct OtherAbstractBuilder {
     other_abstracts: Vec<Abstract>,
@@ -896,6 +1079,84 @@ impl OtherAbstractBuilder {
     }
 }
 
+/// Builder for an element that may be repeated without a wrapping list, such as `Language`.
+#[derive(Debug)]
+struct RepeatedObjectBuilder {
+    tag: String,
+    values: Vec<String>,
+    value_builder: ObjectBuilder<String>,
+}
+
+impl RepeatedObjectBuilder {
+    pub fn new(tag: &str) -> Self {
+        RepeatedObjectBuilder {
+            tag: tag.to_string(),
+            values: Vec::new(),
+            value_builder: ObjectBuilder::new(tag),
+        }
+    }
+
+    pub fn parse(&mut self, line: &str) -> Result<bool, String> {
+        let parsed = self.value_builder.parse(line)?;
+        if self.value_builder.can_build() {
+            if let Some(value) =
+                core::mem::replace(&mut self.value_builder, ObjectBuilder::new(&self.tag)).build()
+            {
+                self.values.push(value);
+            }
+        }
+        Ok(parsed)
+    }
+
+    pub fn build(self) -> Result<Vec<String>, String> {
+        if self.value_builder.xml_helper.tag_opened {
+            return Err(format!(
+                concat!(
+                    "Build method was called on RepeatedObjectBuilder ",
+                    "but the object is not yet ready to build. ",
+                    "The object currently looks like {:?}"
+                ),
+                self
+            ));
+        }
+        Ok(self.values)
+    }
+}
+
+/// Builds the text of every occurrence of an element that fits on a single line,
+/// such as `OtherID` or `GeneralNote`.
+#[derive(Debug)]
+pub struct TextElementBuilder {
+    repeated_object_builder: RepeatedObjectBuilder,
+}
+
+impl TextElementBuilder {
+    pub fn new(tag: &str) -> Self {
+        TextElementBuilder {
+            repeated_object_builder: RepeatedObjectBuilder::new(tag),
+        }
+    }
+}
+
+impl ElementBuilder for TextElementBuilder {
+    fn parse(&mut self, line: &str) -> Result<bool, String> {
+        self.repeated_object_builder.parse(line)
+    }
+
+    fn can_build(&self) -> bool {
+        !self.repeated_object_builder.values.is_empty()
+            && !self
+                .repeated_object_builder
+                .value_builder
+                .xml_helper
+                .tag_opened
+    }
+
+    fn build(self: Box<Self>) -> Result<Vec<String>, String> {
+        self.repeated_object_builder.build()
+    }
+}
+
 #[derive(Debug)]
 struct GeneSymbolListBuilder {
     xml_helper: XMLHelper,
@@ -924,7 +1185,9 @@ impl GeneSymbolListBuilder {
                 &mut self.gene_symbol_builder,
                 ObjectBuilder::new("GeneSymbol"),
             );
-            self.gene_symbols.push(gene_symbol_builder.build().unwrap())
+            if let Some(gene_symbol) = gene_symbol_builder.build() {
+                self.gene_symbols.push(gene_symbol)
+            }
         }
 
         Ok(
@@ -981,19 +1244,11 @@ impl SupplMeshListBuilder {
                 ObjectBuilder::new("SupplMeshName"),
             );
             self.meshes.push(SupplMesh {
-                code: suppl_mesh_builder
-                    .xml_helper
-                    .attributes
-                    .get("UI")
-                    .unwrap()
-                    .clone(),
-                mesh_type: suppl_mesh_builder
-                    .xml_helper
-                    .attributes
-                    .get("Type")
-                    .unwrap()
-                    .clone(),
-                name: suppl_mesh_builder.build().unwrap(),
+                code: suppl_mesh_builder.xml_helper.attribute("UI")?,
+                mesh_type: suppl_mesh_builder.xml_helper.attribute("Type")?,
+                name: suppl_mesh_builder
+                    .build()
+                    .ok_or_else(|| "The supplementary MeSH has no name.".to_string())?,
             })
         }
 
@@ -1023,23 +1278,18 @@ impl SupplMeshListBuilder {
 }
 
 #[derive(Debug)]
-struct ReferencesBuilder {
+struct PublicationTypeListBuilder {
     xml_helper: XMLHelper,
-    references: Vec<usize>,
-    pubmed_builder: ObjectBuilder<usize>,
+    publication_types: Vec<PublicationType>,
+    publication_type_builder: ObjectBuilder<String>,
 }
 
-impl ReferencesBuilder {
+impl PublicationTypeListBuilder {
     pub fn new() -> Self {
-        ReferencesBuilder {
-            xml_helper: XMLHelper::with_reopening("ReferenceList"),
-            references: Vec::new(),
-            pubmed_builder: ObjectBuilder::with_attributes(
-                "ArticleId",
-                [("IdType".to_string(), "pubmed".to_string())]
-                    .into_iter()
-                    .collect(),
-            ),
+        PublicationTypeListBuilder {
+            xml_helper: XMLHelper::new("PublicationTypeList"),
+            publication_types: Vec::new(),
+            publication_type_builder: ObjectBuilder::new("PublicationType"),
         }
     }
 
@@ -1049,68 +1299,370 @@ impl ReferencesBuilder {
             return Ok(self.xml_helper.tag_opened && !self.xml_helper.tag_closed
                 || self.xml_helper.just_closed);
         }
-        if self.pubmed_builder.parse(line).is_err() {
-            let _ = core::mem::replace(
-                &mut self.pubmed_builder,
-                ObjectBuilder::with_attributes(
-                    "ArticleId",
-                    [("IdType".to_string(), "pubmed".to_string())]
-                        .into_iter()
-                        .collect(),
-                )
-            );
-        }
-        if self.pubmed_builder.can_build() {
-            self.references.push(
-                core::mem::replace(
-                    &mut self.pubmed_builder,
-                    ObjectBuilder::with_attributes(
-                        "ArticleId",
-                        [("IdType".to_string(), "pubmed".to_string())]
-                            .into_iter()
-                            .collect(),
-                    ),
-                )
-                .build()
-                .unwrap(),
+        self.publication_type_builder.parse(line)?;
+        if self.publication_type_builder.can_build() {
+            let publication_type_builder = core::mem::replace(
+                &mut self.publication_type_builder,
+                ObjectBuilder::new("PublicationType"),
             );
+            self.publication_types.push(PublicationType {
+                code: publication_type_builder.xml_helper.attribute("UI")?,
+                name: publication_type_builder
+                    .build()
+                    .ok_or_else(|| "The publication type has no name.".to_string())?,
+            })
         }
+
         Ok(
             self.xml_helper.tag_opened && !self.xml_helper.tag_closed
                 || self.xml_helper.just_closed,
         )
     }
 
-    pub fn build(self) -> Result<Vec<usize>, String> {
-        if !self.xml_helper.can_build() && !self.references.is_empty() {
+    pub fn build(self) -> Result<Vec<PublicationType>, String> {
+        if !self.xml_helper.can_build() && !self.publication_types.is_empty() {
             return Err(format!(
                 concat!(
-                    "Build method was called on ReferencesBuilder ",
+                    "Build method was called on PublicationTypeListBuilder ",
                     "but the object is not yet ready to build. ",
                     "The object currently looks like {:?}"
                 ),
                 self
             ));
         }
-        Ok(self.references)
+        Ok(self.publication_types)
+    }
+
+    pub fn can_build(&self) -> bool {
+        self.xml_helper.can_build()
     }
 }
 
-struct IgnoreTag {
+#[derive(Debug)]
+struct ReferenceBuilder {
     xml_helper: XMLHelper,
+    citation_builder: ObjectBuilder<String>,
+    article_ids_builder: ArticleIdsBuilder,
 }
 
-impl IgnoreTag {
-    pub fn new(tag: &str) -> Self {
-        IgnoreTag {
-            xml_helper: XMLHelper::new(tag),
+impl ReferenceBuilder {
+    pub fn new() -> Self {
+        ReferenceBuilder {
+            xml_helper: XMLHelper::new("Reference"),
+            citation_builder: ObjectBuilder::new("Citation"),
+            article_ids_builder: ArticleIdsBuilder::new(),
         }
     }
 
     pub fn parse(&mut self, line: &str) -> Result<bool, String> {
-        let _ = self.xml_helper.parse(line)?;
-        Ok(
-            self.xml_helper.tag_opened && !self.xml_helper.tag_closed
+        let line = self.xml_helper.parse(line)?;
+        if line.is_empty() {
+            return Ok(self.xml_helper.tag_opened && !self.xml_helper.tag_closed
+                || self.xml_helper.just_closed);
+        }
+        if !self.citation_builder.can_build() && self.citation_builder.parse(line)? {
+            return Ok(true);
+        }
+        if !self.article_ids_builder.can_build() && self.article_ids_builder.parse(line)? {
+            return Ok(true);
+        }
+        Ok(
+            self.xml_helper.tag_opened && !self.xml_helper.tag_closed
+                || self.xml_helper.just_closed,
+        )
+    }
+
+    pub fn can_build(&self) -> bool {
+        self.xml_helper.can_build()
+    }
+
+    pub fn build(self) -> Result<Reference, String> {
+        if !self.xml_helper.can_build() {
+            return Err(concat!(
+                "Build method was called on ReferenceBuilder ",
+                "but the object is not yet ready to build."
+            )
+            .to_string());
+        }
+        Ok(Reference {
+            citation: self.citation_builder.build(),
+            article_ids: self.article_ids_builder.build()?,
+        })
+    }
+}
+
+#[derive(Debug)]
+struct ReferencesBuilder {
+    xml_helper: XMLHelper,
+    references: Vec<Reference>,
+    reference_builder: ReferenceBuilder,
+}
+
+impl ReferencesBuilder {
+    pub fn new() -> Self {
+        ReferencesBuilder {
+            xml_helper: XMLHelper::with_reopening("ReferenceList"),
+            references: Vec::new(),
+            reference_builder: ReferenceBuilder::new(),
+        }
+    }
+
+    pub fn parse(&mut self, line: &str) -> Result<bool, String> {
+        let line = self.xml_helper.parse(line)?;
+        if line.is_empty() {
+            return Ok(self.xml_helper.tag_opened && !self.xml_helper.tag_closed
+                || self.xml_helper.just_closed);
+        }
+        self.reference_builder.parse(line)?;
+        if self.reference_builder.can_build() {
+            self.references.push(
+                core::mem::replace(&mut self.reference_builder, ReferenceBuilder::new()).build()?,
+            );
+        }
+        Ok(
+            self.xml_helper.tag_opened && !self.xml_helper.tag_closed
+                || self.xml_helper.just_closed,
+        )
+    }
+
+    pub fn build(self) -> Result<Vec<Reference>, String> {
+        if !self.xml_helper.can_build() && !self.references.is_empty() {
+            return Err(format!(
+                concat!(
+                    "Build method was called on ReferencesBuilder ",
+                    "but the object is not yet ready to build. ",
+                    "The object currently looks like {:?}"
+                ),
+                self
+            ));
+        }
+        Ok(self.references)
+    }
+}
+
+struct BookBuilder {
+    xml_helper: XMLHelper,
+    publisher_name_builder: ObjectBuilder<String>,
+    publisher_location_builder: ObjectBuilder<String>,
+    title_builder: ObjectBuilder<String>,
+    pubblication_date_builder: DateBuilder,
+}
+
+impl BookBuilder {
+    pub fn new() -> Self {
+        BookBuilder {
+            xml_helper: XMLHelper::new("Book"),
+            publisher_name_builder: ObjectBuilder::new("PublisherName"),
+            publisher_location_builder: ObjectBuilder::new("PublisherLocation"),
+            title_builder: ObjectBuilder::new("BookTitle"),
+            pubblication_date_builder: DateBuilder::new("PubDate"),
+        }
+    }
+
+    pub fn parse(&mut self, line: &str) -> Result<bool, String> {
+        let line = self.xml_helper.parse(line)?;
+        if line.is_empty() {
+            return Ok(self.xml_helper.tag_opened && !self.xml_helper.tag_closed
+                || self.xml_helper.just_closed);
+        }
+        if !self.publisher_name_builder.can_build() && self.publisher_name_builder.parse(line)? {
+            return Ok(true);
+        }
+        if !self.publisher_location_builder.can_build()
+            && self.publisher_location_builder.parse(line)?
+        {
+            return Ok(true);
+        }
+        if !self.title_builder.can_build() && self.title_builder.parse(line)? {
+            return Ok(true);
+        }
+        if !self.pubblication_date_builder.can_build()
+            && self.pubblication_date_builder.parse(line)?
+        {
+            return Ok(true);
+        }
+        Ok(
+            self.xml_helper.tag_opened && !self.xml_helper.tag_closed
+                || self.xml_helper.just_closed,
+        )
+    }
+
+    pub fn can_build(&self) -> bool {
+        self.xml_helper.can_build()
+    }
+
+    pub fn build(self) -> Result<Book, String> {
+        if !self.xml_helper.can_build() {
+            return Err(concat!(
+                "Build method was called on BookBuilder ",
+                "but the object is not yet ready to build."
+            )
+            .to_string());
+        }
+        Ok(Book {
+            publisher_name: self.publisher_name_builder.build(),
+            publisher_location: self.publisher_location_builder.build(),
+            title: self.title_builder.build(),
+            pubblication_date: self.pubblication_date_builder.build().ok(),
+        })
+    }
+}
+
+#[derive(Debug)]
+struct SectionsBuilder {
+    xml_helper: XMLHelper,
+    sections: Vec<String>,
+    section_title_builder: ObjectBuilder<String>,
+}
+
+impl SectionsBuilder {
+    pub fn new() -> Self {
+        SectionsBuilder {
+            xml_helper: XMLHelper::new("Sections"),
+            sections: Vec::new(),
+            section_title_builder: ObjectBuilder::new("SectionTitle"),
+        }
+    }
+
+    pub fn parse(&mut self, line: &str) -> Result<bool, String> {
+        let line = self.xml_helper.parse(line)?;
+        if line.is_empty() {
+            return Ok(self.xml_helper.tag_opened && !self.xml_helper.tag_closed
+                || self.xml_helper.just_closed);
+        }
+        self.section_title_builder.parse(line)?;
+        if self.section_title_builder.can_build() {
+            if let Some(section_title) = core::mem::replace(
+                &mut self.section_title_builder,
+                ObjectBuilder::new("SectionTitle"),
+            )
+            .build()
+            {
+                self.sections.push(section_title);
+            }
+        }
+
+        Ok(
+            self.xml_helper.tag_opened && !self.xml_helper.tag_closed
+                || self.xml_helper.just_closed,
+        )
+    }
+
+    pub fn build(self) -> Result<Vec<String>, String> {
+        if !self.xml_helper.can_build() && !self.sections.is_empty() {
+            return Err(format!(
+                concat!(
+                    "Build method was called on SectionsBuilder ",
+                    "but the object is not yet ready to build. ",
+                    "The object currently looks like {:?}"
+                ),
+                self
+            ));
+        }
+        Ok(self.sections)
+    }
+
+    pub fn can_build(&self) -> bool {
+        self.xml_helper.can_build()
+    }
+}
+
+#[derive(Debug)]
+pub(crate) struct DeleteCitationBuilder {
+    xml_helper: XMLHelper,
+    deleted_citations: Vec<DeletedCitation>,
+    pmid_builder: ObjectBuilder<u32>,
+}
+
+impl DeleteCitationBuilder {
+    pub fn new() -> Self {
+        DeleteCitationBuilder {
+            xml_helper: XMLHelper::new("DeleteCitation"),
+            deleted_citations: Vec::new(),
+            pmid_builder: ObjectBuilder::new("PMID"),
+        }
+    }
+
+    pub fn parse(&mut self, line: &str) -> Result<bool, String> {
+        let line = self.xml_helper.parse(line)?;
+        if line.is_empty() {
+            return Ok(self.xml_helper.tag_opened && !self.xml_helper.tag_closed
+                || self.xml_helper.just_closed);
+        }
+        self.pmid_builder.parse(line)?;
+        if self.pmid_builder.can_build() {
+            let pmid_builder =
+                core::mem::replace(&mut self.pmid_builder, ObjectBuilder::new("PMID"));
+            let version = parse_pmid_version(&pmid_builder.xml_helper)?;
+            self.deleted_citations.push(DeletedCitation {
+                pubmed_id: pmid_builder.build().ok_or_else(|| {
+                    "Found an empty PMID within a DeleteCitation block.".to_string()
+                })?,
+                version,
+            })
+        }
+
+        Ok(
+            self.xml_helper.tag_opened && !self.xml_helper.tag_closed
+                || self.xml_helper.just_closed,
+        )
+    }
+
+    pub fn build(self) -> Result<Vec<DeletedCitation>, String> {
+        if !self.xml_helper.can_build() {
+            return Err(format!(
+                concat!(
+                    "Build method was called on DeleteCitationBuilder ",
+                    "but the object is not yet ready to build. ",
+                    "The object currently looks like {:?}"
+                ),
+                self
+            ));
+        }
+        Ok(self.deleted_citations)
+    }
+
+    pub fn can_build(&self) -> bool {
+        self.xml_helper.can_build()
+    }
+}
+
+/// Returns the version of the PMID, which defaults to 1 when the attribute is missing.
+fn parse_pmid_version(xml_helper: &XMLHelper) -> Result<u16, String> {
+    xml_helper
+        .attributes
+        .get("Version")
+        .map_or(Ok(1), |version| {
+            version.parse::<u16>().map_err(|_| {
+                format!(
+                    "Something went wrong while trying to convert the PMID version `{}`.",
+                    version
+                )
+            })
+        })
+}
+
+struct IgnoreTag {
+    xml_helper: XMLHelper,
+}
+
+impl IgnoreTag {
+    pub fn new(tag: &str) -> Self {
+        IgnoreTag {
+            xml_helper: XMLHelper::new(tag),
+        }
+    }
+
+    pub fn with_reopening(tag: &str) -> Self {
+        IgnoreTag {
+            xml_helper: XMLHelper::with_reopening(tag),
+        }
+    }
+
+    pub fn parse(&mut self, line: &str) -> Result<bool, String> {
+        let _ = self.xml_helper.parse(line)?;
+        Ok(
+            self.xml_helper.tag_opened && !self.xml_helper.tag_closed
                 || self.xml_helper.just_closed,
         )
     }
@@ -1127,6 +1679,15 @@ impl IgnoreTags {
         }
     }
 
+    pub fn with_reopening(tags: &[&str]) -> Self {
+        IgnoreTags {
+            ignored_tags: tags
+                .iter()
+                .map(|tag| IgnoreTag::with_reopening(tag))
+                .collect(),
+        }
+    }
+
     pub fn parse(&mut self, line: &str) -> Result<bool, String> {
         for ignored_tag in self.ignored_tags.iter_mut() {
             if ignored_tag.parse(line)? {
@@ -1137,70 +1698,250 @@ impl IgnoreTags {
     }
 }
 
+/// Collects the elements not handled by any other builder, keyed by their path.
+///
+/// The path is relative to the outermost unhandled element, as in
+/// `SpaceFlightMission` or `Foo/Bar`, and attributes are stored as `Foo/@Type`.
+#[derive(Debug, Default)]
+struct ExtrasBuilder {
+    open_elements: Vec<(String, String)>,
+    extras: BTreeMap<String, Vec<String>>,
+}
+
+impl ExtrasBuilder {
+    pub fn new() -> Self {
+        ExtrasBuilder::default()
+    }
+
+    pub fn is_open(&self) -> bool {
+        !self.open_elements.is_empty()
+    }
+
+    fn path(&self) -> String {
+        self.open_elements
+            .iter()
+            .map(|(tag, _)| tag.as_str())
+            .collect::<Vec<&str>>()
+            .join("/")
+    }
+
+    fn push_text(&mut self, text: &str) {
+        let text = text.trim();
+        if text.is_empty() {
+            return;
+        }
+        match self.open_elements.last_mut() {
+            Some((_, buffer)) => {
+                if !buffer.is_empty() {
+                    buffer.push(' ');
+                }
+                buffer.push_str(text);
+            }
+            None => self
+                .extras
+                .entry("#text".to_string())
+                .or_default()
+                .push(decode_entities(text)),
+        }
+    }
+
+    fn open_element(&mut self, tag: &str) {
+        let tag = tag.trim_end_matches('/').trim();
+        let (name, attributes) = tag.split_once(' ').unwrap_or((tag, ""));
+        if self.open_elements.is_empty() {
+            log::warn!("Collecting the unhandled element {} into the extras.", name);
+        }
+        self.open_elements.push((name.to_string(), String::new()));
+        let path = self.path();
+        for attribute in attributes.split(' ') {
+            if let Some((key, value)) = attribute.split_once('=') {
+                self.extras
+                    .entry(format!("{}/@{}", path, key))
+                    .or_default()
+                    .push(decode_entities(value.trim_matches('"')));
+            }
+        }
+    }
+
+    fn close_element(&mut self) {
+        let path = self.path();
+        if let Some((_, text)) = self.open_elements.pop() {
+            if !text.is_empty() {
+                self.extras
+                    .entry(path)
+                    .or_default()
+                    .push(decode_entities(&text));
+            }
+        }
+    }
+
+    pub fn parse(&mut self, line: &str) -> Result<bool, String> {
+        let mut rest = line;
+        while let Some(start) = rest.find('<') {
+            self.push_text(&rest[..start]);
+            let end = match rest[start..].find('>') {
+                Some(end) => start + end,
+                None => {
+                    return Err(format!(
+                        "The unhandled line {} contains an unterminated tag.",
+                        line
+                    ))
+                }
+            };
+            let tag = &rest[start + 1..end];
+            if tag.starts_with('/') {
+                self.close_element();
+            } else if !tag.starts_with('?') && !tag.starts_with('!') {
+                self.open_element(tag);
+                if tag.ends_with('/') {
+                    self.close_element();
+                }
+            }
+            rest = &rest[end + 1..];
+        }
+        self.push_text(rest);
+        Ok(true)
+    }
+
+    pub fn build(self) -> BTreeMap<String, Vec<String>> {
+        self.extras
+    }
+}
+
 pub(crate) struct ArticleBuilder {
     xml_helper: XMLHelper,
+    medline_citation_helper: XMLHelper,
+    article_helper: XMLHelper,
     completion_date_builder: DateBuilder,
     revised_date_builder: DateBuilder,
     pmid_builder: ObjectBuilder<u32>,
     article_ids_builder: ArticleIdsBuilder,
     journal_builder: JournalBuilder,
+    medline_journal_info_builder: MedlineJournalInfoBuilder,
     title_builder: ObjectBuilder<String>,
     abstract_text_builder: AbstractBuilder,
     other_abstracts_builders: OtherAbstractBuilder,
-    language_builder: ObjectBuilder<String>,
+    vernacular_title_builder: ObjectBuilder<String>,
+    languages_builder: RepeatedObjectBuilder,
+    publication_types_builder: PublicationTypeListBuilder,
+    pagination_builder: PaginationBuilder,
+    elocation_ids_builder: ELocationIdsBuilder,
     chemical_list_builder: ChemicalListBuilder,
     mesh_list_builder: MeshListBuilder,
     suppl_mesh_list_builder: SupplMeshListBuilder,
     references_builder: ReferencesBuilder,
     pip_keywords_builder: KeywordListBuilder,
     kie_keywords_builder: KeywordListBuilder,
+    other_keyword_lists: IgnoreTag,
     gene_symbol_list_builder: GeneSymbolListBuilder,
+    citation_subsets_builder: RepeatedObjectBuilder,
+    publication_status_builder: ObjectBuilder<String>,
     ignored_tags: IgnoreTags,
+    extras_builder: ExtrasBuilder,
+    element_builders: Vec<(String, Box<dyn ElementBuilder>)>,
+    lenient: bool,
 }
 
 impl ArticleBuilder {
     pub fn new() -> Self {
         ArticleBuilder {
             xml_helper: XMLHelper::new("PubmedArticle"),
+            medline_citation_helper: XMLHelper::new("MedlineCitation"),
+            article_helper: XMLHelper::new("Article"),
             completion_date_builder: DateBuilder::new("DateCompleted"),
             revised_date_builder: DateBuilder::new("DateRevised"),
             pmid_builder: ObjectBuilder::new("PMID"),
             article_ids_builder: ArticleIdsBuilder::new(),
             journal_builder: JournalBuilder::new(),
+            medline_journal_info_builder: MedlineJournalInfoBuilder::new(),
             title_builder: ObjectBuilder::new("ArticleTitle"),
             abstract_text_builder: AbstractBuilder::new("Abstract"),
             other_abstracts_builders: OtherAbstractBuilder::new(),
-            language_builder: ObjectBuilder::new("Language"),
+            vernacular_title_builder: ObjectBuilder::new("VernacularTitle"),
+            languages_builder: RepeatedObjectBuilder::new("Language"),
+            publication_types_builder: PublicationTypeListBuilder::new(),
+            pagination_builder: PaginationBuilder::new(),
+            elocation_ids_builder: ELocationIdsBuilder::new(),
             chemical_list_builder: ChemicalListBuilder::new(),
             mesh_list_builder: MeshListBuilder::new(),
             suppl_mesh_list_builder: SupplMeshListBuilder::new(),
             references_builder: ReferencesBuilder::new(),
             pip_keywords_builder: KeywordListBuilder::new("PIP"),
             kie_keywords_builder: KeywordListBuilder::new("KIE"),
+            other_keyword_lists: IgnoreTag::with_reopening("KeywordList"),
             gene_symbol_list_builder: GeneSymbolListBuilder::new(),
+            citation_subsets_builder: RepeatedObjectBuilder::new("CitationSubset"),
+            publication_status_builder: ObjectBuilder::new("PublicationStatus"),
             ignored_tags: IgnoreTags::new(&[
                 "AuthorList",
-                "PublicationTypeList",
-                "MedlineJournalInfo",
                 "History",
                 "PersonalNameSubjectList",
                 "DataBankList",
                 "GrantList",
                 "CoiStatement",
-                "VernacularTitle",
                 "CommentsCorrectionsList",
                 "ArticleDate",
                 "InvestigatorList",
+                "ObjectList",
             ]),
+            extras_builder: ExtrasBuilder::new(),
+            element_builders: Vec::new(),
+            lenient: false,
         }
     }
 
+    /// Registers a builder whose values are stored in the extras under the provided name.
+    pub fn with_element_builder(mut self, name: &str, builder: Box<dyn ElementBuilder>) -> Self {
+        self.element_builders.push((name.to_string(), builder));
+        self
+    }
+
+    /// Offers the line to the registered builders, returning whether any of them handled it.
+    pub fn parse_registered(&mut self, line: &str) -> Result<bool, String> {
+        for (_, element_builder) in self.element_builders.iter_mut() {
+            if element_builder.parse(line)? {
+                return Ok(true);
+            }
+        }
+        Ok(false)
+    }
+
+    /// Sets whether the lines not handled by any parser are collected into the extras
+    /// instead of causing an error.
+    pub fn with_lenient(mut self, lenient: bool) -> Self {
+        self.lenient = lenient;
+        self
+    }
+
+    pub fn is_open(&self) -> bool {
+        self.xml_helper.tag_opened && !self.xml_helper.tag_closed
+    }
+
     pub fn parse(&mut self, line: &str) -> Result<(), String> {
         //println!("{}", line);
         let line = self.xml_helper.parse(line)?;
         if line.is_empty() {
             return Ok(());
         }
+        if self.extras_builder.is_open() {
+            self.extras_builder.parse(line)?;
+            return Ok(());
+        }
+        // Lines are only stripped of the MedlineCitation tags once it has been opened.
+        let line =
+            if self.medline_citation_helper.tag_opened || line.starts_with("<MedlineCitation") {
+                self.medline_citation_helper.parse(line)?
+            } else {
+                line
+            };
+        if line.is_empty() {
+            return Ok(());
+        }
+        // Only the attributes of the Article are kept, its elements have their own builders.
+        if line.starts_with("<Article ") {
+            self.article_helper.parse(line)?;
+            return Ok(());
+        }
         if !self.completion_date_builder.can_build() && self.completion_date_builder.parse(line)? {
             return Ok(());
         }
@@ -1216,6 +1957,11 @@ impl ArticleBuilder {
         if !self.journal_builder.can_build() && self.journal_builder.parse(line)? {
             return Ok(());
         }
+        if !self.medline_journal_info_builder.can_build()
+            && self.medline_journal_info_builder.parse(line)?
+        {
+            return Ok(());
+        }
         if !self.title_builder.can_build() && self.title_builder.parse(line)? {
             return Ok(());
         }
@@ -1228,7 +1974,31 @@ impl ArticleBuilder {
         if self.ignored_tags.parse(line)? {
             return Ok(());
         }
-        if self.language_builder.parse(line)? {
+        if !self.vernacular_title_builder.can_build()
+            && self.vernacular_title_builder.parse(line)?
+        {
+            return Ok(());
+        }
+        if self.languages_builder.parse(line)? {
+            return Ok(());
+        }
+        if !self.publication_types_builder.can_build()
+            && self.publication_types_builder.parse(line)?
+        {
+            return Ok(());
+        }
+        if self.citation_subsets_builder.parse(line)? {
+            return Ok(());
+        }
+        if !self.publication_status_builder.can_build()
+            && self.publication_status_builder.parse(line)?
+        {
+            return Ok(());
+        }
+        if !self.pagination_builder.can_build() && self.pagination_builder.parse(line)? {
+            return Ok(());
+        }
+        if self.elocation_ids_builder.parse(line)? {
             return Ok(());
         }
         if !self.chemical_list_builder.can_build() && self.chemical_list_builder.parse(line)? {
@@ -1251,9 +2021,16 @@ impl ArticleBuilder {
         if !self.kie_keywords_builder.can_build() && self.kie_keywords_builder.parse(line)? {
             return Ok(());
         }
+        // Keyword lists of other owners, such as the NOTNLM author keywords, are skipped.
+        if self.other_keyword_lists.parse(line)? {
+            return Ok(());
+        }
         if self.references_builder.parse(line)? {
             return Ok(());
         }
+        if self.lenient && self.extras_builder.parse(line)? {
+            return Ok(());
+        }
 
         Err(format!("The line {} was not handled by any parser.", line))
     }
@@ -1265,21 +2042,224 @@ impl ArticleBuilder {
         let mut keywords = self.pip_keywords_builder.build()?;
 
         keywords.extend(self.kie_keywords_builder.build()?);
+        let pmid_version = parse_pmid_version(&self.pmid_builder.xml_helper)?;
+        let medline_citation_attributes = &self.medline_citation_helper.attributes;
+        let elocation_ids = self.elocation_ids_builder.build()?;
+        let mut article_ids = self.article_ids_builder.build()?;
+        for elocation_id in elocation_ids.iter() {
+            if !article_ids.iter().any(|article_id| {
+                article_id.id_type == elocation_id.id_type
+                    && article_id.value.eq_ignore_ascii_case(&elocation_id.value)
+            }) {
+                article_ids.push(elocation_id.clone());
+            }
+        }
+        let mut extras = self.extras_builder.build();
+        for (name, element_builder) in self.element_builders {
+            if element_builder.can_build() {
+                extras
+                    .entry(name)
+                    .or_default()
+                    .extend(element_builder.build()?);
+            }
+        }
         Ok(Article {
+            status: medline_citation_attributes.get("Status").cloned(),
+            owner: medline_citation_attributes.get("Owner").cloned(),
+            indexing_method: medline_citation_attributes.get("IndexingMethod").cloned(),
+            citation_subsets: self.citation_subsets_builder.build()?,
             completion_date: self.completion_date_builder.build().ok(),
             revision_date: self.revised_date_builder.build().ok(),
-            pubmed_id: self.pmid_builder.build().unwrap(),
-            article_ids: self.article_ids_builder.build()?,
+            pubmed_id: self
+                .pmid_builder
+                .build()
+                .ok_or_else(|| "The article has no PMID.".to_string())?,
+            pmid_version,
+            article_ids,
+            elocation_ids,
+            pagination: if self.pagination_builder.can_build() {
+                Some(self.pagination_builder.build()?)
+            } else {
+                None
+            },
+            pub_model: self.article_helper.attributes.get("PubModel").cloned(),
             journal: self.journal_builder.build()?,
+            medline_journal_info: if self.medline_journal_info_builder.can_build() {
+                Some(self.medline_journal_info_builder.build()?)
+            } else {
+                None
+            },
             title: self.title_builder.build(),
+            vernacular_title: self.vernacular_title_builder.build(),
+            languages: self.languages_builder.build()?,
+            publication_types: self.publication_types_builder.build()?,
             abstract_text: self.abstract_text_builder.build().ok(),
             other_abstract_texts: self.other_abstracts_builders.build()?,
             chemical_list: self.chemical_list_builder.build()?,
             mesh_list: self.mesh_list_builder.build()?,
             gene_symbol_list: self.gene_symbol_list_builder.build()?,
+            extras,
             suppl_mesh_list: self.suppl_mesh_list_builder.build()?,
             references: self.references_builder.build()?,
             keywords,
+            publication_status: self.publication_status_builder.build(),
+        })
+    }
+
+    pub fn can_build(&self) -> bool {
+        self.xml_helper.can_build()
+    }
+}
+
+pub(crate) struct BookArticleBuilder {
+    xml_helper: XMLHelper,
+    pmid_builder: ObjectBuilder<u32>,
+    book_document_ids_builder: ArticleIdsBuilder,
+    book_data_ids_builder: ArticleIdsBuilder,
+    book_builder: BookBuilder,
+    location_label_builder: ObjectBuilder<String>,
+    title_builder: ObjectBuilder<String>,
+    vernacular_title_builder: ObjectBuilder<String>,
+    pagination_builder: PaginationBuilder,
+    languages_builder: RepeatedObjectBuilder,
+    abstract_text_builder: AbstractBuilder,
+    sections_builder: SectionsBuilder,
+    contribution_date_builder: DateBuilder,
+    revised_date_builder: DateBuilder,
+    citation_string_builder: ObjectBuilder<String>,
+    ignored_tags: IgnoreTags,
+}
+
+impl BookArticleBuilder {
+    pub fn new() -> Self {
+        BookArticleBuilder {
+            xml_helper: XMLHelper::new("PubmedBookArticle"),
+            pmid_builder: ObjectBuilder::new("PMID"),
+            book_document_ids_builder: ArticleIdsBuilder::new(),
+            book_data_ids_builder: ArticleIdsBuilder::new(),
+            book_builder: BookBuilder::new(),
+            location_label_builder: ObjectBuilder::new("LocationLabel"),
+            title_builder: ObjectBuilder::new("ArticleTitle"),
+            vernacular_title_builder: ObjectBuilder::new("VernacularTitle"),
+            pagination_builder: PaginationBuilder::new(),
+            languages_builder: RepeatedObjectBuilder::new("Language"),
+            abstract_text_builder: AbstractBuilder::new("Abstract"),
+            sections_builder: SectionsBuilder::new(),
+            contribution_date_builder: DateBuilder::new("ContributionDate"),
+            revised_date_builder: DateBuilder::new("DateRevised"),
+            citation_string_builder: ObjectBuilder::new("CitationString"),
+            ignored_tags: IgnoreTags::with_reopening(&[
+                "AuthorList",
+                "PublicationType",
+                "ELocationID",
+                "KeywordList",
+                "GrantList",
+                "ItemList",
+                "ReferenceList",
+                "History",
+                "PublicationStatus",
+                "CommentsCorrectionsList",
+                "InvestigatorList",
+                "ObjectList",
+            ]),
+        }
+    }
+
+    pub fn is_open(&self) -> bool {
+        self.xml_helper.tag_opened && !self.xml_helper.tag_closed
+    }
+
+    pub fn parse(&mut self, line: &str) -> Result<bool, String> {
+        let line = self.xml_helper.parse(line)?;
+        if line.is_empty() {
+            return Ok(self.xml_helper.tag_opened && !self.xml_helper.tag_closed
+                || self.xml_helper.just_closed);
+        }
+        if !self.pmid_builder.can_build() && self.pmid_builder.parse(line)? {
+            return Ok(true);
+        }
+        // Sections are checked early as they contain their own LocationLabel elements.
+        if !self.sections_builder.can_build() && self.sections_builder.parse(line)? {
+            return Ok(true);
+        }
+        if !self.book_document_ids_builder.can_build()
+            && self.book_document_ids_builder.parse(line)?
+        {
+            return Ok(true);
+        }
+        if !self.book_data_ids_builder.can_build() && self.book_data_ids_builder.parse(line)? {
+            return Ok(true);
+        }
+        if !self.book_builder.can_build() && self.book_builder.parse(line)? {
+            return Ok(true);
+        }
+        if !self.location_label_builder.can_build() && self.location_label_builder.parse(line)? {
+            return Ok(true);
+        }
+        if !self.title_builder.can_build() && self.title_builder.parse(line)? {
+            return Ok(true);
+        }
+        if !self.vernacular_title_builder.can_build()
+            && self.vernacular_title_builder.parse(line)?
+        {
+            return Ok(true);
+        }
+        if !self.pagination_builder.can_build() && self.pagination_builder.parse(line)? {
+            return Ok(true);
+        }
+        if self.languages_builder.parse(line)? {
+            return Ok(true);
+        }
+        if !self.abstract_text_builder.can_build() && self.abstract_text_builder.parse(line)? {
+            return Ok(true);
+        }
+        if !self.contribution_date_builder.can_build()
+            && self.contribution_date_builder.parse(line)?
+        {
+            return Ok(true);
+        }
+        if !self.revised_date_builder.can_build() && self.revised_date_builder.parse(line)? {
+            return Ok(true);
+        }
+        if !self.citation_string_builder.can_build() && self.citation_string_builder.parse(line)? {
+            return Ok(true);
+        }
+        if self.ignored_tags.parse(line)? {
+            return Ok(true);
+        }
+
+        Err(format!("The line {} was not handled by any parser.", line))
+    }
+
+    pub fn build(self) -> Result<BookArticle, String> {
+        if !self.xml_helper.can_build() {
+            return Err("The book article is not ready!".to_string());
+        }
+        let pmid_version = parse_pmid_version(&self.pmid_builder.xml_helper)?;
+        let mut article_ids = self.book_document_ids_builder.build()?;
+        article_ids.extend(self.book_data_ids_builder.build()?);
+        Ok(BookArticle {
+            pubmed_id: self
+                .pmid_builder
+                .build()
+                .ok_or_else(|| "The book article has no PMID.".to_string())?,
+            pmid_version,
+            article_ids,
+            book: self.book_builder.build()?,
+            location_label: self.location_label_builder.build(),
+            title: self.title_builder.build(),
+            vernacular_title: self.vernacular_title_builder.build(),
+            pagination: if self.pagination_builder.can_build() {
+                Some(self.pagination_builder.build()?)
+            } else {
+                None
+            },
+            languages: self.languages_builder.build()?,
+            abstract_text: self.abstract_text_builder.build().ok(),
+            sections: self.sections_builder.build()?,
+            contribution_date: self.contribution_date_builder.build().ok(),
+            revision_date: self.revised_date_builder.build().ok(),
+            citation_string: self.citation_string_builder.build(),
         })
     }
 
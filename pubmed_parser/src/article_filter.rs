@@ -0,0 +1,279 @@
+use crate::article::{Article, Mesh};
+use crate::article_sink::ArticleSink;
+use std::collections::BTreeMap;
+
+/// Predicate selecting the articles to export.
+///
+/// Names are compared regardless of their case. Filters are combined with
+/// [`ArticleFilter::and`], [`ArticleFilter::or`] and [`ArticleFilter::negate`].
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub enum ArticleFilter {
+    /// Matches every article.
+    #[default]
+    All,
+    /// Matches the articles whose journal issue was published within the inclusive range of years.
+    PublicationYears { from: Option<u16>, to: Option<u16> },
+    /// Matches the articles of any of the journals, given by title, ISO abbreviation or ISSN.
+    Journals(Vec<String>),
+    /// Matches the articles indexed with any of the MeSH descriptors, given by UI or name.
+    ///
+    /// When `major_topic` is set, the descriptor or one of its qualifiers must be a major topic.
+    Mesh {
+        terms: Vec<String>,
+        major_topic: bool,
+    },
+    /// Matches the articles of any of the publication types, given by UI or name.
+    PublicationTypes(Vec<String>),
+    /// Matches the articles written in any of the languages, given by ISO 639-2 code.
+    Languages(Vec<String>),
+    /// Matches the articles containing any of the terms in their title, abstracts or keywords.
+    ///
+    /// A term of several words matches them as a phrase, and a term ending with `*` matches
+    /// the words starting with it.
+    TitleAbstract(Vec<String>),
+    /// Matches the articles matched by all of the filters.
+    And(Vec<ArticleFilter>),
+    /// Matches the articles matched by any of the filters.
+    Or(Vec<ArticleFilter>),
+    /// Matches the articles not matched by the filter.
+    Not(Box<ArticleFilter>),
+}
+
+fn to_strings(values: &[&str]) -> Vec<String> {
+    values.iter().map(|value| value.to_string()).collect()
+}
+
+fn contains(values: &[String], value: &str) -> bool {
+    values
+        .iter()
+        .any(|expected| expected.eq_ignore_ascii_case(value))
+}
+
+impl ArticleFilter {
+    pub fn publication_years(from: Option<u16>, to: Option<u16>) -> Self {
+        ArticleFilter::PublicationYears { from, to }
+    }
+
+    pub fn journals(journals: &[&str]) -> Self {
+        ArticleFilter::Journals(to_strings(journals))
+    }
+
+    pub fn mesh(terms: &[&str]) -> Self {
+        ArticleFilter::Mesh {
+            terms: to_strings(terms),
+            major_topic: false,
+        }
+    }
+
+    pub fn mesh_major_topic(terms: &[&str]) -> Self {
+        ArticleFilter::Mesh {
+            terms: to_strings(terms),
+            major_topic: true,
+        }
+    }
+
+    pub fn publication_types(publication_types: &[&str]) -> Self {
+        ArticleFilter::PublicationTypes(to_strings(publication_types))
+    }
+
+    pub fn languages(languages: &[&str]) -> Self {
+        ArticleFilter::Languages(to_strings(languages))
+    }
+
+    pub fn title_abstract(terms: &[&str]) -> Self {
+        ArticleFilter::TitleAbstract(to_strings(terms))
+    }
+
+    /// Returns the filter matching the articles matched by both filters.
+    pub fn and(self, other: ArticleFilter) -> Self {
+        match (self, other) {
+            (ArticleFilter::All, filter) | (filter, ArticleFilter::All) => filter,
+            (ArticleFilter::And(mut filters), ArticleFilter::And(others)) => {
+                filters.extend(others);
+                ArticleFilter::And(filters)
+            }
+            (ArticleFilter::And(mut filters), filter) => {
+                filters.push(filter);
+                ArticleFilter::And(filters)
+            }
+            (filter, other) => ArticleFilter::And(vec![filter, other]),
+        }
+    }
+
+    /// Returns the filter matching the articles matched by either filter.
+    pub fn or(self, other: ArticleFilter) -> Self {
+        match (self, other) {
+            (ArticleFilter::Or(mut filters), ArticleFilter::Or(others)) => {
+                filters.extend(others);
+                ArticleFilter::Or(filters)
+            }
+            (ArticleFilter::Or(mut filters), filter) => {
+                filters.push(filter);
+                ArticleFilter::Or(filters)
+            }
+            (filter, other) => ArticleFilter::Or(vec![filter, other]),
+        }
+    }
+
+    /// Returns the filter matching the articles not matched by this one.
+    pub fn negate(self) -> Self {
+        match self {
+            ArticleFilter::Not(filter) => *filter,
+            filter => ArticleFilter::Not(Box::new(filter)),
+        }
+    }
+
+    /// Returns whether the filter matches every article.
+    pub fn is_all(&self) -> bool {
+        matches!(self, ArticleFilter::All)
+    }
+
+    pub fn matches(&self, article: &Article) -> bool {
+        match self {
+            ArticleFilter::All => true,
+            ArticleFilter::PublicationYears { from, to } => {
+                publication_year(article).is_some_and(|year| {
+                    from.is_none_or(|from| year >= from) && to.is_none_or(|to| year <= to)
+                })
+            }
+            ArticleFilter::Journals(journals) => {
+                let journal = article.journal();
+                [
+                    Some(journal.title()),
+                    journal.iso_abbreviation(),
+                    journal.issn(),
+                ]
+                .into_iter()
+                .flatten()
+                .any(|name| contains(journals, name))
+            }
+            ArticleFilter::Mesh { terms, major_topic } => article.mesh_list().iter().any(|mesh| {
+                (contains(terms, mesh.descriptor().code())
+                    || contains(terms, mesh.descriptor().name()))
+                    && (!major_topic || is_major_topic(mesh))
+            }),
+            ArticleFilter::PublicationTypes(publication_types) => {
+                article.publication_types().iter().any(|publication_type| {
+                    contains(publication_types, publication_type.code())
+                        || contains(publication_types, publication_type.name())
+                })
+            }
+            ArticleFilter::Languages(languages) => article
+                .languages()
+                .iter()
+                .any(|language| contains(languages, language)),
+            ArticleFilter::TitleAbstract(terms) => {
+                let texts = [
+                    article.title(),
+                    article.abstract_text().map(|text| text.text()),
+                ]
+                .into_iter()
+                .flatten()
+                .chain(
+                    article
+                        .other_abstract_texts()
+                        .iter()
+                        .map(|text| text.text()),
+                )
+                .chain(article.keywords().iter().map(|keyword| keyword.name()))
+                .map(words)
+                .collect::<Vec<_>>();
+                terms.iter().any(|term| {
+                    let truncated = term.ends_with('*');
+                    let phrase = words(term.trim_end_matches('*'));
+                    !phrase.is_empty()
+                        && texts
+                            .iter()
+                            .any(|text| contains_phrase(text, &phrase, truncated))
+                })
+            }
+            ArticleFilter::And(filters) => filters.iter().all(|filter| filter.matches(article)),
+            ArticleFilter::Or(filters) => filters.iter().any(|filter| filter.matches(article)),
+            ArticleFilter::Not(filter) => !filter.matches(article),
+        }
+    }
+}
+
+/// Returns the year the journal issue was published, read from the leading
+/// year of its free-text date, as in `1998 Dec-1999 Jan`, when it has no year.
+fn publication_year(article: &Article) -> Option<u16> {
+    let date = article.journal().journal_issue().pubblication_date();
+    date.year().or_else(|| {
+        date.medline_date()?
+            .get(..4)
+            .filter(|year| year.bytes().all(|byte| byte.is_ascii_digit()))?
+            .parse()
+            .ok()
+    })
+}
+
+/// Returns whether the descriptor of the heading, or one of its qualifiers, is a major topic.
+fn is_major_topic(mesh: &Mesh) -> bool {
+    mesh.descriptor().is_major_topic()
+        || mesh
+            .qualifiers()
+            .iter()
+            .any(|qualifier| qualifier.is_major_topic())
+}
+
+/// Returns the lowercase alphanumeric words of the text.
+fn words(text: &str) -> Vec<String> {
+    text.split(|character: char| !character.is_alphanumeric())
+        .filter(|word| !word.is_empty())
+        .map(str::to_lowercase)
+        .collect()
+}
+
+/// Returns whether the words contain the phrase, whose last word is only a prefix when truncated.
+fn contains_phrase(words: &[String], phrase: &[String], truncated: bool) -> bool {
+    words.windows(phrase.len()).any(|window| {
+        window
+            .iter()
+            .zip(phrase.iter())
+            .enumerate()
+            .all(|(index, (word, expected))| {
+                if truncated && index + 1 == phrase.len() {
+                    word.starts_with(expected.as_str())
+                } else {
+                    word == expected
+                }
+            })
+    })
+}
+
+/// Sink forwarding to another sink only the articles matched by a filter.
+pub struct FilteredSink {
+    filter: ArticleFilter,
+    sink: Box<dyn ArticleSink>,
+}
+
+impl FilteredSink {
+    pub fn new(filter: ArticleFilter, sink: Box<dyn ArticleSink>) -> Self {
+        FilteredSink { filter, sink }
+    }
+}
+
+impl ArticleSink for FilteredSink {
+    fn start_file(&mut self, file: &str) -> Result<(), String> {
+        self.sink.start_file(file)
+    }
+
+    fn consume(&mut self, article: &Article) -> Result<(), String> {
+        if self.filter.matches(article) {
+            self.sink.consume(article)?;
+        }
+        Ok(())
+    }
+
+    fn rows(&self) -> BTreeMap<String, u64> {
+        self.sink.rows()
+    }
+
+    fn flush(&mut self) -> Result<(), String> {
+        self.sink.flush()
+    }
+
+    fn finish(self: Box<Self>) -> Result<(), String> {
+        self.sink.finish()
+    }
+}
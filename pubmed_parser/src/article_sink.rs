@@ -0,0 +1,185 @@
+use crate::article::Article;
+use crate::pubmed_writer::PubmedWriter;
+use std::collections::BTreeMap;
+use std::fs::File;
+use std::io::{BufWriter, Write};
+use std::path::Path;
+
+/// Consumer of the parsed articles, such as an exporter.
+pub trait ArticleSink: Send {
+    /// Announces that the following articles are read from the provided file.
+    fn start_file(&mut self, _file: &str) -> Result<(), String> {
+        Ok(())
+    }
+    /// Consumes the provided article.
+    fn consume(&mut self, article: &Article) -> Result<(), String>;
+    /// Returns the number of rows, or articles, written so far to each output file, by file name.
+    fn rows(&self) -> BTreeMap<String, u64> {
+        BTreeMap::new()
+    }
+    /// Writes out the output buffered so far.
+    fn flush(&mut self) -> Result<(), String>;
+    /// Writes out the remaining output and closes the sink.
+    fn finish(self: Box<Self>) -> Result<(), String>;
+}
+
+/// Feeds the provided articles to the sink, then finishes it and returns its rows, see [`ArticleSink::rows`].
+pub fn export_articles(
+    articles: impl Iterator<Item = Result<Article, String>>,
+    mut sink: Box<dyn ArticleSink>,
+) -> Result<BTreeMap<String, u64>, String> {
+    for article in articles {
+        sink.consume(&article?)?;
+    }
+    finish_sink(sink)
+}
+
+/// Finishes the sink, returning the rows it wrote.
+pub(crate) fn finish_sink(sink: Box<dyn ArticleSink>) -> Result<BTreeMap<String, u64>, String> {
+    let rows = sink.rows();
+    sink.finish()?;
+    Ok(rows)
+}
+
+/// Returns the name of the file at the provided path.
+pub(crate) fn file_name(path: &str) -> String {
+    Path::new(path)
+        .file_name()
+        .map(|name| name.to_string_lossy().to_string())
+        .unwrap_or_default()
+}
+
+/// Sink forwarding each article to several sinks, in the order they were added.
+#[derive(Default)]
+pub struct FanOutSink {
+    sinks: Vec<Box<dyn ArticleSink>>,
+}
+
+impl FanOutSink {
+    pub fn new() -> Self {
+        FanOutSink::default()
+    }
+
+    pub fn with_sink(mut self, sink: Box<dyn ArticleSink>) -> Self {
+        self.sinks.push(sink);
+        self
+    }
+}
+
+impl ArticleSink for FanOutSink {
+    fn start_file(&mut self, file: &str) -> Result<(), String> {
+        for sink in self.sinks.iter_mut() {
+            sink.start_file(file)?;
+        }
+        Ok(())
+    }
+
+    fn consume(&mut self, article: &Article) -> Result<(), String> {
+        for sink in self.sinks.iter_mut() {
+            sink.consume(article)?;
+        }
+        Ok(())
+    }
+
+    fn rows(&self) -> BTreeMap<String, u64> {
+        self.sinks.iter().flat_map(|sink| sink.rows()).collect()
+    }
+
+    fn flush(&mut self) -> Result<(), String> {
+        for sink in self.sinks.iter_mut() {
+            sink.flush()?;
+        }
+        Ok(())
+    }
+
+    fn finish(self: Box<Self>) -> Result<(), String> {
+        for sink in self.sinks {
+            sink.finish()?;
+        }
+        Ok(())
+    }
+}
+
+/// Sink writing each article as a JSON object on its own line.
+pub struct JsonlSink<W: Write + Send> {
+    writer: W,
+    /// Name of the file written, reported with the number of lines.
+    file_name: Option<String>,
+    lines: u64,
+}
+
+impl JsonlSink<BufWriter<File>> {
+    pub fn from_path(path: &str) -> Result<Self, String> {
+        let file =
+            File::create(path).map_err(|err| format!("Unable to create {}: {}", path, err))?;
+        Ok(JsonlSink {
+            file_name: Some(file_name(path)),
+            ..JsonlSink::new(BufWriter::new(file))
+        })
+    }
+}
+
+impl<W: Write + Send> JsonlSink<W> {
+    pub fn new(writer: W) -> Self {
+        JsonlSink {
+            writer,
+            file_name: None,
+            lines: 0,
+        }
+    }
+}
+
+impl<W: Write + Send> ArticleSink for JsonlSink<W> {
+    fn consume(&mut self, article: &Article) -> Result<(), String> {
+        serde_json::to_writer(&mut self.writer, article).map_err(|err| {
+            format!(
+                "Unable to write the article {} as JSON: {}",
+                article.pubmed_id(),
+                err
+            )
+        })?;
+        self.writer
+            .write_all(b"\n")
+            .map_err(|err| format!("Unable to write the JSON lines: {}", err))?;
+        self.lines += 1;
+        Ok(())
+    }
+
+    fn rows(&self) -> BTreeMap<String, u64> {
+        self.file_name
+            .iter()
+            .map(|file_name| (file_name.clone(), self.lines))
+            .collect()
+    }
+
+    fn flush(&mut self) -> Result<(), String> {
+        self.writer
+            .flush()
+            .map_err(|err| format!("Unable to flush the JSON lines: {}", err))
+    }
+
+    fn finish(mut self: Box<Self>) -> Result<(), String> {
+        self.flush()
+    }
+}
+
+impl<W: Write + Send> ArticleSink for PubmedWriter<W> {
+    fn consume(&mut self, article: &Article) -> Result<(), String> {
+        self.write_article(article)
+    }
+
+    fn rows(&self) -> BTreeMap<String, u64> {
+        self.file_name
+            .iter()
+            .map(|file_name| (file_name.clone(), self.articles))
+            .collect()
+    }
+
+    fn flush(&mut self) -> Result<(), String> {
+        self.flush_writer()
+    }
+
+    fn finish(self: Box<Self>) -> Result<(), String> {
+        PubmedWriter::finish(*self).map(|_| ())
+    }
+}
@@ -0,0 +1,247 @@
+use crate::merge_pubmed::sort_by_sequence;
+use crate::pubmed_reader::PubmedReader;
+use flate2::read::MultiGzDecoder;
+use std::collections::HashMap;
+use std::fs::File;
+use std::io::{BufRead, BufReader, Read, Seek, SeekFrom};
+use std::path::Path;
+
+/// Reader over the XML of one of the files of an [`ArticleSource`].
+pub type SourceReader = PubmedReader<Box<dyn BufRead + Send>>;
+
+/// Set of PubMed XML files, either gzipped or not.
+pub trait ArticleSource: Send + Sync {
+    /// Returns the names of the files, sorted by their sequence number.
+    fn files(&self) -> &[String];
+    /// Returns the decompressed XML of the file with the provided name.
+    fn read(&self, file: &str) -> Result<Box<dyn BufRead + Send>, String>;
+    /// Returns a reader over the records of the file with the provided name.
+    fn open(&self, file: &str) -> Result<SourceReader, String> {
+        self.read(file)
+            .map(|reader| PubmedReader::new(reader, file))
+    }
+    /// Returns the paths of the files read from the file system, such as the tar archives.
+    fn paths(&self) -> Vec<String> {
+        self.files().to_vec()
+    }
+}
+
+impl<S: ArticleSource + ?Sized> ArticleSource for Box<S> {
+    fn files(&self) -> &[String] {
+        (**self).files()
+    }
+
+    fn read(&self, file: &str) -> Result<Box<dyn BufRead + Send>, String> {
+        (**self).read(file)
+    }
+
+    fn paths(&self) -> Vec<String> {
+        (**self).paths()
+    }
+}
+
+fn is_pubmed_file(name: &str) -> bool {
+    name.ends_with(".xml") || name.ends_with(".xml.gz")
+}
+
+/// Returns the XML of the file, which is decompressed when its name ends with `.gz`.
+fn decode(reader: impl Read + Send + 'static, name: &str) -> Box<dyn BufRead + Send> {
+    if name.ends_with(".gz") {
+        Box::new(BufReader::new(MultiGzDecoder::new(reader)))
+    } else {
+        Box::new(BufReader::new(reader))
+    }
+}
+
+/// Files on the file system.
+pub struct FileSource {
+    files: Vec<String>,
+}
+
+impl FileSource {
+    /// Returns the `.xml` and `.xml.gz` files within the provided directory.
+    pub fn from_directory(directory: &str) -> Result<Self, String> {
+        let files = std::fs::read_dir(directory)
+            .map_err(|err| format!("Unable to read the directory {}: {}", directory, err))?
+            .map(|entry| {
+                entry
+                    .map(|entry| entry.path().display().to_string())
+                    .map_err(|err| format!("Unable to read the directory {}: {}", directory, err))
+            })
+            .filter(|path| path.as_ref().map_or(true, |path| is_pubmed_file(path)))
+            .collect::<Result<Vec<String>, String>>()?;
+        Ok(FileSource::from_files(files))
+    }
+
+    /// Returns the provided files, which are opened only when read.
+    pub fn from_files(mut files: Vec<String>) -> Self {
+        sort_by_sequence(&mut files);
+        FileSource { files }
+    }
+
+    /// Returns the `.xml` and `.xml.gz` files matching the provided glob, as in `baseline/*.xml.gz`.
+    pub fn from_glob(pattern: &str) -> Result<Self, String> {
+        let files = glob::glob(pattern)
+            .map_err(|err| format!("The glob {} is not valid: {}", pattern, err))?
+            .map(|path| {
+                path.map(|path| path.display().to_string())
+                    .map_err(|err| format!("Unable to read the glob {}: {}", pattern, err))
+            })
+            .filter(|path| path.as_ref().map_or(true, |path| is_pubmed_file(path)))
+            .collect::<Result<Vec<String>, String>>()?;
+        Ok(FileSource::from_files(files))
+    }
+
+    /// Returns the files of the provided input: a directory, a glob or a single file.
+    ///
+    /// Tar archives are rejected, as their files cannot be opened on their own.
+    pub fn from_input(input: &str) -> Result<Self, String> {
+        if Path::new(input).is_dir() {
+            FileSource::from_directory(input)
+        } else if input.ends_with(".tar") {
+            Err(format!(
+                "The tar archive {} cannot be read as files on the file system.",
+                input
+            ))
+        } else if input.contains(['*', '?', '[']) {
+            FileSource::from_glob(input)
+        } else if Path::new(input).is_file() {
+            Ok(FileSource::from_files(vec![input.to_string()]))
+        } else {
+            Err(format!("Unable to find {}.", input))
+        }
+    }
+
+    /// Adds the files of the provided source, keeping the files in sequence order.
+    pub fn with_source(mut self, source: FileSource) -> Self {
+        self.files.extend(source.files);
+        sort_by_sequence(&mut self.files);
+        self
+    }
+}
+
+impl ArticleSource for FileSource {
+    fn files(&self) -> &[String] {
+        &self.files
+    }
+
+    fn read(&self, file: &str) -> Result<Box<dyn BufRead + Send>, String> {
+        let reader = File::open(file).map_err(|err| format!("Unable to open {}: {}", file, err))?;
+        Ok(decode(reader, file))
+    }
+}
+
+/// Files within a tar archive, read in place.
+///
+/// The archive is scanned once for the position of each `.xml` and `.xml.gz`
+/// file, which is then read directly from there.
+pub struct TarSource {
+    path: String,
+    files: Vec<String>,
+    positions: HashMap<String, (u64, u64)>,
+}
+
+impl TarSource {
+    pub fn from_path(path: &str) -> Result<Self, String> {
+        let read_error =
+            |err: std::io::Error| format!("Unable to read the archive {}: {}", path, err);
+        let file = File::open(path).map_err(|err| format!("Unable to open {}: {}", path, err))?;
+        let mut archive = tar::Archive::new(file);
+        let mut positions = HashMap::new();
+        for entry in archive.entries().map_err(read_error)? {
+            let entry = entry.map_err(read_error)?;
+            let name = entry.path().map_err(read_error)?.display().to_string();
+            if entry.header().entry_type().is_file() && is_pubmed_file(&name) {
+                positions.insert(name, (entry.raw_file_position(), entry.size()));
+            }
+        }
+        let mut files = positions.keys().cloned().collect::<Vec<String>>();
+        sort_by_sequence(&mut files);
+        Ok(TarSource {
+            path: path.to_string(),
+            files,
+            positions,
+        })
+    }
+}
+
+impl ArticleSource for TarSource {
+    fn files(&self) -> &[String] {
+        &self.files
+    }
+
+    fn read(&self, file: &str) -> Result<Box<dyn BufRead + Send>, String> {
+        let (position, size) = *self
+            .positions
+            .get(file)
+            .ok_or_else(|| format!("The archive {} has no file {}.", self.path, file))?;
+        let mut archive = File::open(&self.path)
+            .map_err(|err| format!("Unable to open {}: {}", self.path, err))?;
+        archive
+            .seek(SeekFrom::Start(position))
+            .map_err(|err| format!("Unable to read the archive {}: {}", self.path, err))?;
+        Ok(decode(archive.take(size), file))
+    }
+
+    fn paths(&self) -> Vec<String> {
+        vec![self.path.clone()]
+    }
+}
+
+/// Files of several sources, such as the baseline and the update files, sorted together.
+///
+/// A file name found in more than one source is read from the first of them.
+#[derive(Default)]
+pub struct CombinedSource {
+    sources: Vec<Box<dyn ArticleSource>>,
+    files: Vec<String>,
+    owners: HashMap<String, usize>,
+}
+
+impl CombinedSource {
+    pub fn new() -> Self {
+        CombinedSource::default()
+    }
+
+    pub fn with_source(mut self, source: Box<dyn ArticleSource>) -> Self {
+        for file in source.files() {
+            if !self.owners.contains_key(file) {
+                self.owners.insert(file.clone(), self.sources.len());
+                self.files.push(file.clone());
+            }
+        }
+        sort_by_sequence(&mut self.files);
+        self.sources.push(source);
+        self
+    }
+}
+
+impl ArticleSource for CombinedSource {
+    fn files(&self) -> &[String] {
+        &self.files
+    }
+
+    fn read(&self, file: &str) -> Result<Box<dyn BufRead + Send>, String> {
+        let owner = *self
+            .owners
+            .get(file)
+            .ok_or_else(|| format!("No source has the file {}.", file))?;
+        self.sources[owner].read(file)
+    }
+
+    fn paths(&self) -> Vec<String> {
+        self.sources
+            .iter()
+            .flat_map(|source| source.paths())
+            .collect()
+    }
+}
+
+/// Returns the source of the provided input: a directory, a tar archive, a glob or a single file.
+pub fn open_source(input: &str) -> Result<Box<dyn ArticleSource>, String> {
+    if input.ends_with(".tar") && !Path::new(input).is_dir() {
+        Ok(Box::new(TarSource::from_path(input)?))
+    } else {
+        Ok(Box::new(FileSource::from_input(input)?))
+    }
+}
@@ -0,0 +1,267 @@
+use crate::article::Article;
+use crate::article_sink::{file_name, ArticleSink};
+use memmap2::Mmap;
+use rayon::prelude::*;
+use std::collections::{BTreeMap, HashMap};
+use std::fs::File;
+use std::io::{BufWriter, Write};
+
+/// Marks both the start and the end of an article store.
+const MAGIC: &[u8; 8] = b"PMSTORE1";
+/// Size of the footer: the offset of the index, the number of its entries and the magic.
+const FOOTER_SIZE: usize = 8 + 8 + MAGIC.len();
+/// Size of an entry of the index: a PMID and the offset of its record.
+const ENTRY_SIZE: usize = 4 + 8;
+
+/// Writer of an article store, a binary file of the articles that is read
+/// without parsing any XML.
+///
+/// The store starts with a magic number, followed by a record per article,
+/// made of its length as a little-endian `u32` and the article encoded with
+/// bincode. An index of the offset of the record of each PMID, sorted by PMID,
+/// and a footer locating the index end the file.
+pub struct ArticleStoreWriter<W: Write + Send> {
+    writer: W,
+    position: u64,
+    offsets: HashMap<u32, u64>,
+    /// Name of the file written, reported with the number of articles.
+    file_name: Option<String>,
+    articles: u64,
+}
+
+impl ArticleStoreWriter<BufWriter<File>> {
+    pub fn from_path(path: &str) -> Result<Self, String> {
+        let file =
+            File::create(path).map_err(|err| format!("Unable to create {}: {}", path, err))?;
+        Ok(ArticleStoreWriter {
+            file_name: Some(file_name(path)),
+            ..ArticleStoreWriter::new(BufWriter::new(file))?
+        })
+    }
+}
+
+impl<W: Write + Send> ArticleStoreWriter<W> {
+    pub fn new(mut writer: W) -> Result<Self, String> {
+        writer
+            .write_all(MAGIC)
+            .map_err(|err| format!("Unable to write the article store: {}", err))?;
+        Ok(ArticleStoreWriter {
+            writer,
+            position: MAGIC.len() as u64,
+            offsets: HashMap::new(),
+            file_name: None,
+            articles: 0,
+        })
+    }
+
+    /// Appends the article, which replaces in the index any earlier article of the same PMID.
+    pub fn write_article(&mut self, article: &Article) -> Result<(), String> {
+        let record = bincode::serialize(article).map_err(|err| {
+            format!(
+                "Unable to encode the article {}: {}",
+                article.pubmed_id(),
+                err
+            )
+        })?;
+        let length = u32::try_from(record.len())
+            .map_err(|_| format!("The article {} is too large.", article.pubmed_id()))?;
+        self.write(&length.to_le_bytes())?;
+        self.write(&record)?;
+        self.offsets.insert(article.pubmed_id(), self.position);
+        self.position += (4 + record.len()) as u64;
+        self.articles += 1;
+        Ok(())
+    }
+
+    fn write(&mut self, bytes: &[u8]) -> Result<(), String> {
+        self.writer
+            .write_all(bytes)
+            .map_err(|err| format!("Unable to write the article store: {}", err))
+    }
+
+    /// Writes the index and the footer, then returns the underlying writer.
+    pub fn finish(mut self) -> Result<W, String> {
+        let mut offsets = self.offsets.drain().collect::<Vec<(u32, u64)>>();
+        offsets.sort_unstable();
+        let index_position = self.position;
+        for (pubmed_id, offset) in offsets.iter() {
+            self.write(&pubmed_id.to_le_bytes())?;
+            self.write(&offset.to_le_bytes())?;
+        }
+        self.write(&index_position.to_le_bytes())?;
+        self.write(&(offsets.len() as u64).to_le_bytes())?;
+        self.write(MAGIC)?;
+        self.writer
+            .flush()
+            .map_err(|err| format!("Unable to flush the article store: {}", err))?;
+        Ok(self.writer)
+    }
+}
+
+impl<W: Write + Send> ArticleSink for ArticleStoreWriter<W> {
+    fn consume(&mut self, article: &Article) -> Result<(), String> {
+        self.write_article(article)
+    }
+
+    fn rows(&self) -> BTreeMap<String, u64> {
+        self.file_name
+            .iter()
+            .map(|file_name| (file_name.clone(), self.articles))
+            .collect()
+    }
+
+    fn flush(&mut self) -> Result<(), String> {
+        self.writer
+            .flush()
+            .map_err(|err| format!("Unable to flush the article store: {}", err))
+    }
+
+    fn finish(self: Box<Self>) -> Result<(), String> {
+        ArticleStoreWriter::finish(*self).map(|_| ())
+    }
+}
+
+/// Memory-mapped reader of an article store written by [`ArticleStoreWriter`].
+pub struct ArticleStore {
+    path: String,
+    mmap: Mmap,
+    /// End of the records and start of the index.
+    index_position: usize,
+    len: usize,
+}
+
+fn read_u32(bytes: &[u8], position: usize) -> u32 {
+    u32::from_le_bytes(bytes[position..position + 4].try_into().unwrap())
+}
+
+fn read_u64(bytes: &[u8], position: usize) -> u64 {
+    u64::from_le_bytes(bytes[position..position + 8].try_into().unwrap())
+}
+
+impl ArticleStore {
+    pub fn from_path(path: &str) -> Result<Self, String> {
+        let file = File::open(path).map_err(|err| format!("Unable to open {}: {}", path, err))?;
+        // SAFETY: the store is only read, and is expected not to be modified while mapped.
+        let mmap = unsafe { Mmap::map(&file) }
+            .map_err(|err| format!("Unable to map {}: {}", path, err))?;
+        let invalid = || format!("{} is not a valid article store.", path);
+        if mmap.len() < MAGIC.len() + FOOTER_SIZE
+            || &mmap[..MAGIC.len()] != MAGIC
+            || &mmap[mmap.len() - MAGIC.len()..] != MAGIC
+        {
+            return Err(invalid());
+        }
+        let footer = mmap.len() - FOOTER_SIZE;
+        let index_position = usize::try_from(read_u64(&mmap, footer)).map_err(|_| invalid())?;
+        let len = usize::try_from(read_u64(&mmap, footer + 8)).map_err(|_| invalid())?;
+        if index_position < MAGIC.len()
+            || len
+                .checked_mul(ENTRY_SIZE)
+                .and_then(|size| size.checked_add(index_position))
+                != Some(footer)
+        {
+            return Err(invalid());
+        }
+        Ok(ArticleStore {
+            path: path.to_string(),
+            mmap,
+            index_position,
+            len,
+        })
+    }
+
+    /// Returns the number of distinct PMIDs within the store.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    fn index_entry(&self, entry: usize) -> (u32, u64) {
+        let position = self.index_position + entry * ENTRY_SIZE;
+        (
+            read_u32(&self.mmap, position),
+            read_u64(&self.mmap, position + 4),
+        )
+    }
+
+    /// Returns the PMIDs within the store, sorted.
+    pub fn pubmed_ids(&self) -> impl Iterator<Item = u32> + '_ {
+        (0..self.len).map(|entry| self.index_entry(entry).0)
+    }
+
+    /// Decodes the record at the provided position, returning it with the position of the next one.
+    fn read_record(&self, position: u64) -> Result<(Article, usize), String> {
+        let invalid = || {
+            format!(
+                "The record at byte {} of {} is not valid.",
+                position, self.path
+            )
+        };
+        let start = usize::try_from(position)
+            .ok()
+            .filter(|&position| position >= MAGIC.len())
+            .and_then(|position| position.checked_add(4))
+            .filter(|&start| start <= self.index_position)
+            .ok_or_else(invalid)?;
+        let end = start
+            .checked_add(read_u32(&self.mmap, start - 4) as usize)
+            .filter(|&end| end <= self.index_position)
+            .ok_or_else(invalid)?;
+        let record = self.mmap.get(start..end).ok_or_else(invalid)?;
+        let article =
+            bincode::deserialize(record).map_err(|err| format!("{} {}", invalid(), err))?;
+        Ok((article, end))
+    }
+
+    /// Returns the article of the PMID, decoding only its own record.
+    pub fn get(&self, pubmed_id: u32) -> Result<Option<Article>, String> {
+        let mut low = 0;
+        let mut high = self.len;
+        while low < high {
+            let middle = (low + high) / 2;
+            let (entry_pubmed_id, offset) = self.index_entry(middle);
+            match entry_pubmed_id.cmp(&pubmed_id) {
+                std::cmp::Ordering::Less => low = middle + 1,
+                std::cmp::Ordering::Greater => high = middle,
+                std::cmp::Ordering::Equal => {
+                    return self.read_record(offset).map(|(article, _)| Some(article))
+                }
+            }
+        }
+        Ok(None)
+    }
+
+    /// Returns every article of the store, in the order they were written.
+    ///
+    /// The articles replaced by a later article of the same PMID are included.
+    pub fn articles(&self) -> impl Iterator<Item = Result<Article, String>> + '_ {
+        let mut position = MAGIC.len();
+        let mut failed = false;
+        std::iter::from_fn(move || {
+            if failed || position >= self.index_position {
+                return None;
+            }
+            match self.read_record(position as u64) {
+                Ok((article, next)) => {
+                    position = next;
+                    Some(Ok(article))
+                }
+                Err(err) => {
+                    failed = true;
+                    Some(Err(err))
+                }
+            }
+        })
+    }
+
+    /// Returns the current article of each PMID, decoded in parallel in no particular order.
+    pub fn par_articles(&self) -> impl ParallelIterator<Item = Result<Article, String>> + '_ {
+        (0..self.len).into_par_iter().map(|entry| {
+            self.read_record(self.index_entry(entry).1)
+                .map(|(article, _)| article)
+        })
+    }
+}
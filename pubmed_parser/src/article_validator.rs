@@ -0,0 +1,233 @@
+use crate::article::{Article, Date, ExportOptions};
+use std::collections::HashMap;
+use std::fmt;
+
+/// Broken invariant of an article, located by the file and line where the article ends.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Violation {
+    pub(crate) file: String,
+    pub(crate) line: usize,
+    pub(crate) pubmed_id: u32,
+    pub(crate) message: String,
+}
+
+impl Violation {
+    pub fn file(&self) -> &str {
+        &self.file
+    }
+
+    pub fn line(&self) -> usize {
+        self.line
+    }
+
+    pub fn pubmed_id(&self) -> u32 {
+        self.pubmed_id
+    }
+
+    pub fn message(&self) -> &str {
+        &self.message
+    }
+}
+
+impl fmt::Display for Violation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{}:{} PMID {}: {}",
+            self.file, self.line, self.pubmed_id, self.message
+        )
+    }
+}
+
+const MONTHS: [&str; 12] = [
+    "Jan", "Feb", "Mar", "Apr", "May", "Jun", "Jul", "Aug", "Sep", "Oct", "Nov", "Dec",
+];
+
+/// Returns whether the UI is the prefix followed by digits only, as in `D000818`.
+fn is_ui(ui: &str, prefixes: &[char]) -> bool {
+    let mut characters = ui.chars();
+    characters
+        .next()
+        .is_some_and(|prefix| prefixes.contains(&prefix))
+        && !characters.as_str().is_empty()
+        && characters.all(|character| character.is_ascii_digit())
+}
+
+/// Returns the current year, as told by the system clock.
+fn current_year() -> u16 {
+    let seconds = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .map(|duration| duration.as_secs())
+        .unwrap_or_default();
+    // The average length of a Gregorian year is 365.2425 days.
+    (1970 + seconds / 31_556_952) as u16
+}
+
+/// Checker of the invariants of the parsed articles, such as the uniqueness of their PMIDs.
+///
+/// The articles are expected to come from a baseline, where no PMID appears twice.
+pub struct ArticleValidator {
+    export_options: ExportOptions,
+    from_year: u16,
+    to_year: u16,
+    files: Vec<String>,
+    /// File index and line of the first article of each PMID.
+    pubmed_ids: HashMap<u32, (usize, usize)>,
+}
+
+impl Default for ArticleValidator {
+    fn default() -> Self {
+        ArticleValidator {
+            export_options: ExportOptions::default(),
+            from_year: 1700,
+            to_year: current_year() + 1,
+            files: Vec::new(),
+            pubmed_ids: HashMap::new(),
+        }
+    }
+}
+
+impl ArticleValidator {
+    /// Returns a validator accepting the years from 1700 to the next one.
+    pub fn new() -> Self {
+        ArticleValidator::default()
+    }
+
+    /// Sets the options of the nodes and edges whose names are checked.
+    pub fn with_export_options(mut self, export_options: ExportOptions) -> Self {
+        self.export_options = export_options;
+        self
+    }
+
+    /// Sets the inclusive range of the plausible years of the dates.
+    pub fn with_years(mut self, from_year: u16, to_year: u16) -> Self {
+        self.from_year = from_year;
+        self.to_year = to_year;
+        self
+    }
+
+    /// Returns the violations of the article, read from the file and ending at the line.
+    pub fn validate(&mut self, file: &str, line: usize, article: &Article) -> Vec<Violation> {
+        let mut messages = Vec::new();
+        let pubmed_id = article.pubmed_id();
+
+        if self.files.last().is_none_or(|last| last != file) {
+            self.files.push(file.to_string());
+        }
+        let file_index = self.files.len() - 1;
+        if let Some(&(first_file, first_line)) = self.pubmed_ids.get(&pubmed_id) {
+            messages.push(format!(
+                "The PMID was already read at {}:{}.",
+                self.files[first_file], first_line
+            ));
+        } else {
+            self.pubmed_ids.insert(pubmed_id, (file_index, line));
+        }
+
+        for mesh in article.mesh_list() {
+            if !is_ui(mesh.descriptor().code(), &['D']) {
+                messages.push(format!(
+                    "The MeSH descriptor UI {:?} is not valid.",
+                    mesh.descriptor().code()
+                ));
+            }
+            for qualifier in mesh.qualifiers() {
+                if !is_ui(qualifier.code(), &['Q']) {
+                    messages.push(format!(
+                        "The MeSH qualifier UI {:?} is not valid.",
+                        qualifier.code()
+                    ));
+                }
+            }
+        }
+        for suppl_mesh in article.suppl_mesh_list() {
+            if !is_ui(suppl_mesh.code(), &['C']) {
+                messages.push(format!(
+                    "The supplementary MeSH UI {:?} is not valid.",
+                    suppl_mesh.code()
+                ));
+            }
+        }
+        for chemical in article.chemical_list() {
+            if !is_ui(chemical.code(), &['C', 'D']) {
+                messages.push(format!(
+                    "The chemical UI {:?} is not valid.",
+                    chemical.code()
+                ));
+            }
+        }
+
+        for (name, date) in [
+            (
+                "publication date",
+                Some(article.journal().journal_issue().pubblication_date()),
+            ),
+            ("completion date", article.completion_date()),
+            ("revision date", article.revision_date()),
+        ] {
+            if let Some(date) = date {
+                if let Some(message) = self.check_date(date) {
+                    messages.push(format!("The {} {}", name, message));
+                }
+            }
+        }
+
+        for reference in article.references() {
+            if let Some(value) = reference.article_id("pubmed") {
+                if !value.chars().all(|character| character.is_ascii_digit())
+                    || !value.parse::<u32>().is_ok_and(|pubmed_id| pubmed_id > 0)
+                {
+                    messages.push(format!("The referenced PMID {:?} is not valid.", value));
+                }
+            }
+        }
+
+        for node in article.to_nodes_with_options(&self.export_options) {
+            if node.node_name().trim().is_empty() {
+                messages.push(format!("A {} node has an empty name.", node.node_type()));
+            }
+        }
+        for edge in article.to_edges_with_options(&self.export_options) {
+            if edge.subject().trim().is_empty() || edge.object().trim().is_empty() {
+                messages.push(format!("A {} edge has an empty end.", edge.edge_type()));
+            }
+        }
+
+        messages
+            .into_iter()
+            .map(|message| Violation {
+                file: file.to_string(),
+                line,
+                pubmed_id,
+                message,
+            })
+            .collect()
+    }
+
+    /// Returns the end of the message describing why the date is not plausible, if it is not.
+    fn check_date(&self, date: &Date) -> Option<String> {
+        if let Some(year) = date.year() {
+            if year < self.from_year || year > self.to_year {
+                return Some(format!(
+                    "has the year {}, outside of {} to {}.",
+                    year, self.from_year, self.to_year
+                ));
+            }
+        }
+        if let Some(month) = date.month() {
+            let is_valid = MONTHS.iter().any(|name| name.eq_ignore_ascii_case(month))
+                || month
+                    .parse::<u8>()
+                    .is_ok_and(|month| (1..=12).contains(&month));
+            if !is_valid {
+                return Some(format!("has the month {:?}.", month));
+            }
+        }
+        if let Some(day) = date.day() {
+            if !(1..=31).contains(&day) {
+                return Some(format!("has the day {}.", day));
+            }
+        }
+        None
+    }
+}
@@ -0,0 +1,432 @@
+use clap::{Args, Parser, Subcommand, ValueEnum};
+use indicatif::{ProgressBar, ProgressIterator};
+use pubmed_parser::*;
+use std::process::ExitCode;
+
+#[derive(Parser)]
+#[command(
+    name = "pubmed-parser",
+    version,
+    about = "Parses the PubMed baseline into a graph."
+)]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, ValueEnum)]
+enum Format {
+    /// The `nodes.tsv` and `edges.tsv` of the graph.
+    Tsv,
+    /// The articles as JSON lines, in `articles.jsonl`.
+    Jsonl,
+    /// The articles as PubMed XML, in `articles.xml`.
+    Xml,
+    /// The articles as a binary article store, in `articles.bin`.
+    Store,
+    /// The statistics of the articles and of their graph, in `statistics.json` and `statistics.txt`.
+    Stats,
+}
+
+/// Criteria of the articles to keep, all of which must be met.
+#[derive(Args)]
+struct FilterArgs {
+    /// PubMed search query of the articles to keep, as in `"neoplasms"[majr] AND review[pt]`.
+    #[arg(long, value_parser = parse_query)]
+    query: Option<ArticleFilter>,
+    /// Inclusive range of publication years of the articles to keep, as in `2010:2022` or `2010:`.
+    #[arg(long, value_parser = parse_years)]
+    years: Option<(Option<u16>, Option<u16>)>,
+    /// Journal of the articles to keep, by title, ISO abbreviation or ISSN.
+    #[arg(long = "journal")]
+    journals: Vec<String>,
+    /// MeSH descriptor of the articles to keep, by UI or name.
+    #[arg(long = "mesh")]
+    meshes: Vec<String>,
+    /// Keeps only the articles where the `--mesh` descriptors are major topics.
+    #[arg(long, requires = "meshes")]
+    mesh_major_topic: bool,
+    /// Publication type of the articles to keep, by UI or name, such as `Review`.
+    #[arg(long = "publication-type")]
+    publication_types: Vec<String>,
+    /// Languages of the articles to keep, such as `eng,fre`.
+    #[arg(long = "language", value_delimiter = ',')]
+    languages: Vec<String>,
+}
+
+impl FilterArgs {
+    fn to_filter(&self) -> ArticleFilter {
+        let mut filter = self.query.clone().unwrap_or_default();
+        if let Some((from, to)) = self.years {
+            filter = filter.and(ArticleFilter::publication_years(from, to));
+        }
+        if !self.journals.is_empty() {
+            filter = filter.and(ArticleFilter::Journals(self.journals.clone()));
+        }
+        if !self.meshes.is_empty() {
+            filter = filter.and(ArticleFilter::Mesh {
+                terms: self.meshes.clone(),
+                major_topic: self.mesh_major_topic,
+            });
+        }
+        if !self.publication_types.is_empty() {
+            filter = filter.and(ArticleFilter::PublicationTypes(
+                self.publication_types.clone(),
+            ));
+        }
+        if !self.languages.is_empty() {
+            filter = filter.and(ArticleFilter::Languages(self.languages.clone()));
+        }
+        filter
+    }
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Parses PubMed files into a graph, and records its provenance in `manifest.json`.
+    Parse {
+        /// Directories, tar archives, globs or files of the baseline.
+        #[arg(required = true)]
+        inputs: Vec<String>,
+        /// Directory, tar archive, glob or file of the updates, applied on top of the baseline.
+        #[arg(long)]
+        updates: Option<String>,
+        /// Directory where the graph is written.
+        #[arg(short, long, default_value = ".")]
+        output: String,
+        /// Outputs written in a single pass, such as `tsv,jsonl`.
+        #[arg(
+            short,
+            long = "format",
+            value_enum,
+            value_delimiter = ',',
+            default_value = "tsv"
+        )]
+        formats: Vec<Format>,
+        /// Number of threads, by default one per core.
+        #[arg(short, long)]
+        threads: Option<usize>,
+        /// Applies the NFKC normalization and collapses the whitespace of the texts.
+        #[arg(long)]
+        normalize: bool,
+        #[arg(long, default_value = "nodes.tsv")]
+        nodes_file: String,
+        #[arg(long, default_value = "edges.tsv")]
+        edges_file: String,
+        /// Node types to keep, such as `Paper,Mesh`, by default all of them.
+        #[arg(long, value_delimiter = ',')]
+        node_types: Option<Vec<String>>,
+        /// Edge types to keep, such as `Citation,PaperToMesh`, by default all of them.
+        #[arg(long, value_delimiter = ',')]
+        edge_types: Option<Vec<String>>,
+        /// Prefix of the names of the nodes of a type, as in `Mesh=MESH:`.
+        #[arg(long = "prefix", value_parser = parse_prefix)]
+        prefixes: Vec<(String, String)>,
+        #[command(flatten)]
+        filter: Box<FilterArgs>,
+    },
+    /// Keeps the first row of each node name of a nodes TSV.
+    DedupNodes { source: String, destination: String },
+    /// Keeps the edges of an edges TSV whose subject and object are both in a nodes TSV.
+    FilterEdges {
+        nodes: String,
+        edges: String,
+        destination: String,
+    },
+    /// Counts the records of each PubMed file of a directory, tar archive, glob or file.
+    Stats { input: String },
+    /// Reads each PubMed file of a directory, tar archive, glob or file and reports the errors found
+    /// and the articles breaking an invariant, such as a PMID read twice or an invalid MeSH UI.
+    Validate { input: String },
+    /// Writes the index of the location of each article of the PubMed files, by PMID.
+    Index {
+        /// Directories, globs or files of the baseline and the updates.
+        #[arg(required = true)]
+        inputs: Vec<String>,
+        /// Path of the index.
+        #[arg(short, long, default_value = "pubmed_index.tsv")]
+        output: String,
+    },
+    /// Prints the articles of the PMIDs, read through an index.
+    Get {
+        /// Path of the index.
+        index: String,
+        #[arg(required = true)]
+        pubmed_ids: Vec<u32>,
+        /// Prints the parsed articles as JSON lines instead of their XML.
+        #[arg(long)]
+        json: bool,
+    },
+}
+
+fn parse_prefix(prefix: &str) -> Result<(String, String), String> {
+    prefix
+        .split_once('=')
+        .map(|(node_type, prefix)| (node_type.to_string(), prefix.to_string()))
+        .ok_or_else(|| format!("The prefix {} is not formatted as TYPE=PREFIX.", prefix))
+}
+
+/// Parses a range of years such as `2010:2022`, where either bound may be omitted.
+fn parse_years(years: &str) -> Result<(Option<u16>, Option<u16>), String> {
+    let parse_year = |year: &str| {
+        if year.is_empty() {
+            Ok(None)
+        } else {
+            year.parse::<u16>()
+                .map(Some)
+                .map_err(|err| format!("The year {} is not valid: {}", year, err))
+        }
+    };
+    let (from, to) = years.split_once(':').unwrap_or((years, years));
+    Ok((parse_year(from)?, parse_year(to)?))
+}
+
+fn parse(
+    inputs: &[String],
+    updates: Option<&str>,
+    formats: &[Format],
+    threads: Option<usize>,
+    normalize: bool,
+    config: &GraphExportConfig,
+    filter: ArticleFilter,
+) -> Result<(), String> {
+    if let Some(threads) = threads {
+        rayon::ThreadPoolBuilder::new()
+            .num_threads(threads)
+            .build_global()
+            .map_err(|err| format!("Unable to start {} threads: {}", threads, err))?;
+    }
+    let normalization = if normalize {
+        TextNormalization::all()
+    } else {
+        TextNormalization::default()
+    };
+
+    std::fs::create_dir_all(config.output_directory()).map_err(|err| {
+        format!(
+            "Unable to create the directory {}: {}",
+            config.output_directory().display(),
+            err
+        )
+    })?;
+    let mut sink = FanOutSink::new();
+    if formats.contains(&Format::Tsv) {
+        sink = sink.with_sink(Box::new(TsvGraphSink::new(config)?));
+    }
+    if formats.contains(&Format::Jsonl) {
+        let path = config.output_directory().join("articles.jsonl");
+        sink = sink.with_sink(Box::new(JsonlSink::from_path(&path.display().to_string())?));
+    }
+    if formats.contains(&Format::Xml) {
+        let path = config.output_directory().join("articles.xml");
+        sink = sink.with_sink(Box::new(PubmedWriter::from_path(
+            &path.display().to_string(),
+        )?));
+    }
+    if formats.contains(&Format::Store) {
+        let path = config.output_directory().join("articles.bin");
+        sink = sink.with_sink(Box::new(ArticleStoreWriter::from_path(
+            &path.display().to_string(),
+        )?));
+    }
+    if formats.contains(&Format::Stats) {
+        sink = sink.with_sink(Box::new(StatisticsSink::new(config)));
+    }
+    let sink: Box<dyn ArticleSink> = if filter.is_all() {
+        Box::new(sink)
+    } else {
+        Box::new(FilteredSink::new(filter, Box::new(sink)))
+    };
+
+    let mut source = CombinedSource::new();
+    for input in inputs.iter().map(String::as_str).chain(updates) {
+        source = source.with_source(open_source(input)?);
+    }
+    let manifest = RunManifest::start(config, &normalization)
+        .with_arguments(std::env::args().collect())
+        .with_inputs(&source)?;
+    let rows = if updates.is_some() {
+        parse_source_merged_into(source, &normalization, sink)?
+    } else {
+        parse_source_into(&source, &normalization, sink)?
+    };
+    rows.iter()
+        .fold(manifest, |manifest, (file_name, rows)| {
+            manifest.with_rows(file_name, *rows)
+        })
+        .finish()
+        .write(&config.manifest_path())
+}
+
+fn stats(input: &str) -> Result<(), String> {
+    let source = open_source(input)?;
+    let progress_bar = ProgressBar::new(source.files().len() as u64);
+    let mut totals = (0, 0, 0);
+
+    println!("file\tarticles\tbook_articles\tdeletions");
+    for file in source.files().iter().progress_with(progress_bar) {
+        let mut counts = (0, 0, 0);
+        for event in source.open(file)? {
+            match event? {
+                PubmedEvent::Article(_) => counts.0 += 1,
+                PubmedEvent::BookArticle(_) => counts.1 += 1,
+                PubmedEvent::Deletion(_) => counts.2 += 1,
+            }
+        }
+        println!("{}\t{}\t{}\t{}", file, counts.0, counts.1, counts.2);
+        totals = (
+            totals.0 + counts.0,
+            totals.1 + counts.1,
+            totals.2 + counts.2,
+        );
+    }
+    println!("total\t{}\t{}\t{}", totals.0, totals.1, totals.2);
+    Ok(())
+}
+
+/// Returns the number of errors and violations found, each of them printed on stdout.
+fn validate(input: &str) -> Result<usize, String> {
+    let source = open_source(input)?;
+    let progress_bar = ProgressBar::new(source.files().len() as u64);
+    let mut validator = ArticleValidator::new();
+    let mut errors = 0;
+
+    for file in source.files().iter().progress_with(progress_bar) {
+        let mut reader = source.open(file)?;
+        while let Some(event) = reader.next() {
+            match event {
+                Ok(PubmedEvent::Article(article)) => {
+                    for violation in validator.validate(file, reader.line_number(), &article) {
+                        println!("{}", violation);
+                        errors += 1;
+                    }
+                }
+                Ok(PubmedEvent::BookArticle(_) | PubmedEvent::Deletion(_)) => {}
+                Err(err) => {
+                    println!("{}", err);
+                    errors += 1;
+                }
+            }
+        }
+    }
+    Ok(errors)
+}
+
+fn index(inputs: &[String], output: &str) -> Result<(), String> {
+    let mut source = FileSource::from_files(Vec::new());
+    for input in inputs {
+        source = source.with_source(FileSource::from_input(input)?);
+    }
+    let index = PubmedIndex::build(&source)?;
+    index.write(output)?;
+    eprintln!("Indexed {} articles in {}.", index.len(), output);
+    Ok(())
+}
+
+/// Returns whether every PMID was found.
+fn get(index: &str, pubmed_ids: &[u32], json: bool) -> Result<bool, String> {
+    let index = PubmedIndex::from_path(index)?;
+    let mut found = true;
+    for &pubmed_id in pubmed_ids {
+        let printed = if json {
+            index.get_article(pubmed_id)?.map(|article| {
+                serde_json::to_string(&article).map_err(|err| {
+                    format!("Unable to write the article {} as JSON: {}", pubmed_id, err)
+                })
+            })
+        } else {
+            index.get_xml(pubmed_id)?.map(Ok)
+        };
+        match printed {
+            Some(printed) => println!("{}", printed?),
+            None => {
+                eprintln!("The PMID {} is not indexed.", pubmed_id);
+                found = false;
+            }
+        }
+    }
+    Ok(found)
+}
+
+fn run(cli: Cli) -> Result<bool, String> {
+    match cli.command {
+        Command::Parse {
+            inputs,
+            updates,
+            output,
+            formats,
+            threads,
+            normalize,
+            nodes_file,
+            edges_file,
+            node_types,
+            edge_types,
+            prefixes,
+            filter,
+        } => {
+            let mut config = GraphExportConfig::new()
+                .with_output_directory(&output)
+                .with_nodes_file_name(&nodes_file)
+                .with_edges_file_name(&edges_file);
+            if let Some(node_types) = node_types {
+                config = config
+                    .with_node_types(&node_types.iter().map(String::as_str).collect::<Vec<_>>());
+            }
+            if let Some(edge_types) = edge_types {
+                config = config
+                    .with_edge_types(&edge_types.iter().map(String::as_str).collect::<Vec<_>>());
+            }
+            for (node_type, prefix) in prefixes {
+                config = config.with_prefix(&node_type, &prefix);
+            }
+            parse(
+                &inputs,
+                updates.as_deref(),
+                &formats,
+                threads,
+                normalize,
+                &config,
+                filter.to_filter(),
+            )?
+        }
+        Command::DedupNodes {
+            source,
+            destination,
+        } => {
+            let rows = dedup_nodes(&source, &destination)?;
+            eprintln!("Wrote {} nodes to {}.", rows, destination);
+        }
+        Command::FilterEdges {
+            nodes,
+            edges,
+            destination,
+        } => {
+            let rows = filter_edges(&nodes, &edges, &destination)?;
+            eprintln!("Wrote {} edges to {}.", rows, destination);
+        }
+        Command::Stats { input } => stats(&input)?,
+        Command::Validate { input } => {
+            let errors = validate(&input)?;
+            eprintln!("Found {} errors.", errors);
+            return Ok(errors == 0);
+        }
+        Command::Index { inputs, output } => index(&inputs, &output)?,
+        Command::Get {
+            index,
+            pubmed_ids,
+            json,
+        } => return get(&index, &pubmed_ids, json),
+    }
+    Ok(true)
+}
+
+fn main() -> ExitCode {
+    match run(Cli::parse()) {
+        Ok(true) => ExitCode::SUCCESS,
+        Ok(false) => ExitCode::FAILURE,
+        Err(err) => {
+            eprintln!("{}", err);
+            ExitCode::FAILURE
+        }
+    }
+}
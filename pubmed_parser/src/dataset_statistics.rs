@@ -0,0 +1,290 @@
+use crate::article::Article;
+use crate::article_sink::ArticleSink;
+use crate::graph_export::GraphExportConfig;
+use std::collections::{BTreeMap, HashMap};
+use std::fmt::Write as _;
+use std::path::PathBuf;
+
+/// Statistics of a set of articles and of the graph exported from them.
+///
+/// The nodes and edges are counted as the rows written by a [`TsvGraphSink`](crate::TsvGraphSink)
+/// with the same configuration, so a node shared by several articles is counted once per article.
+#[derive(Debug, Clone)]
+pub struct DatasetStatistics {
+    config: GraphExportConfig,
+    top_journals: usize,
+    file: Option<String>,
+    articles: u64,
+    articles_per_file: BTreeMap<String, u64>,
+    articles_per_year: BTreeMap<u16, u64>,
+    articles_without_year: u64,
+    articles_with_title: u64,
+    articles_with_abstract: u64,
+    articles_with_mesh: u64,
+    articles_with_references: u64,
+    journals: HashMap<String, u64>,
+    node_types: BTreeMap<String, u64>,
+    edge_types: BTreeMap<String, u64>,
+}
+
+impl DatasetStatistics {
+    pub fn new(config: &GraphExportConfig) -> Self {
+        DatasetStatistics {
+            config: config.clone(),
+            top_journals: 10,
+            file: None,
+            articles: 0,
+            articles_per_file: BTreeMap::new(),
+            articles_per_year: BTreeMap::new(),
+            articles_without_year: 0,
+            articles_with_title: 0,
+            articles_with_abstract: 0,
+            articles_with_mesh: 0,
+            articles_with_references: 0,
+            journals: HashMap::new(),
+            node_types: BTreeMap::new(),
+            edge_types: BTreeMap::new(),
+        }
+    }
+
+    /// Sets how many journals the report lists, 10 by default.
+    pub fn with_top_journals(mut self, top_journals: usize) -> Self {
+        self.top_journals = top_journals;
+        self
+    }
+
+    /// Attributes the following articles to the provided file.
+    pub fn start_file(&mut self, file: &str) {
+        self.file = Some(file.to_string());
+        self.articles_per_file.entry(file.to_string()).or_insert(0);
+    }
+
+    pub fn add_article(&mut self, article: &Article) {
+        self.articles += 1;
+        if let Some(file) = &self.file {
+            *self.articles_per_file.entry(file.clone()).or_insert(0) += 1;
+        }
+        match article.journal().journal_issue().pubblication_date().year() {
+            Some(year) => *self.articles_per_year.entry(year).or_insert(0) += 1,
+            None => self.articles_without_year += 1,
+        }
+        if article.title().is_some_and(|title| !title.is_empty()) {
+            self.articles_with_title += 1;
+        }
+        if article.abstract_text().is_some() {
+            self.articles_with_abstract += 1;
+        }
+        if !article.mesh_list().is_empty() {
+            self.articles_with_mesh += 1;
+        }
+        if !article.references().is_empty() {
+            self.articles_with_references += 1;
+        }
+        *self
+            .journals
+            .entry(article.journal().title().to_string())
+            .or_insert(0) += 1;
+
+        let options = self.config.export_options();
+        for node in article.to_nodes_with_options(options) {
+            if self.config.includes_node_type(node.node_type()) {
+                *self
+                    .node_types
+                    .entry(node.node_type().to_string())
+                    .or_insert(0) += 1;
+            }
+        }
+        for edge in article.to_edges_with_options(options) {
+            if self.config.includes_edge_type(edge.edge_type()) {
+                *self
+                    .edge_types
+                    .entry(edge.edge_type().to_string())
+                    .or_insert(0) += 1;
+            }
+        }
+    }
+
+    pub fn articles(&self) -> u64 {
+        self.articles
+    }
+
+    /// Returns the number of articles of each file, including the files without any.
+    pub fn articles_per_file(&self) -> &BTreeMap<String, u64> {
+        &self.articles_per_file
+    }
+
+    /// Returns the number of articles by year of publication of their journal issue.
+    pub fn articles_per_year(&self) -> &BTreeMap<u16, u64> {
+        &self.articles_per_year
+    }
+
+    pub fn articles_without_year(&self) -> u64 {
+        self.articles_without_year
+    }
+
+    pub fn articles_with_title(&self) -> u64 {
+        self.articles_with_title
+    }
+
+    pub fn articles_with_abstract(&self) -> u64 {
+        self.articles_with_abstract
+    }
+
+    pub fn articles_with_mesh(&self) -> u64 {
+        self.articles_with_mesh
+    }
+
+    pub fn articles_with_references(&self) -> u64 {
+        self.articles_with_references
+    }
+
+    /// Returns the share of the articles that the count represents, between 0 and 1.
+    pub fn share(&self, count: u64) -> f64 {
+        if self.articles == 0 {
+            0.0
+        } else {
+            count as f64 / self.articles as f64
+        }
+    }
+
+    /// Returns the journals with the most articles, by decreasing number of articles then by title.
+    pub fn top_journals(&self) -> Vec<(&str, u64)> {
+        let mut journals = self
+            .journals
+            .iter()
+            .map(|(journal, count)| (journal.as_str(), *count))
+            .collect::<Vec<_>>();
+        journals.sort_by(|left, right| right.1.cmp(&left.1).then_with(|| left.0.cmp(right.0)));
+        journals.truncate(self.top_journals);
+        journals
+    }
+
+    pub fn node_types(&self) -> &BTreeMap<String, u64> {
+        &self.node_types
+    }
+
+    pub fn edge_types(&self) -> &BTreeMap<String, u64> {
+        &self.edge_types
+    }
+
+    fn with_share(&self, count: u64) -> serde_json::Value {
+        serde_json::json!({ "count": count, "share": self.share(count) })
+    }
+
+    pub fn to_json(&self) -> serde_json::Value {
+        serde_json::json!({
+            "articles": self.articles,
+            "articles_per_file": self.articles_per_file,
+            "articles_per_year": self.articles_per_year,
+            "articles_without_year": self.articles_without_year,
+            "articles_with_title": self.with_share(self.articles_with_title),
+            "articles_with_abstract": self.with_share(self.articles_with_abstract),
+            "articles_with_mesh": self.with_share(self.articles_with_mesh),
+            "articles_with_references": self.with_share(self.articles_with_references),
+            "top_journals": self
+                .top_journals()
+                .into_iter()
+                .map(|(journal, count)| serde_json::json!({ "journal": journal, "articles": count }))
+                .collect::<Vec<_>>(),
+            "node_types": self.node_types,
+            "edge_types": self.edge_types,
+        })
+    }
+
+    /// Returns the report as tab-separated sections, meant to be diffed between releases.
+    pub fn to_text(&self) -> String {
+        let mut text = String::new();
+        // Writing to a String cannot fail.
+        let _ = writeln!(text, "articles\t{}", self.articles);
+        for (name, count) in [
+            ("title", self.articles_with_title),
+            ("abstract", self.articles_with_abstract),
+            ("MeSH", self.articles_with_mesh),
+            ("references", self.articles_with_references),
+        ] {
+            let _ = writeln!(
+                text,
+                "with {}\t{}\t{:.2}%",
+                name,
+                count,
+                100.0 * self.share(count)
+            );
+        }
+        let _ = writeln!(text, "\narticles per file");
+        for (file, count) in self.articles_per_file.iter() {
+            let _ = writeln!(text, "{}\t{}", file, count);
+        }
+        let _ = writeln!(text, "\narticles per year");
+        for (year, count) in self.articles_per_year.iter() {
+            let _ = writeln!(text, "{}\t{}", year, count);
+        }
+        if self.articles_without_year > 0 {
+            let _ = writeln!(text, "unknown\t{}", self.articles_without_year);
+        }
+        let _ = writeln!(text, "\ntop journals");
+        for (journal, count) in self.top_journals() {
+            let _ = writeln!(text, "{}\t{}", journal, count);
+        }
+        let _ = writeln!(text, "\nnodes per type");
+        for (node_type, count) in self.node_types.iter() {
+            let _ = writeln!(text, "{}\t{}", node_type, count);
+        }
+        let _ = writeln!(text, "\nedges per type");
+        for (edge_type, count) in self.edge_types.iter() {
+            let _ = writeln!(text, "{}\t{}", edge_type, count);
+        }
+        text
+    }
+}
+
+/// Sink writing the statistics of the articles to `statistics.json` and `statistics.txt`
+/// within the output directory of the configuration.
+pub struct StatisticsSink {
+    statistics: DatasetStatistics,
+    json_path: PathBuf,
+    text_path: PathBuf,
+}
+
+impl StatisticsSink {
+    pub fn new(config: &GraphExportConfig) -> Self {
+        StatisticsSink::from_statistics(DatasetStatistics::new(config))
+    }
+
+    pub fn from_statistics(statistics: DatasetStatistics) -> Self {
+        let directory = statistics.config.output_directory().to_path_buf();
+        StatisticsSink {
+            statistics,
+            json_path: directory.join("statistics.json"),
+            text_path: directory.join("statistics.txt"),
+        }
+    }
+}
+
+impl ArticleSink for StatisticsSink {
+    fn start_file(&mut self, file: &str) -> Result<(), String> {
+        self.statistics.start_file(file);
+        Ok(())
+    }
+
+    fn consume(&mut self, article: &Article) -> Result<(), String> {
+        self.statistics.add_article(article);
+        Ok(())
+    }
+
+    fn flush(&mut self) -> Result<(), String> {
+        Ok(())
+    }
+
+    fn finish(self: Box<Self>) -> Result<(), String> {
+        let json = serde_json::to_string_pretty(&self.statistics.to_json())
+            .map_err(|err| format!("Unable to write the statistics as JSON: {}", err))?;
+        for (path, content) in [
+            (&self.json_path, json + "\n"),
+            (&self.text_path, self.statistics.to_text()),
+        ] {
+            std::fs::write(path, content)
+                .map_err(|err| format!("Unable to write {}: {}", path.display(), err))?;
+        }
+        Ok(())
+    }
+}
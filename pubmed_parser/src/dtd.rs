@@ -0,0 +1,96 @@
+/// The versions of the PubMed DTD with a bundled test fixture.
+///
+/// The version is read from the system identifier of the DOCTYPE, as in
+/// `https://dtd.nlm.nih.gov/ncbi/pubmed/out/pubmed_230101.dtd`. The builders
+/// accept the elements of every version, whichever version is declared:
+///
+/// - `pubmed_190101.dtd` has no `IndexingMethod` attribute on the `MedlineCitation`,
+///   whose older citations still carry `OtherID`, `NumberOfReferences`,
+///   `PersonalNameSubjectList`, `SpaceFlightMission`, `GeneralNote` and the
+///   `OtherAbstract` and `KeywordList` of the PIP and KIE owners.
+/// - `pubmed_230101.dtd` adds the `IndexingMethod` attribute, `Automated` or `Curated`,
+///   and the `PubmedData` may hold an `ObjectList` and titled, nested `ReferenceList`s.
+/// - `pubmed_250101.dtd` adds the `NLM-AUTO` owner of the `KeywordList`, whose
+///   keywords are skipped like the `NOTNLM` ones.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum DtdVersion {
+    Pubmed2019,
+    Pubmed2023,
+    Pubmed2025,
+}
+
+impl DtdVersion {
+    /// Returns every supported version, from the oldest to the newest.
+    pub fn all() -> &'static [DtdVersion] {
+        &[
+            DtdVersion::Pubmed2019,
+            DtdVersion::Pubmed2023,
+            DtdVersion::Pubmed2025,
+        ]
+    }
+
+    /// Returns the file name of the DTD, as in `pubmed_230101.dtd`.
+    pub fn file_name(&self) -> &'static str {
+        match self {
+            DtdVersion::Pubmed2019 => "pubmed_190101.dtd",
+            DtdVersion::Pubmed2023 => "pubmed_230101.dtd",
+            DtdVersion::Pubmed2025 => "pubmed_250101.dtd",
+        }
+    }
+
+    pub fn year(&self) -> u16 {
+        match self {
+            DtdVersion::Pubmed2019 => 2019,
+            DtdVersion::Pubmed2023 => 2023,
+            DtdVersion::Pubmed2025 => 2025,
+        }
+    }
+
+    /// Returns the DOCTYPE declaring the version, as found at the top of the PubMed files.
+    pub fn doctype(&self) -> String {
+        format!(
+            concat!(
+                "<!DOCTYPE PubmedArticleSet PUBLIC ",
+                "\"-//NLM//DTD PubMedArticle, 1st January {}//EN\" ",
+                "\"https://dtd.nlm.nih.gov/ncbi/pubmed/out/{}\">"
+            ),
+            self.year(),
+            self.file_name()
+        )
+    }
+
+    /// Returns the date of the version, as in `230101`.
+    fn date(&self) -> u32 {
+        date(self.file_name()).unwrap_or_default()
+    }
+
+    /// Returns the file name of the PubMed DTD declared by the DOCTYPE line, if any.
+    pub fn declared_file_name(line: &str) -> Option<&str> {
+        line.split(['"', '/'])
+            .find(|part| part.starts_with("pubmed_") && part.ends_with(".dtd"))
+    }
+
+    /// Returns the version declared by the provided DOCTYPE line.
+    ///
+    /// A DOCTYPE that does not reference a PubMed DTD yields `None`, while a PubMed
+    /// DTD without a fixture, as `pubmed_240101.dtd`, yields the latest version
+    /// preceding it, or the oldest version if it precedes all of them.
+    pub fn from_doctype(line: &str) -> Option<DtdVersion> {
+        let declared_date = date(DtdVersion::declared_file_name(line)?)?;
+        DtdVersion::all()
+            .iter()
+            .rev()
+            .find(|version| version.date() <= declared_date)
+            .or_else(|| DtdVersion::all().first())
+            .copied()
+    }
+}
+
+/// Returns the date of the DTD file name, as `230101` for `pubmed_230101.dtd`.
+fn date(file_name: &str) -> Option<u32> {
+    file_name
+        .strip_prefix("pubmed_")?
+        .strip_suffix(".dtd")?
+        .parse()
+        .ok()
+}
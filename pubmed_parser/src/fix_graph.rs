@@ -0,0 +1,93 @@
+use std::collections::HashSet;
+use std::fs::File;
+use std::io::{BufRead, BufReader, BufWriter, Write};
+
+fn open(path: &str) -> Result<BufReader<File>, String> {
+    File::open(path)
+        .map(BufReader::new)
+        .map_err(|err| format!("Unable to open {}: {}", path, err))
+}
+
+fn create(path: &str) -> Result<BufWriter<File>, String> {
+    File::create(path)
+        .map(BufWriter::new)
+        .map_err(|err| format!("Unable to create {}: {}", path, err))
+}
+
+/// Writes the nodes of the provided TSV keeping only the first row of each node name.
+///
+/// Tabs within the descriptions are replaced by spaces. Returns the number of rows written.
+pub fn dedup_nodes(source: &str, destination: &str) -> Result<usize, String> {
+    let mut unique_nodes: HashSet<String> = HashSet::new();
+    let mut writer = create(destination)?;
+    let mut rows = 0;
+
+    for (line_number, line) in open(source)?.lines().enumerate() {
+        let line = line.map_err(|err| format!("Unable to read {}: {}", source, err))?;
+        let splits = line.splitn(3, '\t').collect::<Vec<&str>>();
+        if splits.len() < 3 {
+            return Err(format!(
+                "The line {} of {} is not a node.",
+                line_number + 1,
+                source
+            ));
+        }
+        if unique_nodes.insert(splits[0].to_string()) {
+            writeln!(
+                writer,
+                "{}\t{}\t{}",
+                splits[0],
+                splits[1],
+                splits[2].replace('\t', " ").trim()
+            )
+            .map_err(|err| format!("Unable to write {}: {}", destination, err))?;
+            rows += 1;
+        }
+    }
+
+    writer
+        .flush()
+        .map_err(|err| format!("Unable to flush {}: {}", destination, err))?;
+    Ok(rows)
+}
+
+/// Writes the edges of the provided TSV whose subject and object are both within the nodes TSV.
+///
+/// Returns the number of rows written.
+pub fn filter_edges(nodes: &str, edges: &str, destination: &str) -> Result<usize, String> {
+    let unique_nodes = open(nodes)?
+        .lines()
+        .map(|line| {
+            line.map(|line| line.split('\t').next().unwrap_or_default().to_string())
+                .map_err(|err| format!("Unable to read {}: {}", nodes, err))
+        })
+        .collect::<Result<HashSet<String>, String>>()?;
+    let mut writer = create(destination)?;
+    let mut rows = 0;
+
+    for (line_number, line) in open(edges)?.lines().enumerate() {
+        let line = line.map_err(|err| format!("Unable to read {}: {}", edges, err))?;
+        let splits = line.splitn(3, '\t').collect::<Vec<&str>>();
+        if splits.len() < 3 {
+            return Err(format!(
+                "The line {} of {} is not an edge.",
+                line_number + 1,
+                edges
+            ));
+        }
+        if !splits[0].is_empty()
+            && !splits[2].is_empty()
+            && unique_nodes.contains(splits[0])
+            && unique_nodes.contains(splits[2])
+        {
+            writeln!(writer, "{}", line)
+                .map_err(|err| format!("Unable to write {}: {}", destination, err))?;
+            rows += 1;
+        }
+    }
+
+    writer
+        .flush()
+        .map_err(|err| format!("Unable to flush {}: {}", destination, err))?;
+    Ok(rows)
+}
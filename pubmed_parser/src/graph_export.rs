@@ -0,0 +1,244 @@
+use crate::article::*;
+use crate::article_sink::ArticleSink;
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, BTreeSet};
+use std::fs::File;
+use std::io::{BufWriter, Write};
+use std::path::{Path, PathBuf};
+
+/// Where the graph is written and which of its nodes and edges are kept.
+///
+/// By default every node and edge is written to `nodes.tsv` and `edges.tsv`
+/// within the current directory. The node and edge types are filtered
+/// independently: an edge whose type is kept is written even when the type of
+/// one of its ends is not.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct GraphExportConfig {
+    output_directory: PathBuf,
+    nodes_file_name: String,
+    edges_file_name: String,
+    node_types: Option<BTreeSet<String>>,
+    edge_types: Option<BTreeSet<String>>,
+    prefixes: BTreeMap<String, String>,
+    export_options: ExportOptions,
+}
+
+impl Default for GraphExportConfig {
+    fn default() -> Self {
+        GraphExportConfig {
+            output_directory: PathBuf::from("."),
+            nodes_file_name: "nodes.tsv".to_string(),
+            edges_file_name: "edges.tsv".to_string(),
+            node_types: None,
+            edge_types: None,
+            prefixes: BTreeMap::new(),
+            export_options: ExportOptions::default(),
+        }
+    }
+}
+
+impl GraphExportConfig {
+    pub fn new() -> Self {
+        GraphExportConfig::default()
+    }
+
+    pub fn with_output_directory(mut self, output_directory: impl AsRef<Path>) -> Self {
+        self.output_directory = output_directory.as_ref().to_path_buf();
+        self
+    }
+
+    pub fn with_nodes_file_name(mut self, nodes_file_name: &str) -> Self {
+        self.nodes_file_name = nodes_file_name.to_string();
+        self
+    }
+
+    pub fn with_edges_file_name(mut self, edges_file_name: &str) -> Self {
+        self.edges_file_name = edges_file_name.to_string();
+        self
+    }
+
+    /// Keeps only the nodes of the provided types, such as `Paper` or `Mesh`.
+    pub fn with_node_types(mut self, node_types: &[&str]) -> Self {
+        self.node_types = Some(
+            node_types
+                .iter()
+                .map(|node_type| node_type.to_string())
+                .collect(),
+        );
+        self
+    }
+
+    /// Keeps only the edges of the provided types, such as `Citation` or `PaperToMesh`.
+    pub fn with_edge_types(mut self, edge_types: &[&str]) -> Self {
+        self.edge_types = Some(
+            edge_types
+                .iter()
+                .map(|edge_type| edge_type.to_string())
+                .collect(),
+        );
+        self
+    }
+
+    /// Prepends the prefix to the names of the nodes of the provided type, as in `MESH:D000001`.
+    ///
+    /// The ends of the edges referring to those nodes are prefixed alike.
+    pub fn with_prefix(mut self, node_type: &str, prefix: &str) -> Self {
+        self.prefixes
+            .insert(node_type.to_string(), prefix.to_string());
+        self
+    }
+
+    pub fn with_export_options(mut self, export_options: ExportOptions) -> Self {
+        self.export_options = export_options;
+        self
+    }
+
+    pub fn output_directory(&self) -> &Path {
+        &self.output_directory
+    }
+
+    pub fn nodes_path(&self) -> PathBuf {
+        self.output_directory.join(&self.nodes_file_name)
+    }
+
+    pub fn edges_path(&self) -> PathBuf {
+        self.output_directory.join(&self.edges_file_name)
+    }
+
+    /// Returns the path of the manifest recording the provenance of the graph.
+    pub fn manifest_path(&self) -> PathBuf {
+        self.output_directory.join("manifest.json")
+    }
+
+    pub fn export_options(&self) -> &ExportOptions {
+        &self.export_options
+    }
+
+    pub fn includes_node_type(&self, node_type: &str) -> bool {
+        self.node_types
+            .as_ref()
+            .is_none_or(|node_types| node_types.contains(node_type))
+    }
+
+    pub fn includes_edge_type(&self, edge_type: &str) -> bool {
+        self.edge_types
+            .as_ref()
+            .is_none_or(|edge_types| edge_types.contains(edge_type))
+    }
+
+    /// Returns the name of the node of the provided type, prefixed as configured.
+    pub fn node_name(&self, node_type: &str, name: &str) -> String {
+        match self.prefixes.get(node_type) {
+            Some(prefix) => format!("{}{}", prefix, name),
+            None => name.to_string(),
+        }
+    }
+}
+
+fn create(path: &Path) -> Result<BufWriter<File>, String> {
+    File::create(path)
+        .map(BufWriter::new)
+        .map_err(|err| format!("Unable to create {}: {}", path.display(), err))
+}
+
+/// Sink writing the nodes and edges of the articles as TSV, as configured.
+pub struct TsvGraphSink {
+    config: GraphExportConfig,
+    nodes: BufWriter<File>,
+    edges: BufWriter<File>,
+    /// Number of rows written to the nodes and edges files, without their headers.
+    node_rows: u64,
+    edge_rows: u64,
+}
+
+impl TsvGraphSink {
+    /// Creates the files of the graph and writes their headers.
+    pub fn new(config: &GraphExportConfig) -> Result<Self, String> {
+        std::fs::create_dir_all(config.output_directory()).map_err(|err| {
+            format!(
+                "Unable to create the directory {}: {}",
+                config.output_directory().display(),
+                err
+            )
+        })?;
+        let mut sink = TsvGraphSink {
+            config: config.clone(),
+            nodes: create(&config.nodes_path())?,
+            edges: create(&config.edges_path())?,
+            node_rows: 0,
+            edge_rows: 0,
+        };
+        sink.nodes
+            .write_all(b"node_name\tnode_type\tdescription\n")
+            .map_err(|err| sink.nodes_error(err))?;
+        sink.edges
+            .write_all(b"subject\tedge_type\tobject\n")
+            .map_err(|err| sink.edges_error(err))?;
+        Ok(sink)
+    }
+
+    fn nodes_error(&self, err: std::io::Error) -> String {
+        format!(
+            "Unable to write {}: {}",
+            self.config.nodes_path().display(),
+            err
+        )
+    }
+
+    fn edges_error(&self, err: std::io::Error) -> String {
+        format!(
+            "Unable to write {}: {}",
+            self.config.edges_path().display(),
+            err
+        )
+    }
+}
+
+impl ArticleSink for TsvGraphSink {
+    fn consume(&mut self, article: &Article) -> Result<(), String> {
+        let config = &self.config;
+        for node in article.to_nodes_with_options(config.export_options()) {
+            if config.includes_node_type(&node.node_type) {
+                writeln!(
+                    self.nodes,
+                    "{}\t{}\t{}",
+                    config.node_name(&node.node_type, &node.node_name),
+                    node.node_type,
+                    node.description,
+                )
+                .map_err(|err| self.nodes_error(err))?;
+                self.node_rows += 1;
+            }
+        }
+        for edge in article.to_edges_with_options(config.export_options()) {
+            if config.includes_edge_type(&edge.edge_type) {
+                writeln!(
+                    self.edges,
+                    "{}\t{}\t{}",
+                    config.node_name(&edge.subject_type, &edge.subject),
+                    edge.edge_type,
+                    config.node_name(&edge.object_type, &edge.object),
+                )
+                .map_err(|err| self.edges_error(err))?;
+                self.edge_rows += 1;
+            }
+        }
+        Ok(())
+    }
+
+    fn rows(&self) -> BTreeMap<String, u64> {
+        BTreeMap::from([
+            (self.config.nodes_file_name.clone(), self.node_rows),
+            (self.config.edges_file_name.clone(), self.edge_rows),
+        ])
+    }
+
+    fn flush(&mut self) -> Result<(), String> {
+        self.nodes.flush().map_err(|err| self.nodes_error(err))?;
+        self.edges.flush().map_err(|err| self.edges_error(err))
+    }
+
+    fn finish(mut self: Box<Self>) -> Result<(), String> {
+        self.flush()
+    }
+}
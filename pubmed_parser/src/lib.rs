@@ -1,6 +1,41 @@
 mod article_builder;
 mod article;
+mod article_filter;
+mod article_sink;
+mod article_source;
+mod article_store;
+mod article_validator;
+mod dataset_statistics;
+mod dtd;
+mod fix_graph;
+mod graph_export;
+mod merge_pubmed;
 mod parse_pubmed;
+mod pubmed_index;
+mod pubmed_query;
+mod pubmed_reader;
+mod pubmed_writer;
+mod run_manifest;
+mod text_normalization;
 
 pub use parse_pubmed::*;
 pub use article::*;
+pub use article_builder::{ElementBuilder, TextElementBuilder};
+pub use article_filter::{ArticleFilter, FilteredSink};
+pub use article_sink::{export_articles, ArticleSink, FanOutSink, JsonlSink};
+pub use article_store::{ArticleStore, ArticleStoreWriter};
+pub use article_validator::{ArticleValidator, Violation};
+pub use article_source::{
+    open_source, ArticleSource, CombinedSource, FileSource, SourceReader, TarSource,
+};
+pub use dataset_statistics::{DatasetStatistics, StatisticsSink};
+pub use dtd::DtdVersion;
+pub use fix_graph::{dedup_nodes, filter_edges};
+pub use graph_export::{GraphExportConfig, TsvGraphSink};
+pub use merge_pubmed::*;
+pub use pubmed_index::{write_checkpointed, PubmedIndex};
+pub use pubmed_query::parse_query;
+pub use pubmed_reader::{PubmedEvent, PubmedReader};
+pub use pubmed_writer::{write_pubmed, PubmedWriter};
+pub use run_manifest::{InputFile, RunManifest};
+pub use text_normalization::TextNormalization;
@@ -0,0 +1,330 @@
+use crate::article::*;
+use crate::article_sink::{finish_sink, ArticleSink};
+use crate::article_source::{ArticleSource, CombinedSource, FileSource};
+use crate::graph_export::{GraphExportConfig, TsvGraphSink};
+use crate::pubmed_index::parse_pmid;
+use crate::pubmed_reader::*;
+use crate::run_manifest::RunManifest;
+use crate::text_normalization::TextNormalization;
+use indicatif::{ParallelProgressIterator, ProgressBar};
+use rayon::prelude::*;
+use std::cmp::Ordering;
+use std::collections::{BTreeMap, HashMap, HashSet};
+use std::io::BufRead;
+use std::path::Path;
+
+/// Compares two strings so that digit runs are ordered by their numeric value.
+fn natural_cmp(left: &str, right: &str) -> Ordering {
+    let mut left = left.chars().peekable();
+    let mut right = right.chars().peekable();
+    loop {
+        match (left.peek().copied(), right.peek().copied()) {
+            (None, None) => return Ordering::Equal,
+            (None, Some(_)) => return Ordering::Less,
+            (Some(_), None) => return Ordering::Greater,
+            (Some(l), Some(r)) if l.is_ascii_digit() && r.is_ascii_digit() => {
+                let mut left_digits = String::new();
+                while let Some(digit) = left.next_if(char::is_ascii_digit) {
+                    left_digits.push(digit);
+                }
+                let mut right_digits = String::new();
+                while let Some(digit) = right.next_if(char::is_ascii_digit) {
+                    right_digits.push(digit);
+                }
+                let left_digits = left_digits.trim_start_matches('0');
+                let right_digits = right_digits.trim_start_matches('0');
+                let ordering = left_digits
+                    .len()
+                    .cmp(&right_digits.len())
+                    .then_with(|| left_digits.cmp(right_digits));
+                if ordering != Ordering::Equal {
+                    return ordering;
+                }
+            }
+            (Some(l), Some(r)) => {
+                if l != r {
+                    return l.cmp(&r);
+                }
+                left.next();
+                right.next();
+            }
+        }
+    }
+}
+
+/// Sorts the provided paths by the sequence number within their file names.
+///
+/// Baseline and update files share the same naming scheme, as in
+/// `pubmed23n0001.xml.gz` and `pubmed23n1167.xml.gz`, so sorting the file
+/// names in natural order yields the order in which they must be applied.
+pub fn sort_by_sequence(paths: &mut [String]) {
+    let file_name = |path: &String| {
+        Path::new(path)
+            .file_name()
+            .map(|name| name.to_string_lossy().to_string())
+            .unwrap_or_else(|| path.clone())
+    };
+    paths.sort_by(|left, right| {
+        natural_cmp(&file_name(left), &file_name(right)).then_with(|| natural_cmp(left, right))
+    });
+}
+
+struct CurrentVersion {
+    version: u16,
+    file_index: Option<usize>,
+}
+
+/// Returns the PMID, version and whether it is a deletion for every record of the file.
+///
+/// Only the `PMID` elements of the articles and of the `DeleteCitation` blocks
+/// are read, the articles themselves are not built.
+fn scan_pubmed_file(
+    source: &dyn ArticleSource,
+    file: &str,
+) -> Result<Vec<(u32, u16, bool)>, String> {
+    let mut records = Vec::new();
+    let mut in_article = false;
+    let mut in_deletion = false;
+    let mut has_pmid = false;
+    for (line_number, line) in BufRead::lines(source.read(file)?).enumerate() {
+        let line = line.map_err(|_| format!("Failed with decompression of file {}.", file))?;
+        let line = line.trim();
+        let pmid = || {
+            parse_pmid(line).ok_or_else(|| {
+                format!(
+                    "Unable to read the PMID {} {}:{}",
+                    line,
+                    file,
+                    line_number + 1
+                )
+            })
+        };
+        if line == "<PubmedArticle>" || line.starts_with("<PubmedArticle ") {
+            in_article = true;
+            has_pmid = false;
+        } else if line == "</PubmedArticle>" {
+            if !has_pmid {
+                return Err(format!(
+                    "The article has no PMID {}:{}",
+                    file,
+                    line_number + 1
+                ));
+            }
+            in_article = false;
+        } else if line == "<DeleteCitation>" {
+            in_deletion = true;
+        } else if line == "</DeleteCitation>" {
+            in_deletion = false;
+        } else if line.starts_with("<PMID") {
+            // The first PMID of an article is its own, the others are those of its comments.
+            if in_article && !has_pmid {
+                let (pubmed_id, version) = pmid()?;
+                records.push((pubmed_id, version, false));
+                has_pmid = true;
+            } else if in_deletion {
+                let (pubmed_id, version) = pmid()?;
+                records.push((pubmed_id, version, true));
+            }
+        }
+    }
+    Ok(records)
+}
+
+/// Returns the articles of the file that are part of the current state of PubMed.
+fn current_articles(
+    file_index: usize,
+    source: &dyn ArticleSource,
+    file: &str,
+    current_versions: &HashMap<u32, CurrentVersion>,
+    normalization: &TextNormalization,
+) -> Vec<Result<Article, String>> {
+    let reader = match source.open(file) {
+        Ok(reader) => reader.with_normalization(*normalization),
+        Err(err) => return vec![Err(err)],
+    };
+    let events = match reader.collect::<Result<Vec<PubmedEvent>, String>>() {
+        Ok(events) => events,
+        Err(err) => return vec![Err(err)],
+    };
+
+    // When a PMID appears more than once within the same file, the last one wins.
+    let mut emitted = HashSet::new();
+    let mut articles = events
+        .into_iter()
+        .rev()
+        .filter_map(|event| match event {
+            PubmedEvent::Article(article) => Some(*article),
+            PubmedEvent::BookArticle(_) | PubmedEvent::Deletion(_) => None,
+        })
+        .filter(|article| {
+            current_versions
+                .get(&article.pubmed_id)
+                .is_some_and(|current| {
+                    current.file_index == Some(file_index)
+                        && current.version == article.pmid_version
+                })
+                && emitted.insert(article.pubmed_id)
+        })
+        .map(Ok)
+        .collect::<Vec<_>>();
+    articles.reverse();
+    articles
+}
+
+/// Returns the current state of PubMed from baseline and update files.
+///
+/// Book articles are not part of the merged articles.
+///
+/// The files are applied in sequence order. An article replaces any earlier
+/// record of the same PMID unless the earlier record has a higher version,
+/// and a deletion removes the record of the same PMID and version.
+///
+/// The files are read twice: the first pass, done in parallel, only reads
+/// the PMID and version of each record to find the file holding the current
+/// record of each PMID, and the second pass yields those records file by file.
+pub fn merge_pubmed(
+    paths: Vec<String>,
+    normalization: &TextNormalization,
+) -> Result<impl Iterator<Item = Result<Article, String>>, String> {
+    merge_source(FileSource::from_files(paths), normalization)
+}
+
+/// Returns the current state of PubMed from the files of the source, see [`merge_pubmed`].
+pub fn merge_source<S: ArticleSource + 'static>(
+    source: S,
+    normalization: &TextNormalization,
+) -> Result<impl Iterator<Item = Result<Article, String>>, String> {
+    Ok(merge_source_by_file(source, normalization)?.flat_map(|(_, articles)| articles))
+}
+
+/// Returns the current articles of each file of the source holding any, see [`merge_pubmed`].
+fn merge_source_by_file<S: ArticleSource + 'static>(
+    source: S,
+    normalization: &TextNormalization,
+) -> Result<impl Iterator<Item = (String, Vec<Result<Article, String>>)>, String> {
+    let pb = ProgressBar::new(source.files().len() as u64);
+    let events = source
+        .files()
+        .par_iter()
+        .progress_with(pb)
+        .map(|file| scan_pubmed_file(&source, file))
+        .collect::<Result<Vec<_>, String>>()?;
+
+    let mut current_versions: HashMap<u32, CurrentVersion> = HashMap::new();
+    for (file_index, file_events) in events.into_iter().enumerate() {
+        for (pubmed_id, version, is_deletion) in file_events {
+            let current = current_versions.get_mut(&pubmed_id);
+            match (current, is_deletion) {
+                (Some(current), true) => {
+                    if current.version == version {
+                        current.file_index = None;
+                    }
+                }
+                (None, true) => {}
+                (Some(current), false) => {
+                    if current.file_index.is_none() || current.version <= version {
+                        current.version = version;
+                        current.file_index = Some(file_index);
+                    }
+                }
+                (None, false) => {
+                    current_versions.insert(
+                        pubmed_id,
+                        CurrentVersion {
+                            version,
+                            file_index: Some(file_index),
+                        },
+                    );
+                }
+            }
+        }
+    }
+
+    let file_indices = current_versions
+        .values()
+        .filter_map(|current| current.file_index)
+        .collect::<HashSet<usize>>();
+    let normalization = *normalization;
+
+    let files = source.files().to_vec();
+    Ok(files
+        .into_iter()
+        .enumerate()
+        .filter(move |(file_index, _)| file_indices.contains(file_index))
+        .map(move |(file_index, file)| {
+            let articles = current_articles(
+                file_index,
+                &source,
+                &file,
+                &current_versions,
+                &normalization,
+            );
+            (file, articles)
+        }))
+}
+
+pub fn parse_pubmed_merged(
+    baseline_directory: &str,
+    updates_directory: &str,
+    config: &GraphExportConfig,
+) -> Result<(), String> {
+    parse_pubmed_merged_with_normalization(
+        baseline_directory,
+        updates_directory,
+        &TextNormalization::default(),
+        config,
+    )
+}
+
+/// Writes the graph of the current state of PubMed and its manifest, see [`merge_pubmed`].
+pub fn parse_pubmed_merged_with_normalization(
+    baseline_directory: &str,
+    updates_directory: &str,
+    normalization: &TextNormalization,
+    config: &GraphExportConfig,
+) -> Result<(), String> {
+    let source = CombinedSource::new()
+        .with_source(Box::new(FileSource::from_directory(baseline_directory)?))
+        .with_source(Box::new(FileSource::from_directory(updates_directory)?));
+    let manifest = RunManifest::start(config, normalization).with_inputs(&source)?;
+    let rows =
+        parse_source_merged_into(source, normalization, Box::new(TsvGraphSink::new(config)?))?;
+    rows.iter()
+        .fold(manifest, |manifest, (file_name, rows)| {
+            manifest.with_rows(file_name, *rows)
+        })
+        .finish()
+        .write(&config.manifest_path())
+}
+
+/// Feeds the current state of PubMed to the sink, and returns its rows, see [`merge_pubmed`]
+/// and [`ArticleSink::rows`].
+pub fn parse_pubmed_merged_into(
+    baseline_directory: &str,
+    updates_directory: &str,
+    normalization: &TextNormalization,
+    sink: Box<dyn ArticleSink>,
+) -> Result<BTreeMap<String, u64>, String> {
+    let source = CombinedSource::new()
+        .with_source(Box::new(FileSource::from_directory(baseline_directory)?))
+        .with_source(Box::new(FileSource::from_directory(updates_directory)?));
+
+    parse_source_merged_into(source, normalization, sink)
+}
+
+/// Feeds the current state of PubMed, according to the files of the source, to the sink, and
+/// returns its rows.
+pub fn parse_source_merged_into<S: ArticleSource + 'static>(
+    source: S,
+    normalization: &TextNormalization,
+    sink: Box<dyn ArticleSink>,
+) -> Result<BTreeMap<String, u64>, String> {
+    let mut sink = sink;
+    for (file, articles) in merge_source_by_file(source, normalization)? {
+        sink.start_file(&file)?;
+        for article in articles {
+            sink.consume(&article?)?;
+        }
+    }
+    finish_sink(sink)
+}
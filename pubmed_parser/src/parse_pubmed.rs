@@ -1,121 +1,106 @@
 use crate::article::*;
-use crate::article_builder::*;
-use flate2::read::GzDecoder;
-use indicatif::ProgressIterator;
-use std::fs::File;
+use crate::article_sink::{finish_sink, ArticleSink};
+use crate::article_source::{ArticleSource, FileSource};
+use crate::graph_export::{GraphExportConfig, TsvGraphSink};
+use crate::pubmed_reader::*;
+use crate::run_manifest::RunManifest;
+use crate::text_normalization::TextNormalization;
 use indicatif::ProgressBar;
-use std::io::Write;
-use std::io::BufWriter;
-use std::fs;
+use rayon::prelude::*;
+use std::collections::BTreeMap;
 use std::io::BufRead;
 
-pub fn parse_single_pubmed(path: String) -> Vec<Article> {
-    let file = std::fs::File::open(&path).unwrap();
-    let file = GzDecoder::new(file);
-    let file = std::io::BufReader::new(file);
-
-    let mut article_builder = ArticleBuilder::new();
-    file.lines()
-        .filter_map(|line| match line {
-            Ok(line) => {
-                let line = line.trim();
-                if [
-                    "<?xml",
-                    "<?nihms ?>",
-                    "<?pmcsd ?>",
-                    "<!DOCTYPE",
-                    "<PubmedArticleSet>",
-                    "<PubmedData>",
-                    "</PubmedData>",
-                    "<PublicationStatus>",
-                    "</PubmedArticleSet>",
-                    "<MedlineCitation",
-                    "</MedlineCitation",
-                    "<CitationSubset>",
-                    "<Article ",
-                    "<ArticleTitle/>",
-                    "<PublicationTypeList/>",
-                    "<ReferenceList/>",
-                    "</Article>",
-                    "<NumberOfReferences>",
-                    "<Language>",
-                    "<SpaceFlightMission>",
-                    "<OtherID ",
-                    "<ELocationID ",
-                    "<GeneralNote ",
-                    "<Pagination",
-                    "</Pagination",
-                    "<MedlinePgn",
-                ]
-                .iter()
-                .any(|target| line.starts_with(target) || line.ends_with(target))
-                {
-                    return None;
-                }
-                article_builder
-                    .parse(line)
-                    .map_err(|err| format!("{} {}", err, path))
-                    .unwrap();
-                if article_builder.can_build() {
-                    Some(core::mem::replace(
-                        &mut article_builder,
-                        ArticleBuilder::new(),
-                    )
-                    .build()
-                    .map_err(|err| format!("{} {}", err, path))
-                    .unwrap())
-                } else {
-                    None
-                }
-            }
-            Err(_) => Some(Err(format!("Failed with decompression of file {}.", path)).unwrap()),
-        })
-        .collect::<Vec<_>>()
+/// Returns the articles of the PubMed file, or the first error found while reading it.
+pub fn parse_single_pubmed(path: String) -> Result<Vec<Article>, String> {
+    parse_single_pubmed_with_normalization(path, &TextNormalization::default())
 }
 
-pub fn parse_pubmed(directory: &str) {
-    let paths = fs::read_dir(directory)
-        .unwrap()
-        .map(|path| path.unwrap().path().display().to_string())
-        .filter(|path| path.ends_with(".gz"))
-        .collect::<Vec<String>>();
-
-    let pb = ProgressBar::new(paths.len() as u64);
+pub fn parse_single_pubmed_with_normalization(
+    path: String,
+    normalization: &TextNormalization,
+) -> Result<Vec<Article>, String> {
+    read_articles(PubmedReader::from_path(&path), normalization).collect()
+}
 
-    let edges = File::create("edges.tsv").unwrap();
-    let nodes = File::create("nodes.tsv").unwrap();
+/// Returns the articles read by the provided reader, skipping its book articles and deletions.
+///
+/// A reader that could not be opened yields a single error.
+pub(crate) fn read_articles<R: BufRead>(
+    reader: Result<PubmedReader<R>, String>,
+    normalization: &TextNormalization,
+) -> impl Iterator<Item = Result<Article, String>> {
+    let (reader, error) = match reader {
+        Ok(reader) => (Some(reader.with_normalization(*normalization)), None),
+        Err(err) => (None, Some(Err(err))),
+    };
+    error.into_iter().chain(
+        reader
+            .into_iter()
+            .flatten()
+            .filter_map(|event| match event {
+                Ok(PubmedEvent::Article(article)) => Some(Ok(*article)),
+                Ok(PubmedEvent::BookArticle(_) | PubmedEvent::Deletion(_)) => None,
+                Err(err) => Some(Err(err)),
+            }),
+    )
+}
 
-    let mut edges = BufWriter::new(edges);
+/// Writes the graph of the articles of the files within the provided directory, and its manifest.
+pub fn parse_pubmed(directory: &str, config: &GraphExportConfig) -> Result<(), String> {
+    parse_pubmed_with_normalization(directory, &TextNormalization::default(), config)
+}
 
-    edges.write(b"subject\tedge_type\tobject\n").unwrap();
+pub fn parse_pubmed_with_normalization(
+    directory: &str,
+    normalization: &TextNormalization,
+    config: &GraphExportConfig,
+) -> Result<(), String> {
+    let source = FileSource::from_directory(directory)?;
+    let manifest = RunManifest::start(config, normalization).with_inputs(&source)?;
+    let rows = parse_source_into(&source, normalization, Box::new(TsvGraphSink::new(config)?))?;
+    rows.iter()
+        .fold(manifest, |manifest, (file_name, rows)| {
+            manifest.with_rows(file_name, *rows)
+        })
+        .finish()
+        .write(&config.manifest_path())
+}
 
-    let mut nodes = BufWriter::new(nodes);
+/// Feeds the articles of the files within the provided directory to the sink, and returns its
+/// rows, see [`ArticleSink::rows`].
+pub fn parse_pubmed_into(
+    directory: &str,
+    normalization: &TextNormalization,
+    sink: Box<dyn ArticleSink>,
+) -> Result<BTreeMap<String, u64>, String> {
+    parse_source_into(&FileSource::from_directory(directory)?, normalization, sink)
+}
 
-    nodes.write(b"node_name\tnode_type\tdescription\n").unwrap();
+/// Feeds the articles of the files of the source to the sink, file by file in sequence order,
+/// and returns its rows, see [`ArticleSink::rows`].
+///
+/// The files are read in parallel, as many at a time as the threads of the current pool.
+pub fn parse_source_into(
+    source: &dyn ArticleSource,
+    normalization: &TextNormalization,
+    sink: Box<dyn ArticleSink>,
+) -> Result<BTreeMap<String, u64>, String> {
+    let pb = ProgressBar::new(source.files().len() as u64);
 
-    paths
-        .into_iter()
-        .progress_with(pb)
-        .flat_map(parse_single_pubmed)
-        .for_each(|article|{
-            for node in article.to_nodes() {
-                nodes.write(format!(
-                    "{}\t{}\t{}\n",
-                    node.node_name,
-                    node.node_type,
-                    node.description,
-                ).as_bytes()).unwrap();
-            }
-            for edge in article.to_edges() {
-                edges.write(format!(
-                    "{}\t{}\t{}\n",
-                    edge.subject,
-                    edge.edge_type,
-                    edge.object,
-                ).as_bytes()).unwrap();
+    let mut sink = sink;
+    for files in source.files().chunks(rayon::current_num_threads()) {
+        let articles = files
+            .par_iter()
+            .map(|file| read_articles(source.open(file), normalization).collect::<Vec<_>>())
+            .collect::<Vec<_>>();
+        for (file, articles) in files.iter().zip(articles) {
+            sink.start_file(file)?;
+            for article in articles {
+                sink.consume(&article?)?;
             }
-        });
-
-        nodes.flush().unwrap();
-        edges.flush().unwrap();
+            pb.inc(1);
+        }
+    }
+    pb.finish();
+    finish_sink(sink)
 }
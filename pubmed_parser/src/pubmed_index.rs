@@ -0,0 +1,464 @@
+use crate::article::Article;
+use crate::article_source::{ArticleSource, FileSource};
+use crate::pubmed_reader::{PubmedEvent, PubmedReader};
+use flate2::bufread::{GzDecoder, MultiGzDecoder};
+use flate2::write::GzEncoder;
+use flate2::Compression;
+use indicatif::{ParallelProgressIterator, ProgressBar};
+use rayon::prelude::*;
+use std::collections::HashMap;
+use std::fs::File;
+use std::io::{BufRead, BufReader, BufWriter, Read, Seek, SeekFrom, Write};
+
+const ARTICLE_START: &str = "<PubmedArticle";
+const ARTICLE_END: &str = "</PubmedArticle>";
+
+/// Location of a `PubmedArticle` within one of the indexed files.
+///
+/// Gzipped files are read from the start of the gzip member holding the
+/// beginning of the article, its checkpoint, and the offset is counted in
+/// decompressed bytes from there. Uncompressed files have a checkpoint of 0.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct IndexEntry {
+    file: usize,
+    version: u16,
+    checkpoint: u64,
+    offset: u64,
+    length: u64,
+}
+
+/// A record found while scanning a file, in the order of the file.
+enum IndexRecord {
+    Article(u32, IndexEntry),
+    Deletion(u32, u16),
+}
+
+/// Byte reader counting the bytes consumed, so that the end of a gzip member is known.
+struct CountingReader<R: BufRead> {
+    reader: R,
+    position: u64,
+}
+
+impl<R: BufRead> Read for CountingReader<R> {
+    fn read(&mut self, buffer: &mut [u8]) -> std::io::Result<usize> {
+        let read = self.reader.read(buffer)?;
+        self.position += read as u64;
+        Ok(read)
+    }
+}
+
+impl<R: BufRead> BufRead for CountingReader<R> {
+    fn fill_buf(&mut self) -> std::io::Result<&[u8]> {
+        self.reader.fill_buf()
+    }
+
+    fn consume(&mut self, amount: usize) {
+        self.position += amount as u64;
+        self.reader.consume(amount)
+    }
+}
+
+/// Returns the PMID and the version of a `<PMID Version="1">123</PMID>` element.
+pub(crate) fn parse_pmid(element: &str) -> Option<(u32, u16)> {
+    let (attributes, rest) = element.split_once('>')?;
+    let pubmed_id = rest.split('<').next()?.trim().parse().ok()?;
+    let version = match attributes.split_once("Version=\"") {
+        Some((_, version)) => version.split('"').next()?.parse().ok()?,
+        None => 1,
+    };
+    Some((pubmed_id, version))
+}
+
+/// Returns the position of the next `<PubmedArticle>` tag, skipping `<PubmedArticleSet>`.
+fn find_article_start(line: &str) -> Option<usize> {
+    line.match_indices(ARTICLE_START)
+        .map(|(position, _)| position)
+        .find(|position| {
+            matches!(
+                line[position + ARTICLE_START.len()..].chars().next(),
+                Some('>') | Some(' ')
+            )
+        })
+}
+
+/// Article whose end has not been reached yet.
+#[derive(Clone, Copy)]
+struct OpenArticle {
+    /// Checkpoint and decompressed position of the gzip member holding its start.
+    member: (u64, u64),
+    /// Decompressed position of its start.
+    start: u64,
+    pmid: Option<(u32, u16)>,
+}
+
+/// Scanner of the lines of a file, which may span several gzip members.
+struct FileScanner<'a> {
+    file: usize,
+    path: &'a str,
+    records: Vec<IndexRecord>,
+    /// Decompressed position of the next line.
+    position: u64,
+    /// Checkpoint and decompressed position of the current gzip member.
+    member: (u64, u64),
+    article: Option<OpenArticle>,
+    in_deletion: bool,
+}
+
+impl FileScanner<'_> {
+    fn scan(&mut self, mut reader: impl BufRead) -> Result<(), String> {
+        let mut line = String::new();
+        loop {
+            line.clear();
+            let read = reader
+                .read_line(&mut line)
+                .map_err(|err| format!("Unable to read {}: {}", self.path, err))?;
+            if read == 0 {
+                return Ok(());
+            }
+            self.scan_line(&line)?;
+            self.position += read as u64;
+        }
+    }
+
+    fn pmid(&self, element: &str) -> Result<(u32, u16), String> {
+        parse_pmid(element).ok_or_else(|| {
+            format!(
+                "Unable to read the PMID of {} in {}.",
+                element.trim(),
+                self.path
+            )
+        })
+    }
+
+    fn scan_line(&mut self, line: &str) -> Result<(), String> {
+        let mut cursor = 0;
+        loop {
+            let rest = &line[cursor..];
+            match self.article {
+                None => {
+                    if let Some(start) = find_article_start(rest) {
+                        self.article = Some(OpenArticle {
+                            member: self.member,
+                            start: self.position + (cursor + start) as u64,
+                            pmid: None,
+                        });
+                        cursor += start + ARTICLE_START.len();
+                        continue;
+                    }
+                    if rest.contains("<DeleteCitation>") {
+                        self.in_deletion = true;
+                    } else if rest.contains("</DeleteCitation>") {
+                        self.in_deletion = false;
+                    } else if self.in_deletion {
+                        if let Some(start) = rest.find("<PMID") {
+                            let (pubmed_id, version) = self.pmid(&rest[start..])?;
+                            self.records.push(IndexRecord::Deletion(pubmed_id, version));
+                        }
+                    }
+                    return Ok(());
+                }
+                Some(mut article) => {
+                    if article.pmid.is_none() {
+                        if let Some(position) = rest.find("<PMID") {
+                            article.pmid = Some(self.pmid(&rest[position..])?);
+                            self.article = Some(article);
+                        }
+                    }
+                    let Some(end) = rest.find(ARTICLE_END) else {
+                        return Ok(());
+                    };
+                    cursor += end + ARTICLE_END.len();
+                    self.article = None;
+                    let (pubmed_id, version) = article.pmid.ok_or_else(|| {
+                        format!(
+                            "The article at byte {} of {} has no PMID.",
+                            article.start, self.path
+                        )
+                    })?;
+                    let (checkpoint, member_start) = article.member;
+                    self.records.push(IndexRecord::Article(
+                        pubmed_id,
+                        IndexEntry {
+                            file: self.file,
+                            version,
+                            checkpoint,
+                            offset: article.start - member_start,
+                            length: self.position + cursor as u64 - article.start,
+                        },
+                    ));
+                }
+            }
+        }
+    }
+}
+
+/// Returns the records of the file, in the order of the file.
+fn index_file(file: usize, path: &str) -> Result<Vec<IndexRecord>, String> {
+    let reader = BufReader::new(
+        File::open(path).map_err(|err| format!("Unable to open {}: {}", path, err))?,
+    );
+    let mut scanner = FileScanner {
+        file,
+        path,
+        records: Vec::new(),
+        position: 0,
+        member: (0, 0),
+        article: None,
+        in_deletion: false,
+    };
+    if !path.ends_with(".gz") {
+        scanner.scan(reader)?;
+        return Ok(scanner.records);
+    }
+
+    // Each gzip member is decoded on its own to learn where the next one starts.
+    let mut reader = CountingReader {
+        reader,
+        position: 0,
+    };
+    while !reader
+        .fill_buf()
+        .map_err(|err| format!("Unable to read {}: {}", path, err))?
+        .is_empty()
+    {
+        scanner.member = (reader.position, scanner.position);
+        scanner.scan(BufReader::new(GzDecoder::new(&mut reader)))?;
+    }
+    Ok(scanner.records)
+}
+
+/// Index of the location of each `PubmedArticle` within PubMed files, by PMID.
+///
+/// Reading an article decodes its file from the checkpoint before it. The
+/// baseline files have a single gzip member, so [`write_checkpointed`] can be
+/// used to split them into many members and make the reads near instant.
+#[derive(Debug, Default)]
+pub struct PubmedIndex {
+    files: Vec<String>,
+    entries: HashMap<u32, IndexEntry>,
+}
+
+impl PubmedIndex {
+    /// Indexes the files of the source in sequence order.
+    ///
+    /// The files are read again from the file system by [`PubmedIndex::get_xml`],
+    /// so only a [`FileSource`] can be indexed.
+    ///
+    /// As when merging, a later record of a PMID replaces an earlier one unless
+    /// the earlier one has a higher version, and a deletion removes the record
+    /// of the same PMID and version.
+    pub fn build(source: &FileSource) -> Result<Self, String> {
+        let files = source.files().to_vec();
+        let pb = ProgressBar::new(files.len() as u64);
+        let records = files
+            .par_iter()
+            .enumerate()
+            .progress_with(pb)
+            .map(|(file, path)| index_file(file, path))
+            .collect::<Result<Vec<_>, String>>()?;
+
+        let mut entries: HashMap<u32, IndexEntry> = HashMap::new();
+        for record in records.into_iter().flatten() {
+            match record {
+                IndexRecord::Article(pubmed_id, entry) => {
+                    if entries
+                        .get(&pubmed_id)
+                        .is_none_or(|current| current.version <= entry.version)
+                    {
+                        entries.insert(pubmed_id, entry);
+                    }
+                }
+                IndexRecord::Deletion(pubmed_id, version) => {
+                    if entries
+                        .get(&pubmed_id)
+                        .is_some_and(|current| current.version == version)
+                    {
+                        entries.remove(&pubmed_id);
+                    }
+                }
+            }
+        }
+        Ok(PubmedIndex { files, entries })
+    }
+
+    /// Reads an index written by [`PubmedIndex::write`].
+    pub fn from_path(path: &str) -> Result<Self, String> {
+        let reader = BufReader::new(
+            File::open(path).map_err(|err| format!("Unable to open {}: {}", path, err))?,
+        );
+        let mut index = PubmedIndex::default();
+        let mut file_indices: HashMap<String, usize> = HashMap::new();
+        for (line_number, line) in reader.lines().enumerate().skip(1) {
+            let line = line.map_err(|err| format!("Unable to read {}: {}", path, err))?;
+            let invalid = || format!("The line {} of {} is not valid.", line_number + 1, path);
+            let columns = line.split('\t').collect::<Vec<&str>>();
+            if columns.len() != 6 {
+                return Err(invalid());
+            }
+            let number = |column: &str| column.parse::<u64>().map_err(|_| invalid());
+            let file = *file_indices
+                .entry(columns[1].to_string())
+                .or_insert_with(|| {
+                    index.files.push(columns[1].to_string());
+                    index.files.len() - 1
+                });
+            index.entries.insert(
+                columns[0].parse().map_err(|_| invalid())?,
+                IndexEntry {
+                    file,
+                    version: columns[2].parse().map_err(|_| invalid())?,
+                    checkpoint: number(columns[3])?,
+                    offset: number(columns[4])?,
+                    length: number(columns[5])?,
+                },
+            );
+        }
+        Ok(index)
+    }
+
+    /// Writes the index as a TSV with a row per PMID, sorted by PMID.
+    pub fn write(&self, path: &str) -> Result<(), String> {
+        let write_error = |err: std::io::Error| format!("Unable to write {}: {}", path, err);
+        let mut writer = BufWriter::new(
+            File::create(path).map_err(|err| format!("Unable to create {}: {}", path, err))?,
+        );
+        writeln!(
+            writer,
+            "pubmed_id\tfile\tversion\tcheckpoint\toffset\tlength"
+        )
+        .map_err(write_error)?;
+        for pubmed_id in self.pubmed_ids() {
+            let entry = &self.entries[&pubmed_id];
+            writeln!(
+                writer,
+                "{}\t{}\t{}\t{}\t{}\t{}",
+                pubmed_id,
+                self.files[entry.file],
+                entry.version,
+                entry.checkpoint,
+                entry.offset,
+                entry.length
+            )
+            .map_err(write_error)?;
+        }
+        writer.flush().map_err(write_error)
+    }
+
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    pub fn contains(&self, pubmed_id: u32) -> bool {
+        self.entries.contains_key(&pubmed_id)
+    }
+
+    /// Returns the indexed PMIDs, sorted.
+    pub fn pubmed_ids(&self) -> Vec<u32> {
+        let mut pubmed_ids = self.entries.keys().copied().collect::<Vec<u32>>();
+        pubmed_ids.sort_unstable();
+        pubmed_ids
+    }
+
+    /// Returns the file holding the article of the PMID.
+    pub fn file(&self, pubmed_id: u32) -> Option<&str> {
+        self.entries
+            .get(&pubmed_id)
+            .map(|entry| self.files[entry.file].as_str())
+    }
+
+    /// Returns the XML of the `PubmedArticle` of the PMID, as found in its file.
+    pub fn get_xml(&self, pubmed_id: u32) -> Result<Option<String>, String> {
+        let Some(entry) = self.entries.get(&pubmed_id) else {
+            return Ok(None);
+        };
+        let path = &self.files[entry.file];
+        let read_error = |err: std::io::Error| format!("Unable to read {}: {}", path, err);
+        let mut file =
+            File::open(path).map_err(|err| format!("Unable to open {}: {}", path, err))?;
+        file.seek(SeekFrom::Start(entry.checkpoint))
+            .map_err(read_error)?;
+        let mut reader: Box<dyn Read> = if path.ends_with(".gz") {
+            Box::new(MultiGzDecoder::new(BufReader::new(file)))
+        } else {
+            Box::new(file)
+        };
+        std::io::copy(&mut (&mut reader).take(entry.offset), &mut std::io::sink())
+            .map_err(read_error)?;
+        let mut xml = String::new();
+        reader
+            .take(entry.length)
+            .read_to_string(&mut xml)
+            .map_err(read_error)?;
+        if !xml.starts_with(ARTICLE_START) || !xml.ends_with(ARTICLE_END) {
+            return Err(format!(
+                "The index does not match {}, which may have changed since it was indexed.",
+                path
+            ));
+        }
+        Ok(Some(xml))
+    }
+
+    /// Returns the article of the PMID, parsing only its own `PubmedArticle`.
+    pub fn get_article(&self, pubmed_id: u32) -> Result<Option<Article>, String> {
+        let Some(xml) = self.get_xml(pubmed_id)? else {
+            return Ok(None);
+        };
+        let source = format!("PMID {} of {}", pubmed_id, self.file(pubmed_id).unwrap());
+        match PubmedReader::new(xml.as_bytes(), &source).next() {
+            Some(Ok(PubmedEvent::Article(article))) => Ok(Some(*article)),
+            Some(Err(err)) => Err(err),
+            _ => Err(format!("Unable to read the article of {}.", source)),
+        }
+    }
+}
+
+/// Writes a copy of a gzipped PubMed file made of a gzip member every so many articles.
+///
+/// The copy is still a valid gzip file, a little larger than the original,
+/// and its articles are read by [`PubmedIndex`] from the start of their member.
+pub fn write_checkpointed(
+    source: &str,
+    destination: &str,
+    articles_per_checkpoint: usize,
+) -> Result<(), String> {
+    let read_error = |err: std::io::Error| format!("Unable to read {}: {}", source, err);
+    let write_error = |err: std::io::Error| format!("Unable to write {}: {}", destination, err);
+    let file = File::open(source).map_err(|err| format!("Unable to open {}: {}", source, err))?;
+    let mut reader = BufReader::new(MultiGzDecoder::new(BufReader::new(file)));
+    let mut writer = BufWriter::new(
+        File::create(destination)
+            .map_err(|err| format!("Unable to create {}: {}", destination, err))?,
+    );
+
+    let mut line = String::new();
+    let mut articles = 0;
+    let mut pending = false;
+    let mut encoder = GzEncoder::new(Vec::new(), Compression::default());
+    while reader.read_line(&mut line).map_err(read_error)? > 0 {
+        encoder.write_all(line.as_bytes()).map_err(write_error)?;
+        pending = true;
+        articles += line.matches(ARTICLE_END).count();
+        line.clear();
+        if articles >= articles_per_checkpoint.max(1) {
+            let member = std::mem::replace(
+                &mut encoder,
+                GzEncoder::new(Vec::new(), Compression::default()),
+            );
+            writer
+                .write_all(&member.finish().map_err(write_error)?)
+                .map_err(write_error)?;
+            articles = 0;
+            pending = false;
+        }
+    }
+    if pending {
+        writer
+            .write_all(&encoder.finish().map_err(write_error)?)
+            .map_err(write_error)?;
+    }
+    writer.flush().map_err(write_error)
+}
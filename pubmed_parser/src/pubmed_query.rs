@@ -0,0 +1,223 @@
+use crate::article_filter::ArticleFilter;
+
+#[derive(Debug, Clone)]
+enum Token {
+    Open,
+    Close,
+    And,
+    Or,
+    Not,
+    Term { text: String, tag: Option<String> },
+}
+
+/// Splits the query into parentheses, operators and terms with their optional field tag.
+fn tokenize(query: &str) -> Result<Vec<Token>, String> {
+    let characters = query.chars().collect::<Vec<char>>();
+    let mut tokens = Vec::new();
+    let mut index = 0;
+    while index < characters.len() {
+        let character = characters[index];
+        if character.is_whitespace() {
+            index += 1;
+            continue;
+        }
+        if character == '(' || character == ')' {
+            tokens.push(if character == '(' {
+                Token::Open
+            } else {
+                Token::Close
+            });
+            index += 1;
+            continue;
+        }
+        let text = if character == '"' {
+            let end = characters[index + 1..]
+                .iter()
+                .position(|&character| character == '"')
+                .ok_or_else(|| "The query has an unterminated quote.".to_string())?;
+            let text = characters[index + 1..index + 1 + end].iter().collect();
+            index += end + 2;
+            text
+        } else {
+            let start = index;
+            while index < characters.len()
+                && !characters[index].is_whitespace()
+                && !['(', ')', '[', ']', '"'].contains(&characters[index])
+            {
+                index += 1;
+            }
+            let text = characters[start..index].iter().collect::<String>();
+            match text.as_str() {
+                "AND" => {
+                    tokens.push(Token::And);
+                    continue;
+                }
+                "OR" => {
+                    tokens.push(Token::Or);
+                    continue;
+                }
+                "NOT" => {
+                    tokens.push(Token::Not);
+                    continue;
+                }
+                "" => {
+                    return Err(format!(
+                        "The query has an unexpected {}.",
+                        characters[index]
+                    ))
+                }
+                _ => text,
+            }
+        };
+        // The field tag may be separated from its term by whitespace.
+        let mut next = index;
+        while next < characters.len() && characters[next].is_whitespace() {
+            next += 1;
+        }
+        let tag = if characters.get(next) == Some(&'[') {
+            let end = characters[next + 1..]
+                .iter()
+                .position(|&character| character == ']')
+                .ok_or_else(|| "The query has an unterminated field tag.".to_string())?;
+            let tag = characters[next + 1..next + 1 + end].iter().collect();
+            index = next + end + 2;
+            Some(tag)
+        } else {
+            None
+        };
+        tokens.push(Token::Term { text, tag });
+    }
+    Ok(tokens)
+}
+
+/// Returns the ISO 639-2 code of a language given by its English name, as PubMed accepts both.
+fn language_code(language: &str) -> &str {
+    match language.to_lowercase().as_str() {
+        "english" => "eng",
+        "french" => "fre",
+        "german" => "ger",
+        "spanish" => "spa",
+        "italian" => "ita",
+        "portuguese" => "por",
+        "dutch" => "dut",
+        "russian" => "rus",
+        "japanese" => "jpn",
+        "chinese" => "chi",
+        _ => language,
+    }
+}
+
+/// Returns the year of a date such as `2015`, `2015/06` or `2015/06/01`, or `None` when empty.
+fn year(date: &str) -> Result<Option<u16>, String> {
+    let year = date.trim().split('/').next().unwrap_or_default();
+    if year.is_empty() {
+        return Ok(None);
+    }
+    year.parse::<u16>()
+        .map(Some)
+        .map_err(|err| format!("The publication date {} is not valid: {}", date, err))
+}
+
+fn term_filter(text: &str, tag: Option<&str>) -> Result<ArticleFilter, String> {
+    let text = text.trim();
+    match tag.map(|tag| tag.trim().to_lowercase()).as_deref() {
+        None | Some("tiab") | Some("title/abstract") => Ok(ArticleFilter::title_abstract(&[text])),
+        Some("mh") | Some("mesh") | Some("mesh terms") => Ok(ArticleFilter::mesh(&[text])),
+        Some("majr") | Some("mesh major topic") => Ok(ArticleFilter::mesh_major_topic(&[text])),
+        Some("pt") | Some("publication type") => Ok(ArticleFilter::publication_types(&[text])),
+        Some("ta") | Some("journal") => Ok(ArticleFilter::journals(&[text])),
+        Some("la") | Some("language") => Ok(ArticleFilter::languages(&[language_code(text)])),
+        Some("dp") | Some("publication date") => {
+            let (from, to) = text.split_once(':').unwrap_or((text, text));
+            let (from, to) = (year(from)?, year(to)?);
+            if from.is_none() && to.is_none() {
+                return Err(format!("The publication date {} has no year.", text));
+            }
+            Ok(ArticleFilter::publication_years(from, to))
+        }
+        Some(tag) => Err(format!("The field tag [{}] is not supported.", tag)),
+    }
+}
+
+struct QueryParser {
+    tokens: Vec<Token>,
+    position: usize,
+}
+
+impl QueryParser {
+    fn next(&mut self) -> Option<Token> {
+        let token = self.tokens.get(self.position).cloned();
+        self.position += 1;
+        token
+    }
+
+    fn peek(&self) -> Option<&Token> {
+        self.tokens.get(self.position)
+    }
+
+    /// Parses operands joined by operators, which PubMed applies from left to right.
+    fn expression(&mut self) -> Result<ArticleFilter, String> {
+        let mut filter = self.operand()?;
+        loop {
+            filter = match self.peek() {
+                None | Some(Token::Close) => return Ok(filter),
+                Some(Token::And) => {
+                    self.next();
+                    filter.and(self.operand()?)
+                }
+                Some(Token::Or) => {
+                    self.next();
+                    filter.or(self.operand()?)
+                }
+                Some(Token::Not) => {
+                    self.next();
+                    filter.and(self.operand()?.negate())
+                }
+                // Terms without an operator between them are all required.
+                Some(Token::Open) | Some(Token::Term { .. }) => filter.and(self.operand()?),
+            }
+        }
+    }
+
+    fn operand(&mut self) -> Result<ArticleFilter, String> {
+        match self.next() {
+            Some(Token::Term { text, tag }) => term_filter(&text, tag.as_deref()),
+            Some(Token::Open) => {
+                let filter = self.expression()?;
+                match self.next() {
+                    Some(Token::Close) => Ok(filter),
+                    _ => Err("The query has an unclosed parenthesis.".to_string()),
+                }
+            }
+            Some(Token::Close) => {
+                Err("The query has an unexpected closing parenthesis.".to_string())
+            }
+            Some(_) => Err("The query has an operator without a term before it.".to_string()),
+            None => Err("The query ends without a term.".to_string()),
+        }
+    }
+}
+
+/// Returns the filter matching the articles found by a PubMed search query.
+///
+/// A practical subset of the PubMed syntax is supported: terms, optionally quoted, tagged
+/// with `[mh]`, `[majr]`, `[dp]`, `[pt]`, `[ta]`, `[la]` or `[tiab]` or their long forms,
+/// and combined with `AND`, `OR`, `NOT` and parentheses. As in PubMed, the operators are
+/// applied from left to right. Untagged terms are searched in the title and abstracts,
+/// and MeSH terms are not expanded to their narrower descriptors.
+pub fn parse_query(query: &str) -> Result<ArticleFilter, String> {
+    let mut parser = QueryParser {
+        tokens: tokenize(query).map_err(|err| format!("Unable to parse {}: {}", query, err))?,
+        position: 0,
+    };
+    let filter = parser
+        .expression()
+        .map_err(|err| format!("Unable to parse {}: {}", query, err))?;
+    match parser.peek() {
+        None => Ok(filter),
+        Some(_) => Err(format!(
+            "Unable to parse {}: The query has an unopened parenthesis.",
+            query
+        )),
+    }
+}
@@ -0,0 +1,249 @@
+use crate::article::*;
+use crate::article_builder::*;
+use crate::dtd::DtdVersion;
+use crate::text_normalization::TextNormalization;
+use flate2::read::MultiGzDecoder;
+use std::collections::VecDeque;
+use std::fs::File;
+use std::io::{BufRead, BufReader, Lines};
+
+const IGNORED_LINES: &[&str] = &[
+    "<?xml",
+    "<?nihms ?>",
+    "<?pmcsd ?>",
+    "<PubmedArticleSet>",
+    "<PubmedData>",
+    "</PubmedData>",
+    "<BookDocument>",
+    "</BookDocument>",
+    "<PubmedBookData>",
+    "</PubmedBookData>",
+    "</PubmedArticleSet>",
+    "<ArticleTitle/>",
+    "<PublicationTypeList/>",
+    "<ReferenceList/>",
+    "</Article>",
+    "<NumberOfReferences>",
+    "<SpaceFlightMission>",
+    "<OtherID ",
+    "<GeneralNote ",
+];
+
+/// A record read from a PubMed baseline or update file.
+pub enum PubmedEvent {
+    /// A `PubmedArticle`, which replaces any earlier version of the same PMID.
+    Article(Box<Article>),
+    /// A `PubmedBookArticle`, which replaces any earlier version of the same PMID.
+    BookArticle(Box<BookArticle>),
+    /// A PMID listed within a `DeleteCitation` block.
+    Deletion(DeletedCitation),
+}
+
+type ElementBuilderFactory = Box<dyn Fn() -> Box<dyn ElementBuilder> + Send + Sync>;
+
+/// Streaming reader of the records within a PubMed XML document.
+pub struct PubmedReader<R: BufRead> {
+    lines: Lines<R>,
+    source: String,
+    line_number: usize,
+    normalization: TextNormalization,
+    lenient: bool,
+    dtd_version: Option<DtdVersion>,
+    element_builder_factories: Vec<(String, ElementBuilderFactory)>,
+    article_builder: ArticleBuilder,
+    book_article_builder: BookArticleBuilder,
+    delete_citation_builder: DeleteCitationBuilder,
+    pending_events: VecDeque<PubmedEvent>,
+    failed: bool,
+}
+
+impl PubmedReader<BufReader<MultiGzDecoder<File>>> {
+    /// Returns a reader over the provided gzipped PubMed XML file.
+    pub fn from_path(path: &str) -> Result<Self, String> {
+        let file = File::open(path).map_err(|err| format!("Unable to open {}: {}", path, err))?;
+        Ok(PubmedReader::new(
+            BufReader::new(MultiGzDecoder::new(file)),
+            path,
+        ))
+    }
+}
+
+impl<R: BufRead> PubmedReader<R> {
+    /// Returns a reader over an uncompressed PubMed XML document.
+    ///
+    /// The source is only used to give context to the error messages.
+    pub fn new(reader: R, source: &str) -> Self {
+        PubmedReader {
+            lines: reader.lines(),
+            source: source.to_string(),
+            line_number: 0,
+            normalization: TextNormalization::default(),
+            lenient: false,
+            dtd_version: None,
+            element_builder_factories: Vec::new(),
+            article_builder: ArticleBuilder::new(),
+            book_article_builder: BookArticleBuilder::new(),
+            delete_citation_builder: DeleteCitationBuilder::new(),
+            pending_events: VecDeque::new(),
+            failed: false,
+        }
+    }
+
+    pub fn with_normalization(mut self, normalization: TextNormalization) -> Self {
+        self.normalization = normalization;
+        self
+    }
+
+    /// Sets whether the elements not handled by any parser are collected into
+    /// [`Article::extras`] instead of causing an error.
+    pub fn with_lenient(mut self, lenient: bool) -> Self {
+        self.lenient = lenient;
+        self.article_builder = self.new_article_builder();
+        self
+    }
+
+    /// Registers a builder for an element of the articles, such as `OtherID`.
+    ///
+    /// A new builder is created by the factory for every article, and its values
+    /// are stored in [`Article::extras`] under the provided name.
+    pub fn with_element_builder<B, F>(mut self, name: &str, factory: F) -> Self
+    where
+        B: ElementBuilder + 'static,
+        F: Fn() -> B + Send + Sync + 'static,
+    {
+        self.element_builder_factories.push((
+            name.to_string(),
+            Box::new(move || Box::new(factory()) as Box<dyn ElementBuilder>),
+        ));
+        self.article_builder = self.new_article_builder();
+        self
+    }
+
+    fn new_article_builder(&self) -> ArticleBuilder {
+        self.element_builder_factories.iter().fold(
+            ArticleBuilder::new().with_lenient(self.lenient),
+            |article_builder, (name, factory)| {
+                article_builder.with_element_builder(name, factory())
+            },
+        )
+    }
+
+    pub fn source(&self) -> &str {
+        &self.source
+    }
+
+    /// Returns the DTD version declared by the DOCTYPE of the document, if read so far.
+    ///
+    /// See [`DtdVersion::from_doctype`] for the DTDs without a fixture.
+    pub fn dtd_version(&self) -> Option<DtdVersion> {
+        self.dtd_version
+    }
+
+    /// Returns the number of lines read so far.
+    pub fn line_number(&self) -> usize {
+        self.line_number
+    }
+
+    fn parse_line(&mut self, line: &str) -> Result<(), String> {
+        let line = line.trim();
+        // The registered builders come first, as they may handle otherwise ignored lines.
+        if self.article_builder.is_open() && self.article_builder.parse_registered(line)? {
+            return Ok(());
+        }
+        if line.starts_with("<!DOCTYPE") {
+            self.dtd_version = DtdVersion::from_doctype(line);
+            if let (Some(declared), Some(dtd_version)) =
+                (DtdVersion::declared_file_name(line), self.dtd_version)
+            {
+                if declared != dtd_version.file_name() {
+                    log::warn!(
+                        "The DTD {} of {} has no fixture, reading it as {}.",
+                        declared,
+                        self.source,
+                        dtd_version.file_name()
+                    );
+                }
+            }
+            return Ok(());
+        }
+        if IGNORED_LINES
+            .iter()
+            .any(|target| line.starts_with(target) || line.ends_with(target))
+        {
+            return Ok(());
+        }
+
+        if !self.article_builder.is_open()
+            && !self.book_article_builder.is_open()
+            && self.delete_citation_builder.parse(line)?
+        {
+            if self.delete_citation_builder.can_build() {
+                self.pending_events.extend(
+                    core::mem::replace(
+                        &mut self.delete_citation_builder,
+                        DeleteCitationBuilder::new(),
+                    )
+                    .build()?
+                    .into_iter()
+                    .map(PubmedEvent::Deletion),
+                );
+            }
+            return Ok(());
+        }
+
+        if !self.article_builder.is_open() && self.book_article_builder.parse(line)? {
+            if self.book_article_builder.can_build() {
+                let mut book_article =
+                    core::mem::replace(&mut self.book_article_builder, BookArticleBuilder::new())
+                        .build()?;
+                book_article.normalize_text(&self.normalization);
+                self.pending_events
+                    .push_back(PubmedEvent::BookArticle(Box::new(book_article)));
+            }
+            return Ok(());
+        }
+
+        self.article_builder.parse(line)?;
+        if self.article_builder.can_build() {
+            let article_builder = self.new_article_builder();
+            let mut article =
+                core::mem::replace(&mut self.article_builder, article_builder).build()?;
+            article.normalize_text(&self.normalization);
+            self.pending_events
+                .push_back(PubmedEvent::Article(Box::new(article)));
+        }
+        Ok(())
+    }
+}
+
+impl<R: BufRead> Iterator for PubmedReader<R> {
+    type Item = Result<PubmedEvent, String>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if let Some(event) = self.pending_events.pop_front() {
+                return Some(Ok(event));
+            }
+            if self.failed {
+                return None;
+            }
+            let line = match self.lines.next()? {
+                Ok(line) => line,
+                Err(_) => {
+                    self.failed = true;
+                    return Some(Err(format!(
+                        "Failed with decompression of file {}.",
+                        self.source
+                    )));
+                }
+            };
+            self.line_number += 1;
+            if let Err(err) = self.parse_line(&line) {
+                self.article_builder = self.new_article_builder();
+                self.book_article_builder = BookArticleBuilder::new();
+                self.delete_citation_builder = DeleteCitationBuilder::new();
+                return Some(Err(format!("{} {}:{}", err, self.source, self.line_number)));
+            }
+        }
+    }
+}
@@ -0,0 +1,460 @@
+use crate::article::*;
+use crate::article_sink::file_name;
+use crate::dtd::DtdVersion;
+use flate2::write::GzEncoder;
+use flate2::Compression;
+use std::fs::File;
+use std::io::{BufWriter, Write};
+
+/// Escapes the characters that cannot appear as they are within XML text and attributes.
+fn escape(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for character in text.chars() {
+        match character {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            _ => escaped.push(character),
+        }
+    }
+    escaped
+}
+
+fn major_topic(is_major_topic: bool) -> &'static str {
+    if is_major_topic {
+        "Y"
+    } else {
+        "N"
+    }
+}
+
+/// Streaming writer of articles as a PubMed XML document.
+///
+/// The document is laid out one element per line, as in the files distributed
+/// by NLM, so that it can be read back by [`crate::PubmedReader`] into equal articles.
+/// Only the elements kept within [`Article`] are written: the authors and the elements
+/// collected in [`Article::extras`] are not. An article missing an element required
+/// by the DTD, such as its languages, is not written and its missing element is reported.
+pub struct PubmedWriter<W: Write> {
+    writer: W,
+    depth: usize,
+    /// Name of the file written, reported with the number of articles.
+    pub(crate) file_name: Option<String>,
+    pub(crate) articles: u64,
+}
+
+impl PubmedWriter<BufWriter<File>> {
+    /// Returns a writer of an uncompressed document at the provided path.
+    pub fn from_path(path: &str) -> Result<Self, String> {
+        let file =
+            File::create(path).map_err(|err| format!("Unable to create {}: {}", path, err))?;
+        Ok(PubmedWriter {
+            file_name: Some(file_name(path)),
+            ..PubmedWriter::new(BufWriter::new(file))?
+        })
+    }
+}
+
+impl<W: Write> PubmedWriter<W> {
+    /// Returns a writer that has already written the header of the document.
+    pub fn new(writer: W) -> Result<Self, String> {
+        let mut pubmed_writer = PubmedWriter {
+            writer,
+            depth: 0,
+            file_name: None,
+            articles: 0,
+        };
+        pubmed_writer.line("<?xml version=\"1.0\" ?>")?;
+        pubmed_writer.line(&DtdVersion::Pubmed2025.doctype())?;
+        pubmed_writer.open("PubmedArticleSet", &[])?;
+        Ok(pubmed_writer)
+    }
+
+    /// Closes the document and returns the underlying writer.
+    pub fn finish(mut self) -> Result<W, String> {
+        self.close("PubmedArticleSet")?;
+        self.flush_writer()?;
+        Ok(self.writer)
+    }
+
+    pub(crate) fn flush_writer(&mut self) -> Result<(), String> {
+        self.writer
+            .flush()
+            .map_err(|err| format!("Unable to flush the document: {}", err))
+    }
+
+    fn line(&mut self, content: &str) -> Result<(), String> {
+        writeln!(self.writer, "{}{}", "  ".repeat(self.depth), content)
+            .map_err(|err| format!("Unable to write the document: {}", err))
+    }
+
+    fn opening_tag(tag: &str, attributes: &[(&str, &str)]) -> String {
+        let attributes = attributes
+            .iter()
+            .map(|(key, value)| format!(" {}=\"{}\"", key, escape(value)))
+            .collect::<String>();
+        format!("<{}{}>", tag, attributes)
+    }
+
+    fn open(&mut self, tag: &str, attributes: &[(&str, &str)]) -> Result<(), String> {
+        self.line(&Self::opening_tag(tag, attributes))?;
+        self.depth += 1;
+        Ok(())
+    }
+
+    fn close(&mut self, tag: &str) -> Result<(), String> {
+        self.depth -= 1;
+        self.line(&format!("</{}>", tag))
+    }
+
+    fn text_element(
+        &mut self,
+        tag: &str,
+        attributes: &[(&str, &str)],
+        text: &str,
+    ) -> Result<(), String> {
+        self.line(&format!(
+            "{}{}</{}>",
+            Self::opening_tag(tag, attributes),
+            escape(text),
+            tag
+        ))
+    }
+
+    fn optional_text_element(&mut self, tag: &str, text: Option<&str>) -> Result<(), String> {
+        match text {
+            Some(text) => self.text_element(tag, &[], text),
+            None => Ok(()),
+        }
+    }
+
+    fn date(&mut self, tag: &str, date: &Date) -> Result<(), String> {
+        self.open(tag, &[])?;
+        self.optional_text_element("Year", date.year.map(|year| year.to_string()).as_deref())?;
+        self.optional_text_element("Month", date.month.as_deref())?;
+        self.optional_text_element("Day", date.day.map(|day| day.to_string()).as_deref())?;
+        self.optional_text_element("Season", date.season.as_deref())?;
+        self.optional_text_element("MedlineDate", date.medline_date.as_deref())?;
+        self.close(tag)
+    }
+
+    fn abstract_text(&mut self, tag: &str, abstract_text: &Abstract) -> Result<(), String> {
+        let mut attributes = Vec::new();
+        if let Some(abstract_type) = abstract_text.abstract_type.as_deref() {
+            attributes.push(("Type", abstract_type));
+        }
+        if let Some(language) = abstract_text.language.as_deref() {
+            attributes.push(("Language", language));
+        }
+        self.open(tag, &attributes)?;
+        self.text_element("AbstractText", &[], &abstract_text.text)?;
+        self.close(tag)
+    }
+
+    fn mesh_topic(&mut self, tag: &str, mesh_topic: &MeshTopic) -> Result<(), String> {
+        self.text_element(
+            tag,
+            &[
+                ("UI", &mesh_topic.code),
+                ("MajorTopicYN", major_topic(mesh_topic.is_major_topic)),
+            ],
+            &mesh_topic.name,
+        )
+    }
+
+    fn article_ids(&mut self, article_ids: &[ArticleId]) -> Result<(), String> {
+        self.open("ArticleIdList", &[])?;
+        for article_id in article_ids {
+            self.text_element(
+                "ArticleId",
+                &[("IdType", &article_id.id_type)],
+                &article_id.value,
+            )?;
+        }
+        self.close("ArticleIdList")
+    }
+
+    fn journal(
+        &mut self,
+        journal: &Journal,
+        issn: Option<(&str, &str)>,
+        cited_medium: &str,
+    ) -> Result<(), String> {
+        self.open("Journal", &[])?;
+        if let Some((issn, issn_type)) = issn {
+            self.text_element("ISSN", &[("IssnType", issn_type)], issn)?;
+        }
+        self.open("JournalIssue", &[("CitedMedium", cited_medium)])?;
+        self.optional_text_element("Volume", journal.journal_issue.volume.as_deref())?;
+        self.optional_text_element("Issue", journal.journal_issue.issue.as_deref())?;
+        self.date("PubDate", &journal.journal_issue.pubblication_date)?;
+        self.close("JournalIssue")?;
+        self.text_element("Title", &[], &journal.title)?;
+        self.optional_text_element("ISOAbbreviation", journal.iso_abbreviation.as_deref())?;
+        self.close("Journal")
+    }
+
+    fn medline_journal_info(
+        &mut self,
+        medline_journal_info: &MedlineJournalInfo,
+        medline_ta: &str,
+    ) -> Result<(), String> {
+        self.open("MedlineJournalInfo", &[])?;
+        self.optional_text_element("Country", medline_journal_info.country.as_deref())?;
+        self.text_element("MedlineTA", &[], medline_ta)?;
+        self.optional_text_element("NlmUniqueID", medline_journal_info.nlm_unique_id.as_deref())?;
+        self.optional_text_element("ISSNLinking", medline_journal_info.issn_linking.as_deref())?;
+        self.close("MedlineJournalInfo")
+    }
+
+    /// Writes the provided article as a `PubmedArticle`.
+    ///
+    /// Nothing is written when the article misses an element required by the DTD.
+    pub fn write_article(&mut self, article: &Article) -> Result<(), String> {
+        let missing = |element: &str| {
+            format!(
+                "Unable to write the article {}: its {} is missing.",
+                article.pubmed_id, element
+            )
+        };
+        let status = article
+            .status
+            .as_deref()
+            .ok_or_else(|| missing("MedlineCitation Status"))?;
+        let pub_model = article
+            .pub_model
+            .as_deref()
+            .ok_or_else(|| missing("Article PubModel"))?;
+        let journal = &article.journal;
+        let issn = match (journal.issn.as_deref(), journal.issn_type.as_deref()) {
+            (Some(issn), Some(issn_type)) => Some((issn, issn_type)),
+            (Some(_), None) => return Err(missing("ISSN IssnType")),
+            (None, _) => None,
+        };
+        let cited_medium = journal
+            .journal_issue
+            .cited_medium
+            .as_deref()
+            .ok_or_else(|| missing("JournalIssue CitedMedium"))?;
+        let pubblication_date = &journal.journal_issue.pubblication_date;
+        if pubblication_date.year.is_none() && pubblication_date.medline_date.is_none() {
+            return Err(missing("PubDate Year or MedlineDate"));
+        }
+        if article.pagination.is_none() && article.elocation_ids.is_empty() {
+            return Err(missing("Pagination or ELocationID"));
+        }
+        if article.languages.is_empty() {
+            return Err(missing("Language"));
+        }
+        if article.publication_types.is_empty() {
+            return Err(missing("PublicationType"));
+        }
+        let medline_journal_info = article
+            .medline_journal_info
+            .as_ref()
+            .ok_or_else(|| missing("MedlineJournalInfo"))?;
+        let medline_ta = medline_journal_info
+            .medline_ta
+            .as_deref()
+            .ok_or_else(|| missing("MedlineTA"))?;
+        // The PubmedData is optional, but holds a status and identifiers whenever it is written.
+        let publication_status = if article.publication_status.is_none()
+            && article.article_ids.is_empty()
+            && article.references.is_empty()
+        {
+            None
+        } else if article.article_ids.is_empty() {
+            return Err(missing("ArticleIdList"));
+        } else {
+            Some(
+                article
+                    .publication_status
+                    .as_deref()
+                    .ok_or_else(|| missing("PublicationStatus"))?,
+            )
+        };
+        if article
+            .references
+            .iter()
+            .any(|reference| reference.citation.is_none())
+        {
+            return Err(missing("Citation of a reference"));
+        }
+
+        self.open("PubmedArticle", &[])?;
+
+        let mut medline_citation_attributes = vec![("Status", status)];
+        if let Some(indexing_method) = article.indexing_method.as_deref() {
+            medline_citation_attributes.push(("IndexingMethod", indexing_method));
+        }
+        if let Some(owner) = article.owner.as_deref() {
+            medline_citation_attributes.push(("Owner", owner));
+        }
+        self.open("MedlineCitation", &medline_citation_attributes)?;
+        self.text_element(
+            "PMID",
+            &[("Version", &article.pmid_version.to_string())],
+            &article.pubmed_id.to_string(),
+        )?;
+        if let Some(completion_date) = article.completion_date.as_ref() {
+            self.date("DateCompleted", completion_date)?;
+        }
+        if let Some(revision_date) = article.revision_date.as_ref() {
+            self.date("DateRevised", revision_date)?;
+        }
+
+        self.open("Article", &[("PubModel", pub_model)])?;
+        self.journal(journal, issn, cited_medium)?;
+        match article.title.as_deref() {
+            Some(title) => self.text_element("ArticleTitle", &[], title)?,
+            None => self.line("<ArticleTitle/>")?,
+        }
+        if let Some(pagination) = article.pagination.as_ref() {
+            self.open("Pagination", &[])?;
+            self.optional_text_element("StartPage", pagination.start_page.as_deref())?;
+            self.optional_text_element("EndPage", pagination.end_page.as_deref())?;
+            match pagination.medline_pgn.as_deref() {
+                Some(medline_pgn) => self.text_element("MedlinePgn", &[], medline_pgn)?,
+                // A pagination without pages is read from an empty MedlinePgn.
+                None if pagination.start_page.is_none() => self.line("<MedlinePgn/>")?,
+                None => {}
+            }
+            self.close("Pagination")?;
+        }
+        for elocation_id in article.elocation_ids.iter() {
+            self.text_element(
+                "ELocationID",
+                &[("EIdType", &elocation_id.id_type), ("ValidYN", "Y")],
+                &elocation_id.value,
+            )?;
+        }
+        if let Some(abstract_text) = article.abstract_text.as_ref() {
+            self.abstract_text("Abstract", abstract_text)?;
+        }
+        for language in article.languages.iter() {
+            self.text_element("Language", &[], language)?;
+        }
+        self.open("PublicationTypeList", &[])?;
+        for publication_type in article.publication_types.iter() {
+            self.text_element(
+                "PublicationType",
+                &[("UI", &publication_type.code)],
+                &publication_type.name,
+            )?;
+        }
+        self.close("PublicationTypeList")?;
+        self.optional_text_element("VernacularTitle", article.vernacular_title.as_deref())?;
+        self.close("Article")?;
+        self.medline_journal_info(medline_journal_info, medline_ta)?;
+
+        if !article.chemical_list.is_empty() {
+            self.open("ChemicalList", &[])?;
+            for chemical in article.chemical_list.iter() {
+                self.open("Chemical", &[])?;
+                self.text_element("RegistryNumber", &[], &chemical.registry_number)?;
+                self.text_element(
+                    "NameOfSubstance",
+                    &[("UI", &chemical.code)],
+                    &chemical.name_of_substance,
+                )?;
+                self.close("Chemical")?;
+            }
+            self.close("ChemicalList")?;
+        }
+        if !article.suppl_mesh_list.is_empty() {
+            self.open("SupplMeshList", &[])?;
+            for suppl_mesh in article.suppl_mesh_list.iter() {
+                self.text_element(
+                    "SupplMeshName",
+                    &[("Type", &suppl_mesh.mesh_type), ("UI", &suppl_mesh.code)],
+                    &suppl_mesh.name,
+                )?;
+            }
+            self.close("SupplMeshList")?;
+        }
+        for citation_subset in article.citation_subsets.iter() {
+            self.text_element("CitationSubset", &[], citation_subset)?;
+        }
+        if !article.gene_symbol_list.is_empty() {
+            self.open("GeneSymbolList", &[])?;
+            for gene_symbol in article.gene_symbol_list.iter() {
+                self.text_element("GeneSymbol", &[], gene_symbol)?;
+            }
+            self.close("GeneSymbolList")?;
+        }
+        if !article.mesh_list.is_empty() {
+            self.open("MeshHeadingList", &[])?;
+            for mesh in article.mesh_list.iter() {
+                self.open("MeshHeading", &[])?;
+                self.mesh_topic("DescriptorName", &mesh.descriptor)?;
+                for qualifier in mesh.qualifiers.iter() {
+                    self.mesh_topic("QualifierName", qualifier)?;
+                }
+                self.close("MeshHeading")?;
+            }
+            self.close("MeshHeadingList")?;
+        }
+        for other_abstract_text in article.other_abstract_texts.iter() {
+            self.abstract_text("OtherAbstract", other_abstract_text)?;
+        }
+        // The keywords of each owner are written as a list of their own.
+        for keywords in article
+            .keywords
+            .chunk_by(|left, right| left.owner == right.owner)
+        {
+            self.open("KeywordList", &[("Owner", &keywords[0].owner)])?;
+            for keyword in keywords {
+                self.text_element(
+                    "Keyword",
+                    &[("MajorTopicYN", major_topic(keyword.is_major_topic))],
+                    &keyword.name,
+                )?;
+            }
+            self.close("KeywordList")?;
+        }
+        self.close("MedlineCitation")?;
+
+        if let Some(publication_status) = publication_status {
+            self.open("PubmedData", &[])?;
+            self.text_element("PublicationStatus", &[], publication_status)?;
+            self.article_ids(&article.article_ids)?;
+            if !article.references.is_empty() {
+                self.open("ReferenceList", &[])?;
+                for reference in article.references.iter() {
+                    self.open("Reference", &[])?;
+                    self.optional_text_element("Citation", reference.citation.as_deref())?;
+                    if !reference.article_ids.is_empty() {
+                        self.article_ids(&reference.article_ids)?;
+                    }
+                    self.close("Reference")?;
+                }
+                self.close("ReferenceList")?;
+            }
+            self.close("PubmedData")?;
+        }
+
+        self.close("PubmedArticle")?;
+        self.articles += 1;
+        Ok(())
+    }
+}
+
+/// Writes the provided articles to a gzipped PubMed XML file, as the ones read by [`crate::parse_single_pubmed`].
+pub fn write_pubmed<'a>(
+    path: &str,
+    articles: impl IntoIterator<Item = &'a Article>,
+) -> Result<(), String> {
+    let file = File::create(path).map_err(|err| format!("Unable to create {}: {}", path, err))?;
+    let mut writer =
+        PubmedWriter::new(GzEncoder::new(BufWriter::new(file), Compression::default()))?;
+    for article in articles {
+        writer.write_article(article)?;
+    }
+    writer
+        .finish()?
+        .finish()
+        .map_err(|err| format!("Unable to compress {}: {}", path, err))?
+        .flush()
+        .map_err(|err| format!("Unable to flush {}: {}", path, err))
+}
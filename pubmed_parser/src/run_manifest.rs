@@ -0,0 +1,168 @@
+use crate::article_source::ArticleSource;
+use crate::graph_export::GraphExportConfig;
+use crate::text_normalization::TextNormalization;
+use chrono::{SecondsFormat, Utc};
+use rayon::prelude::*;
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use std::collections::BTreeMap;
+use std::fs::File;
+use std::io::{BufReader, Read};
+use std::path::Path;
+
+/// Input file of a run, identified by its size and SHA-256 checksum.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct InputFile {
+    pub(crate) path: String,
+    pub(crate) size: u64,
+    pub(crate) sha256: String,
+}
+
+impl InputFile {
+    /// Reads the whole file to compute its checksum.
+    pub fn from_path(path: &str) -> Result<Self, String> {
+        let mut file =
+            File::open(path).map_err(|err| format!("Unable to open {}: {}", path, err))?;
+        let mut hasher = Sha256::new();
+        let mut buffer = vec![0; 1 << 16];
+        let mut size = 0;
+        loop {
+            let read = file
+                .read(&mut buffer)
+                .map_err(|err| format!("Unable to read {}: {}", path, err))?;
+            if read == 0 {
+                break;
+            }
+            hasher.update(&buffer[..read]);
+            size += read as u64;
+        }
+        Ok(InputFile {
+            path: path.to_string(),
+            size,
+            sha256: format!("{:x}", hasher.finalize()),
+        })
+    }
+
+    pub fn path(&self) -> &str {
+        &self.path
+    }
+
+    pub fn size(&self) -> u64 {
+        self.size
+    }
+
+    pub fn sha256(&self) -> &str {
+        &self.sha256
+    }
+}
+
+fn now() -> String {
+    Utc::now().to_rfc3339_opts(SecondsFormat::Secs, true)
+}
+
+/// Provenance of the graph written by a run: what was read, how, when, and how much was written.
+///
+/// It is written as `manifest.json` next to the graph, see [`GraphExportConfig::manifest_path`].
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RunManifest {
+    pub(crate) crate_version: String,
+    pub(crate) arguments: Vec<String>,
+    pub(crate) config: GraphExportConfig,
+    pub(crate) normalization: TextNormalization,
+    pub(crate) inputs: Vec<InputFile>,
+    pub(crate) started_at: String,
+    pub(crate) finished_at: Option<String>,
+    /// Number of rows, or articles, written to each output file, by file name.
+    pub(crate) rows: BTreeMap<String, u64>,
+}
+
+impl RunManifest {
+    /// Returns the manifest of a run starting now.
+    pub fn start(config: &GraphExportConfig, normalization: &TextNormalization) -> Self {
+        RunManifest {
+            crate_version: env!("CARGO_PKG_VERSION").to_string(),
+            arguments: Vec::new(),
+            config: config.clone(),
+            normalization: *normalization,
+            inputs: Vec::new(),
+            started_at: now(),
+            finished_at: None,
+            rows: BTreeMap::new(),
+        }
+    }
+
+    /// Records the command line of the run.
+    pub fn with_arguments(mut self, arguments: Vec<String>) -> Self {
+        self.arguments = arguments;
+        self
+    }
+
+    /// Records the files of the source, checksummed in parallel.
+    pub fn with_inputs(mut self, source: &dyn ArticleSource) -> Result<Self, String> {
+        self.inputs = source
+            .paths()
+            .par_iter()
+            .map(|path| InputFile::from_path(path))
+            .collect::<Result<Vec<_>, String>>()?;
+        Ok(self)
+    }
+
+    /// Records the number of rows written to an output file, as counted by its sink.
+    pub fn with_rows(mut self, file_name: &str, rows: u64) -> Self {
+        self.rows.insert(file_name.to_string(), rows);
+        self
+    }
+
+    /// Records the end of the run.
+    pub fn finish(mut self) -> Self {
+        self.finished_at = Some(now());
+        self
+    }
+
+    pub fn from_path(path: &str) -> Result<Self, String> {
+        let file = File::open(path).map_err(|err| format!("Unable to open {}: {}", path, err))?;
+        serde_json::from_reader(BufReader::new(file))
+            .map_err(|err| format!("Unable to read the manifest {}: {}", path, err))
+    }
+
+    pub fn write(&self, path: &Path) -> Result<(), String> {
+        let json = serde_json::to_string_pretty(self)
+            .map_err(|err| format!("Unable to write the manifest as JSON: {}", err))?;
+        std::fs::write(path, json + "\n")
+            .map_err(|err| format!("Unable to write {}: {}", path.display(), err))
+    }
+
+    pub fn crate_version(&self) -> &str {
+        &self.crate_version
+    }
+
+    pub fn arguments(&self) -> &[String] {
+        &self.arguments
+    }
+
+    pub fn config(&self) -> &GraphExportConfig {
+        &self.config
+    }
+
+    pub fn normalization(&self) -> &TextNormalization {
+        &self.normalization
+    }
+
+    pub fn inputs(&self) -> &[InputFile] {
+        &self.inputs
+    }
+
+    /// Returns when the run started, in RFC 3339 format.
+    pub fn started_at(&self) -> &str {
+        &self.started_at
+    }
+
+    /// Returns when the run finished, in RFC 3339 format, unless it did not.
+    pub fn finished_at(&self) -> Option<&str> {
+        self.finished_at.as_deref()
+    }
+
+    pub fn rows(&self) -> &BTreeMap<String, u64> {
+        &self.rows
+    }
+}
@@ -0,0 +1,127 @@
+use serde::{Deserialize, Serialize};
+use unicode_normalization::UnicodeNormalization;
+
+/// Decodes the predefined XML entities and the numeric character references.
+///
+/// Unknown or malformed entities are kept as they appear in the text.
+pub(crate) fn decode_entities(text: &str) -> String {
+    if !text.contains('&') {
+        return text.to_string();
+    }
+    let mut decoded = String::with_capacity(text.len());
+    let mut rest = text;
+    while let Some(start) = rest.find('&') {
+        decoded.push_str(&rest[..start]);
+        rest = &rest[start..];
+        let decoded_entity = rest.find(';').and_then(|end| {
+            let character = match &rest[1..end] {
+                "amp" => Some('&'),
+                "lt" => Some('<'),
+                "gt" => Some('>'),
+                "quot" => Some('"'),
+                "apos" => Some('\''),
+                entity => entity
+                    .strip_prefix("#x")
+                    .or_else(|| entity.strip_prefix("#X"))
+                    .map(|hexadecimal| u32::from_str_radix(hexadecimal, 16))
+                    .or_else(|| entity.strip_prefix('#').map(str::parse::<u32>))
+                    .and_then(|code| code.ok())
+                    .and_then(char::from_u32),
+            };
+            character.map(|character| (character, end))
+        });
+        match decoded_entity {
+            Some((character, end)) => {
+                decoded.push(character);
+                rest = &rest[end + 1..];
+            }
+            None => {
+                decoded.push('&');
+                rest = &rest[1..];
+            }
+        }
+    }
+    decoded.push_str(rest);
+    decoded
+}
+
+/// Optional clean-up steps applied to the textual fields of an article.
+///
+/// Entity decoding always happens while parsing, these steps are applied
+/// afterwards and are all disabled by default.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TextNormalization {
+    nfkc: bool,
+    collapse_whitespace: bool,
+    remove_control_characters: bool,
+}
+
+impl TextNormalization {
+    /// Returns a normalization with every step disabled.
+    pub fn new() -> Self {
+        TextNormalization::default()
+    }
+
+    /// Returns a normalization with every step enabled.
+    pub fn all() -> Self {
+        TextNormalization {
+            nfkc: true,
+            collapse_whitespace: true,
+            remove_control_characters: true,
+        }
+    }
+
+    pub fn with_nfkc(mut self, nfkc: bool) -> Self {
+        self.nfkc = nfkc;
+        self
+    }
+
+    pub fn with_collapse_whitespace(mut self, collapse_whitespace: bool) -> Self {
+        self.collapse_whitespace = collapse_whitespace;
+        self
+    }
+
+    pub fn with_remove_control_characters(mut self, remove_control_characters: bool) -> Self {
+        self.remove_control_characters = remove_control_characters;
+        self
+    }
+
+    pub fn is_noop(&self) -> bool {
+        !self.nfkc && !self.collapse_whitespace && !self.remove_control_characters
+    }
+
+    /// Returns the normalized version of the provided text.
+    ///
+    /// Control characters that are whitespace, such as tabs and newlines,
+    /// are replaced by a space instead of being dropped.
+    pub fn normalize(&self, text: &str) -> String {
+        let mut text = text.to_string();
+        if self.remove_control_characters {
+            text = text
+                .chars()
+                .filter_map(|character| {
+                    if !character.is_control() {
+                        Some(character)
+                    } else if character.is_whitespace() {
+                        Some(' ')
+                    } else {
+                        None
+                    }
+                })
+                .collect();
+        }
+        if self.nfkc {
+            text = text.nfkc().collect();
+        }
+        if self.collapse_whitespace {
+            text = text.split_whitespace().collect::<Vec<&str>>().join(" ");
+        }
+        text
+    }
+
+    pub(crate) fn normalize_in_place(&self, text: &mut String) {
+        if !self.is_noop() {
+            *text = self.normalize(text);
+        }
+    }
+}
@@ -0,0 +1,777 @@
+//! Generator of random `PubmedArticle` documents, valid against the PubMed DTD,
+//! together with the values the parser is expected to read from them.
+use proptest::collection::vec;
+use proptest::option;
+use proptest::prelude::*;
+use pubmed_parser::*;
+
+/// The values of an article that the parser is expected to read.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ExpectedArticle {
+    pub pubmed_id: u32,
+    pub pmid_version: u16,
+    pub status: String,
+    pub owner: String,
+    pub indexing_method: Option<String>,
+    pub completion_date: Option<(u16, String, u8)>,
+    pub revision_date: Option<(u16, String, u8)>,
+    pub journal: ExpectedJournal,
+    pub title: Option<String>,
+    pub medline_pgn: Option<String>,
+    pub elocation_ids: Vec<(String, String)>,
+    pub abstract_texts: Vec<String>,
+    pub languages: Vec<String>,
+    pub publication_types: Vec<(String, String)>,
+    pub vernacular_title: Option<String>,
+    pub chemicals: Vec<(String, String, String)>,
+    pub suppl_meshes: Vec<(String, String, String)>,
+    pub citation_subsets: Vec<String>,
+    pub gene_symbols: Vec<String>,
+    pub meshes: Vec<(ExpectedMeshTopic, Vec<ExpectedMeshTopic>)>,
+    pub other_abstract: Option<(String, String, String)>,
+    pub keywords: Option<(String, Vec<(String, bool)>)>,
+    pub article_ids: Vec<(String, String)>,
+    pub references: Vec<(String, Option<u32>)>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ExpectedJournal {
+    pub issn: Option<String>,
+    pub title: String,
+    pub iso_abbreviation: Option<String>,
+    pub volume: Option<String>,
+    pub issue: Option<String>,
+    pub year: u16,
+    pub month: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ExpectedMeshTopic {
+    pub code: String,
+    pub name: String,
+    pub is_major_topic: bool,
+}
+
+impl ExpectedArticle {
+    /// Returns the values read from the provided article, in the same shape as the expected ones.
+    pub fn from_article(article: &Article) -> Self {
+        let date = |date: Option<&Date>| {
+            date.map(|date| {
+                (
+                    date.year().unwrap(),
+                    date.month().unwrap().to_string(),
+                    date.day().unwrap(),
+                )
+            })
+        };
+        let mesh_topic = |topic: &MeshTopic| ExpectedMeshTopic {
+            code: topic.code().to_string(),
+            name: topic.name().to_string(),
+            is_major_topic: topic.is_major_topic(),
+        };
+        let ids = |article_ids: &[ArticleId]| {
+            article_ids
+                .iter()
+                .map(|article_id| {
+                    (
+                        article_id.id_type().to_string(),
+                        article_id.value().to_string(),
+                    )
+                })
+                .collect()
+        };
+        let journal = article.journal();
+        ExpectedArticle {
+            pubmed_id: article.pubmed_id(),
+            pmid_version: article.pmid_version(),
+            status: article.status().unwrap().to_string(),
+            owner: article.owner().unwrap().to_string(),
+            indexing_method: article.indexing_method().map(str::to_string),
+            completion_date: date(article.completion_date()),
+            revision_date: date(article.revision_date()),
+            journal: ExpectedJournal {
+                issn: journal.issn().map(str::to_string),
+                title: journal.title().to_string(),
+                iso_abbreviation: journal.iso_abbreviation().map(str::to_string),
+                volume: journal.journal_issue().volume().map(str::to_string),
+                issue: journal.journal_issue().issue().map(str::to_string),
+                year: journal.journal_issue().pubblication_date().year().unwrap(),
+                month: journal
+                    .journal_issue()
+                    .pubblication_date()
+                    .month()
+                    .map(str::to_string),
+            },
+            title: article.title().map(str::to_string),
+            medline_pgn: article
+                .pagination()
+                .and_then(|pagination| pagination.medline_pgn())
+                .map(str::to_string),
+            elocation_ids: ids(article.elocation_ids()),
+            abstract_texts: article
+                .abstract_text()
+                .map(|abstract_text| vec![abstract_text.text().to_string()])
+                .unwrap_or_default(),
+            languages: article.languages().to_vec(),
+            publication_types: article
+                .publication_types()
+                .iter()
+                .map(|publication_type| {
+                    (
+                        publication_type.code().to_string(),
+                        publication_type.name().to_string(),
+                    )
+                })
+                .collect(),
+            vernacular_title: article.vernacular_title().map(str::to_string),
+            chemicals: article
+                .chemical_list()
+                .iter()
+                .map(|chemical| {
+                    (
+                        chemical.registry_number().to_string(),
+                        chemical.code().to_string(),
+                        chemical.name_of_substance().to_string(),
+                    )
+                })
+                .collect(),
+            suppl_meshes: article
+                .suppl_mesh_list()
+                .iter()
+                .map(|suppl_mesh| {
+                    (
+                        suppl_mesh.mesh_type().to_string(),
+                        suppl_mesh.code().to_string(),
+                        suppl_mesh.name().to_string(),
+                    )
+                })
+                .collect(),
+            citation_subsets: article.citation_subsets().to_vec(),
+            gene_symbols: article.gene_symbol_list().to_vec(),
+            meshes: article
+                .mesh_list()
+                .iter()
+                .map(|mesh| {
+                    (
+                        mesh_topic(mesh.descriptor()),
+                        mesh.qualifiers().iter().map(mesh_topic).collect(),
+                    )
+                })
+                .collect(),
+            other_abstract: article.other_abstract_texts().first().map(|other| {
+                (
+                    other.abstract_type().unwrap().to_string(),
+                    other.language().unwrap().to_string(),
+                    other.text().to_string(),
+                )
+            }),
+            keywords: article.keywords().first().map(|first| {
+                (
+                    first.owner().to_string(),
+                    article
+                        .keywords()
+                        .iter()
+                        .map(|keyword| (keyword.name().to_string(), keyword.is_major_topic()))
+                        .collect(),
+                )
+            }),
+            article_ids: ids(article.article_ids()),
+            references: article
+                .references()
+                .iter()
+                .map(|reference| {
+                    (
+                        reference.citation().unwrap().to_string(),
+                        reference.pubmed_id(),
+                    )
+                })
+                .collect(),
+        }
+    }
+
+    /// Returns the expected values in the shape read by [`ExpectedArticle::from_article`].
+    ///
+    /// The abstract texts are joined and only the keywords of the `PIP` and `KIE`
+    /// lists, the ones kept by the parser, are expected.
+    pub fn normalized(&self) -> Self {
+        let mut expected = self.clone();
+        if !expected.abstract_texts.is_empty() {
+            expected.abstract_texts = vec![expected.abstract_texts.join(" ")];
+        }
+        expected.keywords = expected
+            .keywords
+            .filter(|(owner, _)| owner == "PIP" || owner == "KIE");
+        expected
+    }
+}
+
+/// A generated document holding a single article.
+#[derive(Debug, Clone)]
+pub struct GeneratedArticle {
+    pub xml: String,
+    pub expected: ExpectedArticle,
+}
+
+/// Returns text made of words mixing characters that must be escaped, accents and punctuation.
+fn text() -> impl Strategy<Value = String> {
+    vec("[A-Za-z0-9éßü&<>\"'.,;:()/-]{1,8}", 1..6).prop_map(|words| words.join(" "))
+}
+
+fn code(prefix: &'static str) -> impl Strategy<Value = String> {
+    (0..1_000_000u32).prop_map(move |number| format!("{}{:06}", prefix, number))
+}
+
+fn date() -> impl Strategy<Value = (u16, String, u8)> {
+    (1950..2030u16, 1..=12u8, 1..=28u8)
+        .prop_map(|(year, month, day)| (year, format!("{:02}", month), day))
+}
+
+fn mesh_topic(prefix: &'static str) -> impl Strategy<Value = ExpectedMeshTopic> {
+    (code(prefix), text(), any::<bool>()).prop_map(|(code, name, is_major_topic)| {
+        ExpectedMeshTopic {
+            code,
+            name,
+            is_major_topic,
+        }
+    })
+}
+
+fn journal() -> impl Strategy<Value = ExpectedJournal> {
+    (
+        option::of("[0-9]{4}-[0-9]{3}[0-9X]"),
+        text(),
+        option::of(text()),
+        option::of("[0-9]{1,3}"),
+        option::of("[0-9]{1,2}( Suppl [0-9])?"),
+        1900..2030u16,
+        option::of(prop::sample::select(vec![
+            "Jan", "Feb", "Mar", "Apr", "May", "Jun", "Jul", "Aug", "Sep", "Oct", "Nov", "Dec",
+        ])),
+    )
+        .prop_map(
+            |(issn, title, iso_abbreviation, volume, issue, year, month)| ExpectedJournal {
+                issn,
+                title,
+                iso_abbreviation,
+                volume,
+                issue,
+                year,
+                month: month.map(str::to_string),
+            },
+        )
+}
+
+/// Returns the expected values of a random article.
+pub fn expected_article() -> impl Strategy<Value = ExpectedArticle> {
+    let header = (
+        1..40_000_000u32,
+        1..4u16,
+        prop::sample::select(vec![
+            "MEDLINE",
+            "PubMed-not-MEDLINE",
+            "In-Data-Review",
+            "In-Process",
+            "Publisher",
+        ]),
+        prop::sample::select(vec!["NLM", "NASA", "PIP", "KIE", "HSR", "HMD"]),
+        option::of(prop::sample::select(vec!["Automated", "Curated"])),
+        option::of(date()),
+        option::of(date()),
+    );
+    let article = (
+        journal(),
+        option::of(text()),
+        option::of("[0-9]{1,4}(-[0-9]{1,4})?"),
+        vec(
+            prop_oneof![
+                "10\\.[0-9]{4}/[a-z0-9.]{1,10}".prop_map(|doi| ("doi".to_string(), doi)),
+                "S[0-9]{4}-[0-9]{4}".prop_map(|pii| ("pii".to_string(), pii)),
+            ],
+            0..3,
+        ),
+        vec(text(), 0..4),
+        vec(
+            prop::sample::select(vec!["eng", "fre", "ger", "spa", "jpn"]).prop_map(str::to_string),
+            1..3,
+        ),
+        vec(
+            prop::sample::select(vec![
+                ("D016428", "Journal Article"),
+                ("D016454", "Review"),
+                ("D013485", "Research Support, Non-U.S. Gov't"),
+            ])
+            .prop_map(|(code, name)| (code.to_string(), name.to_string())),
+            1..3,
+        ),
+        option::of(text()),
+    );
+    let medline_citation = (
+        vec(("[0-9A-Z]{1,10}", code("D"), text()), 0..3),
+        vec(
+            (
+                prop::sample::select(vec!["Disease", "Protocol", "Organism"])
+                    .prop_map(str::to_string),
+                code("C"),
+                text(),
+            ),
+            0..3,
+        ),
+        vec(
+            prop::sample::select(vec!["IM", "S", "AIM", "X"]).prop_map(str::to_string),
+            0..3,
+        ),
+        vec("[A-Z][A-Z0-9]{1,5}", 0..3),
+        vec((mesh_topic("D"), vec(mesh_topic("Q"), 0..3)), 0..4),
+        option::of((
+            prop::sample::select(vec!["Publisher", "AAMC", "KIE", "NASA"]).prop_map(str::to_string),
+            prop::sample::select(vec!["eng", "fre", "spa"]).prop_map(str::to_string),
+            text(),
+        )),
+        option::of((
+            prop::sample::select(vec!["PIP", "KIE", "NOTNLM", "NLM"]).prop_map(str::to_string),
+            vec((text(), any::<bool>()), 1..4),
+        )),
+    );
+    let pubmed_data = (
+        option::of("10\\.[0-9]{4}/[a-z0-9.]{1,10}"),
+        option::of("PMC[0-9]{1,7}"),
+        vec((text(), option::of(1..40_000_000u32)), 0..4),
+    );
+    (header, article, medline_citation, pubmed_data).prop_map(
+        |(
+            (
+                pubmed_id,
+                pmid_version,
+                status,
+                owner,
+                indexing_method,
+                completion_date,
+                revision_date,
+            ),
+            (
+                journal,
+                title,
+                medline_pgn,
+                elocation_ids,
+                abstract_texts,
+                languages,
+                publication_types,
+                vernacular_title,
+            ),
+            (
+                chemicals,
+                suppl_meshes,
+                citation_subsets,
+                gene_symbols,
+                meshes,
+                other_abstract,
+                keywords,
+            ),
+            (doi, pmcid, references),
+        )| {
+            // The DTD requires a pagination when there is no ELocationID.
+            let medline_pgn = match medline_pgn {
+                None if elocation_ids.is_empty() => Some("1".to_string()),
+                medline_pgn => medline_pgn,
+            };
+            let mut article_ids = vec![("pubmed".to_string(), pubmed_id.to_string())];
+            article_ids.extend(doi.map(|doi| ("doi".to_string(), doi)));
+            article_ids.extend(pmcid.map(|pmcid| ("pmc".to_string(), pmcid)));
+            ExpectedArticle {
+                pubmed_id,
+                pmid_version,
+                status: status.to_string(),
+                owner: owner.to_string(),
+                indexing_method: indexing_method.map(str::to_string),
+                completion_date,
+                revision_date,
+                journal,
+                title,
+                medline_pgn,
+                elocation_ids,
+                abstract_texts,
+                languages,
+                publication_types,
+                vernacular_title,
+                chemicals,
+                suppl_meshes,
+                citation_subsets,
+                gene_symbols,
+                meshes,
+                other_abstract,
+                keywords,
+                article_ids,
+                references,
+            }
+        },
+    )
+}
+
+/// Returns a random article rendered with a random layout.
+pub fn generated_article() -> impl Strategy<Value = GeneratedArticle> {
+    (expected_article(), vec(any::<u8>(), 64)).prop_map(|(expected, choices)| {
+        let mut renderer = Renderer::new(choices);
+        renderer.article(&expected);
+        GeneratedArticle {
+            xml: crate::common::pubmed_article_set(&[&renderer.xml]),
+            expected: expected.with_elocation_ids_merged(),
+        }
+    })
+}
+
+impl ExpectedArticle {
+    /// Adds the ELocationIDs missing from the ArticleIdList to the article ids, as the parser does.
+    fn with_elocation_ids_merged(mut self) -> Self {
+        for (id_type, value) in self.elocation_ids.iter() {
+            if !self.article_ids.iter().any(|(other_type, other_value)| {
+                other_type == id_type && other_value.eq_ignore_ascii_case(value)
+            }) {
+                self.article_ids.push((id_type.clone(), value.clone()));
+            }
+        }
+        self
+    }
+}
+
+/// Renders the XML of an article, taking every layout decision from the provided choices.
+struct Renderer {
+    choices: Vec<u8>,
+    position: usize,
+    depth: usize,
+    xml: String,
+}
+
+impl Renderer {
+    fn new(choices: Vec<u8>) -> Self {
+        Renderer {
+            choices,
+            position: 0,
+            depth: 0,
+            xml: String::new(),
+        }
+    }
+
+    fn choose(&mut self, options: u8) -> u8 {
+        let choice = self.choices[self.position % self.choices.len()];
+        self.position += 1;
+        choice % options
+    }
+
+    /// Writes a line with a random indentation, trailing whitespace and blank line after it.
+    fn line(&mut self, content: &str) {
+        let indentation = match self.choose(4) {
+            0 => "  ".repeat(self.depth),
+            1 => "\t".repeat(self.depth),
+            2 => String::new(),
+            _ => " \t ".repeat(self.depth),
+        };
+        let trailing = match self.choose(8) {
+            0 => " ",
+            1 => "\t",
+            _ => "",
+        };
+        self.xml
+            .push_str(&format!("{}{}{}\n", indentation, content, trailing));
+        if self.choose(16) == 0 {
+            self.xml.push('\n');
+        }
+    }
+
+    fn open(&mut self, tag: &str, attributes: &[(&str, &str)]) {
+        let tag = Self::opening_tag(tag, attributes);
+        self.line(&tag);
+        self.depth += 1;
+    }
+
+    fn close(&mut self, tag: &str) {
+        self.depth -= 1;
+        self.line(&format!("</{}>", tag));
+    }
+
+    fn opening_tag(tag: &str, attributes: &[(&str, &str)]) -> String {
+        let attributes = attributes
+            .iter()
+            .map(|(key, value)| format!(" {}=\"{}\"", key, value))
+            .collect::<String>();
+        format!("<{}{}>", tag, attributes)
+    }
+
+    /// Escapes the text, picking among the equivalent ways to write each character.
+    fn escape(&mut self, text: &str) -> String {
+        let mut escaped = String::new();
+        for character in text.chars() {
+            let code = character as u32;
+            match character {
+                '&' => escaped.push_str(match self.choose(3) {
+                    0 => "&amp;",
+                    1 => "&#38;",
+                    _ => "&#x26;",
+                }),
+                '<' => escaped.push_str(match self.choose(2) {
+                    0 => "&lt;",
+                    _ => "&#60;",
+                }),
+                '>' | '"' | '\'' | 'é' | 'ß' | 'ü' => match self.choose(3) {
+                    0 => escaped.push(character),
+                    1 => escaped.push_str(&format!("&#{};", code)),
+                    _ => escaped.push_str(&format!("&#x{:X};", code)),
+                },
+                _ => escaped.push(character),
+            }
+        }
+        escaped
+    }
+
+    /// Writes an element holding text, possibly spread over several lines.
+    fn text_element(&mut self, tag: &str, attributes: &[(&str, &str)], text: &str) {
+        let opening_tag = Self::opening_tag(tag, attributes);
+        let words = text
+            .split(' ')
+            .map(|word| self.escape(word))
+            .collect::<Vec<String>>();
+        match self.choose(4) {
+            // The text is split between the line of the opening tag and the one of the closing tag.
+            0 if words.len() > 1 => {
+                let split = 1 + self.choose(words.len() as u8 - 1) as usize;
+                self.line(&format!("{}{}", opening_tag, words[..split].join(" ")));
+                self.line(&format!("{}</{}>", words[split..].join(" "), tag));
+            }
+            // The tags are on their own lines and each word on a line of its own.
+            1 => {
+                self.line(&opening_tag);
+                for word in words.iter() {
+                    self.line(word);
+                }
+                self.line(&format!("</{}>", tag));
+            }
+            _ => self.line(&format!("{}{}</{}>", opening_tag, words.join(" "), tag)),
+        }
+    }
+
+    fn date(&mut self, tag: &str, (year, month, day): &(u16, String, u8)) {
+        self.open(tag, &[]);
+        self.text_element("Year", &[], &year.to_string());
+        self.text_element("Month", &[], month);
+        self.text_element("Day", &[], &day.to_string());
+        self.close(tag);
+    }
+
+    fn major_topic(is_major_topic: bool) -> &'static str {
+        if is_major_topic {
+            "Y"
+        } else {
+            "N"
+        }
+    }
+
+    fn article(&mut self, expected: &ExpectedArticle) {
+        self.open("PubmedArticle", &[]);
+        let mut medline_citation_attributes = vec![("Status", expected.status.as_str())];
+        if let Some(indexing_method) = expected.indexing_method.as_deref() {
+            medline_citation_attributes.push(("IndexingMethod", indexing_method));
+        }
+        medline_citation_attributes.push(("Owner", expected.owner.as_str()));
+        self.open("MedlineCitation", &medline_citation_attributes);
+        self.text_element(
+            "PMID",
+            &[("Version", &expected.pmid_version.to_string())],
+            &expected.pubmed_id.to_string(),
+        );
+        if let Some(completion_date) = expected.completion_date.as_ref() {
+            self.date("DateCompleted", completion_date);
+        }
+        if let Some(revision_date) = expected.revision_date.as_ref() {
+            self.date("DateRevised", revision_date);
+        }
+
+        self.open("Article", &[("PubModel", "Print")]);
+        self.journal(&expected.journal);
+        match expected.title.as_deref() {
+            Some(title) => self.text_element("ArticleTitle", &[], title),
+            None => self.line("<ArticleTitle/>"),
+        }
+        if let Some(medline_pgn) = expected.medline_pgn.as_deref() {
+            self.open("Pagination", &[]);
+            self.text_element("MedlinePgn", &[], medline_pgn);
+            self.close("Pagination");
+        }
+        for (id_type, value) in expected.elocation_ids.iter() {
+            self.text_element(
+                "ELocationID",
+                &[("EIdType", id_type), ("ValidYN", "Y")],
+                value,
+            );
+        }
+        if !expected.abstract_texts.is_empty() {
+            self.open("Abstract", &[]);
+            for abstract_text in expected.abstract_texts.iter() {
+                if self.choose(2) == 0 {
+                    self.text_element(
+                        "AbstractText",
+                        &[("Label", "RESULTS"), ("NlmCategory", "RESULTS")],
+                        abstract_text,
+                    );
+                } else {
+                    self.text_element("AbstractText", &[], abstract_text);
+                }
+            }
+            self.close("Abstract");
+        }
+        if self.choose(2) == 0 {
+            self.open("AuthorList", &[("CompleteYN", "Y")]);
+            self.open("Author", &[("ValidYN", "Y")]);
+            self.text_element("LastName", &[], "Doe");
+            self.text_element("Initials", &[], "J");
+            self.close("Author");
+            self.close("AuthorList");
+        }
+        for language in expected.languages.iter() {
+            self.text_element("Language", &[], language);
+        }
+        self.open("PublicationTypeList", &[]);
+        for (code, name) in expected.publication_types.iter() {
+            self.text_element("PublicationType", &[("UI", code)], name);
+        }
+        self.close("PublicationTypeList");
+        if let Some(vernacular_title) = expected.vernacular_title.as_deref() {
+            self.text_element("VernacularTitle", &[], vernacular_title);
+        }
+        self.close("Article");
+
+        self.open("MedlineJournalInfo", &[]);
+        self.text_element("Country", &[], "United States");
+        self.text_element("MedlineTA", &[], "Fixture J");
+        self.text_element("NlmUniqueID", &[], "0000001");
+        self.close("MedlineJournalInfo");
+        if !expected.chemicals.is_empty() {
+            self.open("ChemicalList", &[]);
+            for (registry_number, code, name) in expected.chemicals.iter() {
+                self.open("Chemical", &[]);
+                self.text_element("RegistryNumber", &[], registry_number);
+                self.text_element("NameOfSubstance", &[("UI", code)], name);
+                self.close("Chemical");
+            }
+            self.close("ChemicalList");
+        }
+        if !expected.suppl_meshes.is_empty() {
+            self.open("SupplMeshList", &[]);
+            for (mesh_type, code, name) in expected.suppl_meshes.iter() {
+                self.text_element("SupplMeshName", &[("Type", mesh_type), ("UI", code)], name);
+            }
+            self.close("SupplMeshList");
+        }
+        for citation_subset in expected.citation_subsets.iter() {
+            self.text_element("CitationSubset", &[], citation_subset);
+        }
+        if !expected.gene_symbols.is_empty() {
+            self.open("GeneSymbolList", &[]);
+            for gene_symbol in expected.gene_symbols.iter() {
+                self.text_element("GeneSymbol", &[], gene_symbol);
+            }
+            self.close("GeneSymbolList");
+        }
+        if !expected.meshes.is_empty() {
+            self.open("MeshHeadingList", &[]);
+            for (descriptor, qualifiers) in expected.meshes.iter() {
+                self.open("MeshHeading", &[]);
+                self.text_element(
+                    "DescriptorName",
+                    &[
+                        ("UI", &descriptor.code),
+                        ("MajorTopicYN", Self::major_topic(descriptor.is_major_topic)),
+                    ],
+                    &descriptor.name,
+                );
+                for qualifier in qualifiers.iter() {
+                    self.text_element(
+                        "QualifierName",
+                        &[
+                            ("UI", &qualifier.code),
+                            ("MajorTopicYN", Self::major_topic(qualifier.is_major_topic)),
+                        ],
+                        &qualifier.name,
+                    );
+                }
+                self.close("MeshHeading");
+            }
+            self.close("MeshHeadingList");
+        }
+        if let Some((abstract_type, language, text)) = expected.other_abstract.as_ref() {
+            self.open(
+                "OtherAbstract",
+                &[("Type", abstract_type), ("Language", language)],
+            );
+            self.text_element("AbstractText", &[], text);
+            self.close("OtherAbstract");
+        }
+        if let Some((owner, keywords)) = expected.keywords.as_ref() {
+            self.open("KeywordList", &[("Owner", owner)]);
+            for (keyword, is_major_topic) in keywords.iter() {
+                self.text_element(
+                    "Keyword",
+                    &[("MajorTopicYN", Self::major_topic(*is_major_topic))],
+                    keyword,
+                );
+            }
+            self.close("KeywordList");
+        }
+        self.close("MedlineCitation");
+
+        self.open("PubmedData", &[]);
+        self.open("History", &[]);
+        self.open("PubMedPubDate", &[("PubStatus", "pubmed")]);
+        self.text_element("Year", &[], "2020");
+        self.text_element("Month", &[], "1");
+        self.text_element("Day", &[], "1");
+        self.close("PubMedPubDate");
+        self.close("History");
+        self.text_element("PublicationStatus", &[], "ppublish");
+        self.open("ArticleIdList", &[]);
+        for (id_type, value) in expected.article_ids.iter() {
+            self.text_element("ArticleId", &[("IdType", id_type)], value);
+        }
+        self.close("ArticleIdList");
+        if !expected.references.is_empty() {
+            self.open("ReferenceList", &[]);
+            for (citation, pubmed_id) in expected.references.iter() {
+                self.open("Reference", &[]);
+                self.text_element("Citation", &[], citation);
+                if let Some(pubmed_id) = pubmed_id {
+                    self.open("ArticleIdList", &[]);
+                    self.text_element("ArticleId", &[("IdType", "pubmed")], &pubmed_id.to_string());
+                    self.close("ArticleIdList");
+                }
+                self.close("Reference");
+            }
+            self.close("ReferenceList");
+        }
+        self.close("PubmedData");
+        self.close("PubmedArticle");
+    }
+
+    fn journal(&mut self, journal: &ExpectedJournal) {
+        self.open("Journal", &[]);
+        if let Some(issn) = journal.issn.as_deref() {
+            self.text_element("ISSN", &[("IssnType", "Print")], issn);
+        }
+        self.open("JournalIssue", &[("CitedMedium", "Print")]);
+        if let Some(volume) = journal.volume.as_deref() {
+            self.text_element("Volume", &[], volume);
+        }
+        if let Some(issue) = journal.issue.as_deref() {
+            self.text_element("Issue", &[], issue);
+        }
+        self.open("PubDate", &[]);
+        self.text_element("Year", &[], &journal.year.to_string());
+        if let Some(month) = journal.month.as_deref() {
+            self.text_element("Month", &[], month);
+        }
+        self.close("PubDate");
+        self.close("JournalIssue");
+        self.text_element("Title", &[], &journal.title);
+        if let Some(iso_abbreviation) = journal.iso_abbreviation.as_deref() {
+            self.text_element("ISOAbbreviation", &[], iso_abbreviation);
+        }
+        self.close("Journal");
+    }
+}
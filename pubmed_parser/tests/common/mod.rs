@@ -0,0 +1,85 @@
+#![allow(dead_code)]
+use flate2::write::GzEncoder;
+use flate2::Compression;
+use std::io::Write;
+pub mod generator;
+
+use std::path::{Path, PathBuf};
+
+/// Writes the provided XML as a gzipped file at the provided path.
+pub fn write_gz(path: &Path, xml: &str) {
+    let file = std::fs::File::create(path).unwrap();
+    let mut encoder = GzEncoder::new(file, Compression::default());
+    encoder.write_all(xml.as_bytes()).unwrap();
+    encoder.finish().unwrap();
+}
+
+/// Writes the provided XML as a gzipped file in the temporary directory.
+pub fn write_gz_fixture(name: &str, xml: &str) -> String {
+    let path = std::env::temp_dir().join(format!("pubmed_parser_{}.xml.gz", name));
+    write_gz(&path, xml);
+    path.display().to_string()
+}
+
+/// Returns an empty directory within the temporary directory.
+pub fn fixture_directory(name: &str) -> PathBuf {
+    let path = std::env::temp_dir().join(format!("pubmed_parser_{}", name));
+    if path.exists() {
+        std::fs::remove_dir_all(&path).unwrap();
+    }
+    std::fs::create_dir_all(&path).unwrap();
+    path
+}
+
+/// Wraps the provided articles in a PubmedArticleSet document.
+pub fn pubmed_article_set(articles: &[&str]) -> String {
+    format!(
+        concat!(
+            "<?xml version=\"1.0\" ?>\n",
+            "<!DOCTYPE PubmedArticleSet PUBLIC \"-//NLM//DTD PubMedArticle, 1st January 2023//EN\" ",
+            "\"https://dtd.nlm.nih.gov/ncbi/pubmed/out/pubmed_230101.dtd\">\n",
+            "<PubmedArticleSet>\n",
+            "{}",
+            "</PubmedArticleSet>\n"
+        ),
+        articles.join("")
+    )
+}
+
+/// Returns a minimal PubmedArticle with the provided PMID, version and title.
+pub fn article(pubmed_id: u32, version: u16, title: &str) -> String {
+    format!(
+        r#"<PubmedArticle>
+    <MedlineCitation Status="MEDLINE" Owner="NLM">
+      <PMID Version="{}">{}</PMID>
+      <Article PubModel="Print">
+        <Journal>
+          <JournalIssue CitedMedium="Print">
+            <PubDate>
+              <Year>2020</Year>
+            </PubDate>
+          </JournalIssue>
+          <Title>Journal</Title>
+        </Journal>
+        <ArticleTitle>{}</ArticleTitle>
+      </Article>
+    </MedlineCitation>
+  </PubmedArticle>
+"#,
+        version, pubmed_id, title
+    )
+}
+
+/// Returns a DeleteCitation block for the provided PMIDs and versions.
+pub fn delete_citation(pubmed_ids: &[(u32, u16)]) -> String {
+    format!(
+        "<DeleteCitation>\n{}</DeleteCitation>\n",
+        pubmed_ids
+            .iter()
+            .map(|(pubmed_id, version)| format!(
+                "<PMID Version=\"{}\">{}</PMID>\n",
+                version, pubmed_id
+            ))
+            .collect::<String>()
+    )
+}
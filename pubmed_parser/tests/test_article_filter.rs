@@ -0,0 +1,161 @@
+extern crate pubmed_parser;
+mod common;
+
+use pubmed_parser::*;
+use std::io::Cursor;
+
+fn fixtures() -> String {
+    format!("{}/tests/fixtures/pubmed", env!("CARGO_MANIFEST_DIR"))
+}
+
+fn articles() -> Vec<Article> {
+    ["pubmed23n0001.xml.gz", "pubmed23n0002.xml.gz"]
+        .iter()
+        .flat_map(|name| parse_single_pubmed(format!("{}/{}", fixtures(), name)).unwrap())
+        .collect()
+}
+
+/// Returns the PMIDs of the fixture articles matched by the filter.
+fn matched(filter: &ArticleFilter) -> Vec<u32> {
+    articles()
+        .iter()
+        .filter(|article| filter.matches(article))
+        .map(|article| article.pubmed_id())
+        .collect()
+}
+
+#[test]
+fn test_publication_types() {
+    let articles = articles();
+    assert_eq!(
+        articles[0]
+            .publication_types()
+            .iter()
+            .map(|publication_type| (publication_type.code(), publication_type.name()))
+            .collect::<Vec<_>>(),
+        [
+            ("D016428", "Journal Article"),
+            ("D013487", "Research Support, U.S. Gov't, P.H.S."),
+        ]
+    );
+    assert_eq!(articles[1].publication_types().len(), 1);
+}
+
+#[test]
+fn test_filters() {
+    assert_eq!(matched(&ArticleFilter::All), [1, 100]);
+
+    assert_eq!(
+        matched(&ArticleFilter::publication_years(Some(2010), Some(2022))),
+        [100]
+    );
+    assert_eq!(
+        matched(&ArticleFilter::publication_years(None, Some(1975))),
+        [1]
+    );
+    assert!(matched(&ArticleFilter::publication_years(Some(1976), Some(2019))).is_empty());
+
+    assert_eq!(
+        matched(&ArticleFilter::journals(&["biochemical medicine"])),
+        [1]
+    );
+    assert_eq!(
+        matched(&ArticleFilter::journals(&["J Synth Fixtures"])),
+        [100]
+    );
+    assert_eq!(matched(&ArticleFilter::journals(&["1234-5678"])), [100]);
+
+    assert_eq!(matched(&ArticleFilter::mesh(&["D005561"])), [1]);
+    assert_eq!(
+        matched(&ArticleFilter::mesh(&["formates", "Humans"])),
+        [1, 100]
+    );
+    // The qualifier makes Formates a major topic, unlike Animals.
+    assert_eq!(
+        matched(&ArticleFilter::mesh_major_topic(&["Formates", "Humans"])),
+        [1, 100]
+    );
+    assert!(matched(&ArticleFilter::mesh_major_topic(&["Animals"])).is_empty());
+    // Qualifiers alone do not match.
+    assert!(matched(&ArticleFilter::mesh(&["Q000097"])).is_empty());
+
+    assert_eq!(
+        matched(&ArticleFilter::publication_types(&["journal article"])),
+        [1, 100]
+    );
+    assert_eq!(
+        matched(&ArticleFilter::publication_types(&["D013487"])),
+        [1]
+    );
+    assert!(matched(&ArticleFilter::publication_types(&["Review"])).is_empty());
+
+    assert_eq!(matched(&ArticleFilter::languages(&["eng"])), [1, 100]);
+    assert_eq!(matched(&ArticleFilter::languages(&["FRE", "spa"])), [100]);
+}
+
+#[test]
+fn test_publication_years_of_a_medline_date() {
+    let xml = common::pubmed_article_set(&[&common::article(5, 1, "Winter issue").replace(
+        "<Year>2020</Year>",
+        "<MedlineDate>1998 Dec-1999 Jan</MedlineDate>",
+    )]);
+    let article = match PubmedReader::new(Cursor::new(xml), "medline date").next() {
+        Some(Ok(PubmedEvent::Article(article))) => article,
+        _ => panic!("Expected an article."),
+    };
+    let date = article.journal().journal_issue().pubblication_date();
+    assert_eq!(date.year(), None);
+    assert_eq!(date.medline_date(), Some("1998 Dec-1999 Jan"));
+
+    assert!(ArticleFilter::publication_years(Some(1998), Some(1998)).matches(&article));
+    assert!(ArticleFilter::publication_years(None, Some(1998)).matches(&article));
+    assert!(!ArticleFilter::publication_years(Some(1999), None).matches(&article));
+}
+
+#[test]
+fn test_combinators() {
+    let english = ArticleFilter::languages(&["eng"]);
+    let recent = ArticleFilter::publication_years(Some(2010), None);
+    let french = ArticleFilter::languages(&["fre"]);
+
+    assert_eq!(matched(&english.clone().and(recent.clone())), [100]);
+    assert_eq!(matched(&recent.clone().negate()), [1]);
+    assert_eq!(
+        matched(&recent.clone().negate().or(french.clone())),
+        [1, 100]
+    );
+    assert!(matched(&french.clone().and(recent.clone().negate())).is_empty());
+
+    assert_eq!(ArticleFilter::All.and(english.clone()), english);
+    assert_eq!(english.clone().negate().negate(), english);
+    assert_eq!(
+        english.clone().and(recent.clone()).and(french.clone()),
+        ArticleFilter::And(vec![english.clone(), recent.clone(), french.clone()])
+    );
+    assert_eq!(
+        english.clone().or(recent.clone().or(french.clone())),
+        ArticleFilter::Or(vec![english, ArticleFilter::Or(vec![recent, french])])
+    );
+}
+
+#[test]
+fn test_filtered_sink_exports_only_the_matching_articles() {
+    let output_directory = std::env::temp_dir().join("pubmed_parser_test_article_filter");
+    let config = GraphExportConfig::new().with_output_directory(&output_directory);
+
+    let sink = FilteredSink::new(
+        ArticleFilter::publication_years(Some(2010), Some(2022))
+            .and(ArticleFilter::languages(&["eng"])),
+        Box::new(TsvGraphSink::new(&config).unwrap()),
+    );
+    parse_pubmed_into(&fixtures(), &TextNormalization::default(), Box::new(sink)).unwrap();
+
+    let nodes = std::fs::read_to_string(config.nodes_path()).unwrap();
+    assert!(nodes.lines().any(|line| line.starts_with("PMID:100\t")));
+    assert!(!nodes.lines().any(|line| line.starts_with("PMID:1\t")));
+    let edges = std::fs::read_to_string(config.edges_path()).unwrap();
+    assert!(edges
+        .lines()
+        .skip(1)
+        .all(|line| line.starts_with("PMID:100\t")));
+}
@@ -0,0 +1,216 @@
+extern crate pubmed_parser;
+mod common;
+
+use pubmed_parser::*;
+use std::sync::{Arc, Mutex};
+
+fn fixtures() -> String {
+    format!("{}/tests/fixtures/pubmed", env!("CARGO_MANIFEST_DIR"))
+}
+
+/// Sink recording the calls it receives.
+struct RecordingSink {
+    name: &'static str,
+    calls: Arc<Mutex<Vec<String>>>,
+}
+
+impl ArticleSink for RecordingSink {
+    fn consume(&mut self, article: &Article) -> Result<(), String> {
+        self.calls
+            .lock()
+            .unwrap()
+            .push(format!("{} consume {}", self.name, article.pubmed_id()));
+        Ok(())
+    }
+
+    fn flush(&mut self) -> Result<(), String> {
+        self.calls
+            .lock()
+            .unwrap()
+            .push(format!("{} flush", self.name));
+        Ok(())
+    }
+
+    fn finish(self: Box<Self>) -> Result<(), String> {
+        self.calls
+            .lock()
+            .unwrap()
+            .push(format!("{} finish", self.name));
+        Ok(())
+    }
+}
+
+struct FailingSink;
+
+impl ArticleSink for FailingSink {
+    fn consume(&mut self, article: &Article) -> Result<(), String> {
+        Err(format!("Unable to consume {}.", article.pubmed_id()))
+    }
+
+    fn flush(&mut self) -> Result<(), String> {
+        Ok(())
+    }
+
+    fn finish(self: Box<Self>) -> Result<(), String> {
+        Ok(())
+    }
+}
+
+#[test]
+fn test_fan_out_forwards_in_order() {
+    let calls = Arc::new(Mutex::new(Vec::new()));
+    let mut sink = FanOutSink::new()
+        .with_sink(Box::new(RecordingSink {
+            name: "first",
+            calls: calls.clone(),
+        }))
+        .with_sink(Box::new(RecordingSink {
+            name: "second",
+            calls: calls.clone(),
+        }));
+
+    sink.flush().unwrap();
+    parse_pubmed_into(&fixtures(), &TextNormalization::default(), Box::new(sink)).unwrap();
+
+    assert_eq!(
+        *calls.lock().unwrap(),
+        [
+            "first flush",
+            "second flush",
+            "first consume 1",
+            "second consume 1",
+            "first consume 100",
+            "second consume 100",
+            "first finish",
+            "second finish",
+        ]
+    );
+}
+
+#[test]
+fn test_graph_and_corpus_in_a_single_pass() {
+    let output_directory = std::env::temp_dir().join("pubmed_parser_test_article_sink");
+    let config = GraphExportConfig::new().with_output_directory(&output_directory);
+    let jsonl_path = output_directory.join("articles.jsonl");
+    let xml_path = output_directory.join("articles.xml");
+
+    let sink = FanOutSink::new()
+        .with_sink(Box::new(TsvGraphSink::new(&config).unwrap()))
+        .with_sink(Box::new(
+            JsonlSink::from_path(jsonl_path.to_str().unwrap()).unwrap(),
+        ))
+        .with_sink(Box::new(
+            PubmedWriter::from_path(xml_path.to_str().unwrap()).unwrap(),
+        ));
+    let rows =
+        parse_pubmed_into(&fixtures(), &TextNormalization::default(), Box::new(sink)).unwrap();
+
+    // The rows are counted by the sinks as they write them.
+    let tsv_rows = |path: std::path::PathBuf| {
+        std::fs::read_to_string(path).unwrap().lines().count() as u64 - 1
+    };
+    assert_eq!(
+        rows.into_iter().collect::<Vec<_>>(),
+        [
+            ("articles.jsonl".to_string(), 2),
+            ("articles.xml".to_string(), 2),
+            ("edges.tsv".to_string(), tsv_rows(config.edges_path())),
+            ("nodes.tsv".to_string(), tsv_rows(config.nodes_path())),
+        ]
+    );
+
+    assert_eq!(
+        std::fs::read_to_string(config.nodes_path()).unwrap(),
+        std::fs::read_to_string(format!("{}/expected_nodes.tsv", fixtures())).unwrap()
+    );
+    assert_eq!(
+        std::fs::read_to_string(config.edges_path()).unwrap(),
+        std::fs::read_to_string(format!("{}/expected_edges.tsv", fixtures())).unwrap()
+    );
+
+    let articles = ["pubmed23n0001.xml.gz", "pubmed23n0002.xml.gz"]
+        .iter()
+        .flat_map(|name| parse_single_pubmed(format!("{}/{}", fixtures(), name)).unwrap())
+        .collect::<Vec<_>>();
+
+    let lines = std::fs::read_to_string(&jsonl_path)
+        .unwrap()
+        .lines()
+        .map(|line| serde_json::from_str::<serde_json::Value>(line).unwrap())
+        .collect::<Vec<_>>();
+    assert_eq!(lines.len(), articles.len());
+    for (line, article) in lines.iter().zip(articles.iter()) {
+        assert_eq!(line["pubmed_id"], article.pubmed_id());
+        assert_eq!(line["title"].as_str(), article.title());
+        assert_eq!(
+            line["mesh_list"].as_array().unwrap().len(),
+            article.mesh_list().len()
+        );
+    }
+
+    let reader = PubmedReader::new(
+        std::io::BufReader::new(std::fs::File::open(&xml_path).unwrap()),
+        "articles.xml",
+    );
+    assert_eq!(
+        reader
+            .map(|event| match event.unwrap() {
+                PubmedEvent::Article(article) => *article,
+                _ => panic!("Expected an article."),
+            })
+            .collect::<Vec<_>>(),
+        articles
+    );
+}
+
+#[test]
+fn test_sink_errors_stop_the_export() {
+    let calls = Arc::new(Mutex::new(Vec::new()));
+    let sink = FanOutSink::new()
+        .with_sink(Box::new(FailingSink))
+        .with_sink(Box::new(RecordingSink {
+            name: "recording",
+            calls: calls.clone(),
+        }));
+
+    assert_eq!(
+        parse_pubmed_into(&fixtures(), &TextNormalization::default(), Box::new(sink)),
+        Err("Unable to consume 1.".to_string())
+    );
+    assert!(calls.lock().unwrap().is_empty());
+}
+
+#[test]
+fn test_files_read_in_parallel_are_consumed_in_order() {
+    let directory = common::fixture_directory("article_sink_parallel");
+    for pubmed_id in 1..=8 {
+        common::write_gz(
+            &directory.join(format!("pubmed23n{:04}.xml.gz", pubmed_id)),
+            &common::pubmed_article_set(&[&common::article(pubmed_id, 1, "Parallel")]),
+        );
+    }
+
+    let calls = Arc::new(Mutex::new(Vec::new()));
+    let sink = RecordingSink {
+        name: "recording",
+        calls: calls.clone(),
+    };
+    let pool = rayon::ThreadPoolBuilder::new()
+        .num_threads(3)
+        .build()
+        .unwrap();
+    pool.install(|| {
+        parse_pubmed_into(
+            directory.to_str().unwrap(),
+            &TextNormalization::default(),
+            Box::new(sink),
+        )
+    })
+    .unwrap();
+
+    let mut expected = (1..=8)
+        .map(|pubmed_id| format!("recording consume {}", pubmed_id))
+        .collect::<Vec<_>>();
+    expected.push("recording finish".to_string());
+    assert_eq!(*calls.lock().unwrap(), expected);
+}
@@ -0,0 +1,203 @@
+extern crate pubmed_parser;
+mod common;
+
+use pubmed_parser::*;
+use std::io::Read;
+use std::path::Path;
+
+fn fixtures() -> String {
+    format!("{}/tests/fixtures/pubmed", env!("CARGO_MANIFEST_DIR"))
+}
+
+fn file_names(source: &dyn ArticleSource) -> Vec<String> {
+    source
+        .files()
+        .iter()
+        .map(|file| {
+            Path::new(file)
+                .file_name()
+                .unwrap()
+                .to_string_lossy()
+                .to_string()
+        })
+        .collect()
+}
+
+fn titles(source: &dyn ArticleSource) -> Vec<(u32, String)> {
+    source
+        .files()
+        .iter()
+        .flat_map(|file| source.open(file).unwrap())
+        .filter_map(|event| match event.unwrap() {
+            PubmedEvent::Article(article) => Some((
+                article.pubmed_id(),
+                article.title().unwrap_or_default().to_string(),
+            )),
+            _ => None,
+        })
+        .collect()
+}
+
+/// Writes a tar archive holding the provided files, in the provided order.
+fn write_tar(path: &Path, files: &[(&str, Vec<u8>)]) {
+    let mut builder = tar::Builder::new(std::fs::File::create(path).unwrap());
+    for (name, content) in files {
+        let mut header = tar::Header::new_gnu();
+        header.set_size(content.len() as u64);
+        header.set_mode(0o644);
+        header.set_cksum();
+        builder
+            .append_data(&mut header, name, content.as_slice())
+            .unwrap();
+    }
+    builder.finish().unwrap();
+}
+
+fn gunzip(path: &str) -> Vec<u8> {
+    let mut xml = Vec::new();
+    flate2::read::GzDecoder::new(std::fs::File::open(path).unwrap())
+        .read_to_end(&mut xml)
+        .unwrap();
+    xml
+}
+
+#[test]
+fn test_file_source_from_directory_and_glob() {
+    let expected = ["pubmed23n0001.xml.gz", "pubmed23n0002.xml.gz"];
+
+    let source = FileSource::from_directory(&fixtures()).unwrap();
+    assert_eq!(file_names(&source), expected);
+    assert_eq!(
+        titles(&source)
+            .iter()
+            .map(|(pubmed_id, _)| *pubmed_id)
+            .collect::<Vec<_>>(),
+        [1, 100]
+    );
+
+    let source = FileSource::from_glob(&format!("{}/pubmed23n*", fixtures())).unwrap();
+    assert_eq!(file_names(&source), expected);
+
+    assert!(FileSource::from_directory("missing_directory").is_err());
+    assert!(FileSource::from_glob("[").is_err());
+}
+
+#[test]
+fn test_file_source_from_files_is_sorted() {
+    let source = FileSource::from_files(vec![
+        format!("{}/pubmed23n0002.xml.gz", fixtures()),
+        format!("{}/pubmed23n0001.xml.gz", fixtures()),
+    ]);
+    assert_eq!(
+        file_names(&source),
+        ["pubmed23n0001.xml.gz", "pubmed23n0002.xml.gz"]
+    );
+    assert!(source.open("missing_file.xml.gz").is_err());
+}
+
+#[test]
+fn test_tar_source() {
+    let directory = common::fixture_directory("tar_source");
+    let archive = directory.join("baseline.tar");
+    write_tar(
+        &archive,
+        &[
+            (
+                "baseline/pubmed23n0002.xml",
+                gunzip(&format!("{}/pubmed23n0002.xml.gz", fixtures())),
+            ),
+            ("baseline/README.txt", b"Not a PubMed file.".to_vec()),
+            (
+                "baseline/pubmed23n0001.xml.gz",
+                std::fs::read(format!("{}/pubmed23n0001.xml.gz", fixtures())).unwrap(),
+            ),
+        ],
+    );
+
+    let source = TarSource::from_path(archive.to_str().unwrap()).unwrap();
+    assert_eq!(
+        source.files(),
+        [
+            "baseline/pubmed23n0001.xml.gz",
+            "baseline/pubmed23n0002.xml"
+        ]
+    );
+    assert_eq!(
+        titles(&source),
+        titles(&FileSource::from_directory(&fixtures()).unwrap())
+    );
+    assert!(source.open("baseline/README.txt").is_err());
+
+    let config = GraphExportConfig::new().with_output_directory(&directory);
+    parse_source_into(
+        &source,
+        &TextNormalization::default(),
+        Box::new(TsvGraphSink::new(&config).unwrap()),
+    )
+    .unwrap();
+    assert_eq!(
+        std::fs::read_to_string(config.nodes_path()).unwrap(),
+        std::fs::read_to_string(format!("{}/expected_nodes.tsv", fixtures())).unwrap()
+    );
+
+    assert!(TarSource::from_path("missing_archive.tar").is_err());
+}
+
+#[test]
+fn test_combined_source_merges_updates() {
+    let directory = common::fixture_directory("combined_source");
+    let archive = directory.join("updates.tar");
+    write_tar(
+        &archive,
+        &[(
+            "pubmed23n0003.xml",
+            common::pubmed_article_set(&[
+                &common::delete_citation(&[(1, 1)]),
+                &common::article(101, 1, "Added by an update"),
+            ])
+            .into_bytes(),
+        )],
+    );
+
+    let source = CombinedSource::new()
+        .with_source(open_source(archive.to_str().unwrap()).unwrap())
+        .with_source(open_source(&fixtures()).unwrap());
+    assert_eq!(
+        file_names(&source),
+        [
+            "pubmed23n0001.xml.gz",
+            "pubmed23n0002.xml.gz",
+            "pubmed23n0003.xml"
+        ]
+    );
+
+    let articles = merge_source(source, &TextNormalization::default())
+        .unwrap()
+        .collect::<Result<Vec<Article>, String>>()
+        .unwrap();
+    assert_eq!(
+        articles
+            .iter()
+            .map(|article| article.pubmed_id())
+            .collect::<Vec<_>>(),
+        [100, 101]
+    );
+}
+
+#[test]
+fn test_open_source() {
+    let file = format!("{}/pubmed23n0002.xml.gz", fixtures());
+    assert_eq!(open_source(&fixtures()).unwrap().files().len(), 2);
+    assert_eq!(
+        open_source(&format!("{}/*.xml.gz", fixtures()))
+            .unwrap()
+            .files()
+            .len(),
+        2
+    );
+    assert_eq!(
+        open_source(&file).unwrap().files(),
+        std::slice::from_ref(&file)
+    );
+    assert!(open_source("missing_input").is_err());
+}
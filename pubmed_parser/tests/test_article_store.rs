@@ -0,0 +1,153 @@
+extern crate pubmed_parser;
+mod common;
+
+use pubmed_parser::*;
+use rayon::prelude::*;
+
+fn fixtures() -> String {
+    format!("{}/tests/fixtures/pubmed", env!("CARGO_MANIFEST_DIR"))
+}
+
+fn parse_fixtures() -> Vec<Article> {
+    ["pubmed23n0001.xml.gz", "pubmed23n0002.xml.gz"]
+        .iter()
+        .flat_map(|name| parse_single_pubmed(format!("{}/{}", fixtures(), name)).unwrap())
+        .collect()
+}
+
+/// Writes the articles of the fixtures into a store, returning its path.
+fn write_fixture_store(name: &str) -> String {
+    let path = common::fixture_directory(name).join("articles.bin");
+    let path = path.to_str().unwrap().to_string();
+    parse_pubmed_into(
+        &fixtures(),
+        &TextNormalization::default(),
+        Box::new(ArticleStoreWriter::from_path(&path).unwrap()),
+    )
+    .unwrap();
+    path
+}
+
+#[test]
+fn test_store_round_trip() {
+    let store = ArticleStore::from_path(&write_fixture_store("article_store")).unwrap();
+    let expected = parse_fixtures();
+
+    assert_eq!(store.len(), 2);
+    assert_eq!(store.pubmed_ids().collect::<Vec<_>>(), [1, 100]);
+    assert_eq!(
+        store
+            .articles()
+            .collect::<Result<Vec<Article>, String>>()
+            .unwrap(),
+        expected
+    );
+    for article in expected.iter() {
+        assert_eq!(
+            store.get(article.pubmed_id()).unwrap().as_ref(),
+            Some(article)
+        );
+    }
+    assert_eq!(store.get(2).unwrap(), None);
+
+    let mut articles = store
+        .par_articles()
+        .collect::<Result<Vec<Article>, String>>()
+        .unwrap();
+    articles.sort_by_key(|article| article.pubmed_id());
+    assert_eq!(articles, expected);
+}
+
+#[test]
+fn test_store_keeps_the_last_article_of_a_pubmed_id() {
+    let path = common::fixture_directory("article_store_duplicates").join("articles.bin");
+    let path = path.to_str().unwrap();
+    let mut expected = parse_fixtures();
+    let mut writer = ArticleStoreWriter::from_path(path).unwrap();
+    for article in expected.iter().chain(expected[..1].iter()) {
+        writer.write_article(article).unwrap();
+    }
+    writer.finish().unwrap();
+
+    let store = ArticleStore::from_path(path).unwrap();
+    assert_eq!(store.len(), 2);
+    assert_eq!(store.articles().count(), 3);
+    assert_eq!(store.get(1).unwrap(), Some(expected.remove(0)));
+
+    let empty = ArticleStoreWriter::new(Vec::new())
+        .unwrap()
+        .finish()
+        .unwrap();
+    let path = common::fixture_directory("article_store_empty").join("articles.bin");
+    std::fs::write(&path, empty).unwrap();
+    let store = ArticleStore::from_path(path.to_str().unwrap()).unwrap();
+    assert!(store.is_empty());
+    assert_eq!(store.articles().count(), 0);
+}
+
+#[test]
+fn test_store_exports_the_same_graph() {
+    let store = ArticleStore::from_path(&write_fixture_store("article_store_export")).unwrap();
+    let directory = common::fixture_directory("article_store_graph");
+    let config = GraphExportConfig::new().with_output_directory(&directory);
+    export_articles(
+        store.articles(),
+        Box::new(TsvGraphSink::new(&config).unwrap()),
+    )
+    .unwrap();
+
+    for (path, expected) in [
+        (config.nodes_path(), "expected_nodes.tsv"),
+        (config.edges_path(), "expected_edges.tsv"),
+    ] {
+        assert_eq!(
+            std::fs::read_to_string(path).unwrap(),
+            std::fs::read_to_string(format!("{}/{}", fixtures(), expected)).unwrap()
+        );
+    }
+}
+
+#[test]
+fn test_invalid_store() {
+    let path = write_fixture_store("article_store_invalid");
+    let bytes = std::fs::read(&path).unwrap();
+    let directory = common::fixture_directory("article_store_invalid_files");
+
+    let truncated = directory.join("truncated.bin");
+    std::fs::write(&truncated, &bytes[..bytes.len() - 1]).unwrap();
+    assert!(ArticleStore::from_path(truncated.to_str().unwrap()).is_err());
+
+    let not_a_store = directory.join("not_a_store.bin");
+    std::fs::write(&not_a_store, b"<PubmedArticleSet/>").unwrap();
+    assert!(ArticleStore::from_path(not_a_store.to_str().unwrap()).is_err());
+
+    // A corrupted record is reported, and ends the iteration.
+    let mut corrupted = bytes.clone();
+    corrupted[8..12].copy_from_slice(&u32::MAX.to_le_bytes());
+    let corrupted_path = directory.join("corrupted.bin");
+    std::fs::write(&corrupted_path, corrupted).unwrap();
+    let store = ArticleStore::from_path(corrupted_path.to_str().unwrap()).unwrap();
+    assert!(store.get(1).is_err());
+    let articles = store.articles().collect::<Vec<_>>();
+    assert_eq!(articles.len(), 1);
+    assert!(articles[0].is_err());
+
+    // An offset of the index out of the records is reported rather than read.
+    let footer = bytes.len() - 24;
+    let index_position = u64::from_le_bytes(bytes[footer..footer + 8].try_into().unwrap()) as usize;
+    for offset in [0, u64::MAX - 1, bytes.len() as u64 - 2] {
+        let mut corrupted = bytes.clone();
+        corrupted[index_position + 4..index_position + 12].copy_from_slice(&offset.to_le_bytes());
+        std::fs::write(&corrupted_path, corrupted).unwrap();
+        let store = ArticleStore::from_path(corrupted_path.to_str().unwrap()).unwrap();
+        let pubmed_id = store.pubmed_ids().next().unwrap();
+        assert!(store.get(pubmed_id).is_err());
+        assert!(store
+            .par_articles()
+            .collect::<Vec<_>>()
+            .iter()
+            .any(Result::is_err));
+    }
+
+    assert!(ArticleStore::from_path("missing_store.bin").is_err());
+}
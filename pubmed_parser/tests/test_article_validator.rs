@@ -0,0 +1,147 @@
+extern crate pubmed_parser;
+mod common;
+
+use pubmed_parser::*;
+
+fn fixtures() -> String {
+    format!("{}/tests/fixtures/pubmed", env!("CARGO_MANIFEST_DIR"))
+}
+
+/// Returns an article breaking most of the invariants checked by the validator.
+fn invalid_article(pubmed_id: u32) -> String {
+    format!(
+        r#"<PubmedArticle>
+    <MedlineCitation Status="MEDLINE" Owner="NLM">
+      <PMID Version="1">{}</PMID>
+      <DateRevised>
+        <Year>2021</Year>
+        <Month>13</Month>
+        <Day>12</Day>
+      </DateRevised>
+      <Article PubModel="Print">
+        <Journal>
+          <JournalIssue CitedMedium="Print">
+            <PubDate>
+              <Year>1066</Year>
+            </PubDate>
+          </JournalIssue>
+          <Title>Journal</Title>
+        </Journal>
+        <ArticleTitle>Invalid article</ArticleTitle>
+      </Article>
+      <SupplMeshList>
+        <SupplMeshName Type="Disease" UI="D000001">Not a supplementary concept</SupplMeshName>
+      </SupplMeshList>
+      <MeshHeadingList>
+        <MeshHeading>
+          <DescriptorName UI="Q000097" MajorTopicYN="N">Not a descriptor</DescriptorName>
+          <QualifierName UI="Q000097x" MajorTopicYN="N">metabolism</QualifierName>
+        </MeshHeading>
+        <MeshHeading>
+          <DescriptorName UI="" MajorTopicYN="N">Without UI</DescriptorName>
+        </MeshHeading>
+      </MeshHeadingList>
+    </MedlineCitation>
+    <PubmedData>
+      <ArticleIdList>
+        <ArticleId IdType="pubmed">{}</ArticleId>
+      </ArticleIdList>
+      <ReferenceList>
+        <Reference>
+          <Citation>A reference with a malformed PMID.</Citation>
+          <ArticleIdList>
+            <ArticleId IdType="pubmed">12a</ArticleId>
+          </ArticleIdList>
+        </Reference>
+      </ReferenceList>
+    </PubmedData>
+  </PubmedArticle>
+"#,
+        pubmed_id, pubmed_id
+    )
+}
+
+fn validate(validator: &mut ArticleValidator, name: &str, xml: &str) -> Vec<Violation> {
+    let mut reader = PubmedReader::new(xml.as_bytes(), name);
+    let mut violations = Vec::new();
+    while let Some(event) = reader.next() {
+        if let PubmedEvent::Article(article) = event.unwrap() {
+            violations.extend(validator.validate(name, reader.line_number(), &article));
+        }
+    }
+    violations
+}
+
+#[test]
+fn test_fixtures_are_valid() {
+    let mut validator = ArticleValidator::new();
+    for name in ["pubmed23n0001.xml.gz", "pubmed23n0002.xml.gz"] {
+        let path = format!("{}/{}", fixtures(), name);
+        let mut reader = PubmedReader::from_path(&path).unwrap();
+        while let Some(event) = reader.next() {
+            if let PubmedEvent::Article(article) = event.unwrap() {
+                assert_eq!(
+                    validator.validate(&path, reader.line_number(), &article),
+                    []
+                );
+            }
+        }
+    }
+}
+
+#[test]
+fn test_violations_are_located() {
+    let mut validator = ArticleValidator::new().with_years(1700, 2100);
+    let violations = validate(
+        &mut validator,
+        "pubmed23n0001.xml",
+        &common::pubmed_article_set(&[&common::article(1, 1, "Valid"), &invalid_article(2)]),
+    );
+    let messages = violations
+        .iter()
+        .map(|violation| violation.message())
+        .collect::<Vec<_>>();
+    assert_eq!(
+        messages,
+        [
+            "The MeSH descriptor UI \"Q000097\" is not valid.",
+            "The MeSH qualifier UI \"Q000097x\" is not valid.",
+            "The MeSH descriptor UI \"\" is not valid.",
+            "The supplementary MeSH UI \"D000001\" is not valid.",
+            "The publication date has the year 1066, outside of 1700 to 2100.",
+            "The revision date has the month \"13\".",
+            "The referenced PMID \"12a\" is not valid.",
+            "A Mesh node has an empty name.",
+            "A PaperToMesh edge has an empty end.",
+        ]
+    );
+    assert!(violations
+        .iter()
+        .all(|violation| violation.pubmed_id() == 2 && violation.file() == "pubmed23n0001.xml"));
+    assert_eq!(violations[0].line(), 65);
+    assert_eq!(
+        violations[0].to_string(),
+        "pubmed23n0001.xml:65 PMID 2: The MeSH descriptor UI \"Q000097\" is not valid."
+    );
+}
+
+#[test]
+fn test_duplicate_pubmed_ids_across_files() {
+    let mut validator = ArticleValidator::new().with_years(1000, 2100);
+    let xml = common::pubmed_article_set(&[&common::article(1, 1, "First")]);
+    assert_eq!(validate(&mut validator, "pubmed23n0001.xml", &xml), []);
+
+    let violations = validate(
+        &mut validator,
+        "pubmed23n0002.xml",
+        &common::pubmed_article_set(&[
+            &common::article(2, 1, "Second"),
+            &common::article(1, 2, "Again"),
+        ]),
+    );
+    assert_eq!(violations.len(), 1);
+    assert_eq!(
+        violations[0].to_string(),
+        "pubmed23n0002.xml:35 PMID 1: The PMID was already read at pubmed23n0001.xml:19."
+    );
+}
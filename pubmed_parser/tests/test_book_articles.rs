@@ -0,0 +1,238 @@
+extern crate pubmed_parser;
+mod common;
+
+use pubmed_parser::*;
+use std::io::BufReader;
+
+const BOOK_ARTICLE: &str = r#"<PubmedBookArticle>
+    <BookDocument>
+      <PMID Version="1">20301608</PMID>
+      <ArticleIdList>
+        <ArticleId IdType="bookaccession">NBK1435</ArticleId>
+      </ArticleIdList>
+      <Book>
+        <Publisher>
+          <PublisherName>University of Washington, Seattle</PublisherName>
+          <PublisherLocation>Seattle (WA)</PublisherLocation>
+        </Publisher>
+        <BookTitle book="gene">GeneReviews<sup>&#xae;</sup></BookTitle>
+        <PubDate>
+          <Year>1993</Year>
+        </PubDate>
+        <BeginningDate>
+          <Year>1993</Year>
+        </BeginningDate>
+        <AuthorList Type="editors">
+          <Author>
+            <LastName>Adam</LastName>
+            <ForeName>Margaret P</ForeName>
+            <Initials>MP</Initials>
+          </Author>
+        </AuthorList>
+        <Medium>Internet</Medium>
+      </Book>
+      <LocationLabel Type="chapter">Alpha-Thalassemia</LocationLabel>
+      <ArticleTitle book="gene" part="a-thal">Alpha-Thalassemia</ArticleTitle>
+      <Language>eng</Language>
+      <AuthorList Type="authors">
+        <Author>
+          <LastName>Tamary</LastName>
+          <ForeName>Hannah</ForeName>
+        </Author>
+      </AuthorList>
+      <PublicationType UI="D016454">Review</PublicationType>
+      <Abstract>
+        <AbstractText Label="CLINICAL CHARACTERISTICS">Alpha-thalassemia has two clinical forms.</AbstractText>
+        <AbstractText Label="DIAGNOSIS/TESTING">The diagnosis is established by molecular testing.</AbstractText>
+        <CopyrightInformation>Copyright &#xa9; 1993-2023, University of Washington, Seattle.</CopyrightInformation>
+      </Abstract>
+      <Sections>
+        <Section>
+          <SectionTitle book="gene" part="a-thal" sec="a-thal.Summary">Summary</SectionTitle>
+        </Section>
+        <Section>
+          <LocationLabel Type="section">1</LocationLabel>
+          <SectionTitle book="gene" part="a-thal" sec="a-thal.Diagnosis">Diagnosis</SectionTitle>
+          <Section>
+            <SectionTitle book="gene" part="a-thal" sec="a-thal.Testing">Testing</SectionTitle>
+          </Section>
+        </Section>
+      </Sections>
+      <ContributionDate>
+        <Year>2005</Year>
+        <Month>11</Month>
+        <Day>1</Day>
+      </ContributionDate>
+      <DateRevised>
+        <Year>2020</Year>
+        <Month>10</Month>
+        <Day>1</Day>
+      </DateRevised>
+    </BookDocument>
+    <PubmedBookData>
+      <History>
+        <PubMedPubDate PubStatus="pubmed">
+          <Year>2010</Year>
+          <Month>3</Month>
+          <Day>20</Day>
+        </PubMedPubDate>
+      </History>
+      <PublicationStatus>ppublish</PublicationStatus>
+      <ArticleIdList>
+        <ArticleId IdType="pubmed">20301608</ArticleId>
+      </ArticleIdList>
+    </PubmedBookData>
+  </PubmedBookArticle>
+"#;
+
+#[test]
+fn test_book_article() {
+    let xml = common::pubmed_article_set(&[
+        &common::article(1, 1, "Before"),
+        BOOK_ARTICLE,
+        &common::article(2, 1, "After"),
+    ]);
+    let events = PubmedReader::new(BufReader::new(xml.as_bytes()), "books")
+        .collect::<Result<Vec<PubmedEvent>, String>>()
+        .unwrap();
+    assert_eq!(events.len(), 3);
+    assert!(matches!(&events[0], PubmedEvent::Article(article) if article.pubmed_id() == 1));
+    assert!(matches!(&events[2], PubmedEvent::Article(article) if article.pubmed_id() == 2));
+
+    let book_article = match &events[1] {
+        PubmedEvent::BookArticle(book_article) => book_article,
+        _ => panic!("Expected a book article."),
+    };
+    assert_eq!(book_article.pubmed_id(), 20301608);
+    assert_eq!(book_article.pmid_version(), 1);
+    assert_eq!(
+        book_article
+            .article_ids()
+            .iter()
+            .map(|article_id| (article_id.id_type(), article_id.value()))
+            .collect::<Vec<_>>(),
+        [("bookaccession", "NBK1435"), ("pubmed", "20301608")]
+    );
+    let book = book_article.book();
+    assert_eq!(
+        book.publisher_name(),
+        Some("University of Washington, Seattle")
+    );
+    assert_eq!(book.publisher_location(), Some("Seattle (WA)"));
+    assert_eq!(book.title(), Some("GeneReviews<sup>®</sup>"));
+    assert_eq!(book.pubblication_date().unwrap().year(), Some(1993));
+    assert_eq!(book_article.location_label(), Some("Alpha-Thalassemia"));
+    assert_eq!(book_article.title(), Some("Alpha-Thalassemia"));
+    assert_eq!(book_article.languages(), ["eng"]);
+    assert_eq!(
+        book_article.abstract_text().unwrap().text(),
+        "Alpha-thalassemia has two clinical forms. The diagnosis is established by molecular testing."
+    );
+    assert_eq!(book_article.sections(), ["Summary", "Diagnosis", "Testing"]);
+    assert_eq!(book_article.contribution_date().unwrap().year(), Some(2005));
+    assert_eq!(book_article.revision_date().unwrap().year(), Some(2020));
+    assert_eq!(book_article.vernacular_title(), None);
+    assert!(book_article.pagination().is_none());
+    assert_eq!(book_article.citation_string(), None);
+}
+
+/// A book chapter holding the optional elements of the `BookDocument` and the `PubmedBookData`.
+const BOOK_CHAPTER: &str = r#"<PubmedBookArticle>
+    <BookDocument>
+      <PMID Version="1">28520355</PMID>
+      <ArticleIdList>
+        <ArticleId IdType="bookaccession">NBK430685</ArticleId>
+      </ArticleIdList>
+      <Book>
+        <Publisher>
+          <PublisherName>StatPearls Publishing</PublisherName>
+          <PublisherLocation>Treasure Island (FL)</PublisherLocation>
+        </Publisher>
+        <BookTitle book="statpearls">StatPearls</BookTitle>
+        <PubDate>
+          <Year>2023</Year>
+          <Month>01</Month>
+        </PubDate>
+      </Book>
+      <ArticleTitle book="statpearls" part="article-1">Anatomy, Head and Neck</ArticleTitle>
+      <VernacularTitle>Anatomie de la tête et du cou</VernacularTitle>
+      <Pagination>
+        <StartPage>12</StartPage>
+        <EndPage>18</EndPage>
+        <MedlinePgn>12-8</MedlinePgn>
+      </Pagination>
+      <Language>eng</Language>
+      <ContributionDate>
+        <Year>2023</Year>
+        <Month>05</Month>
+        <Day>1</Day>
+      </ContributionDate>
+      <CitationString>Treasure Island (FL): StatPearls Publishing; 2023 Jan.</CitationString>
+    </BookDocument>
+    <PubmedBookData>
+      <History>
+        <PubMedPubDate PubStatus="pubmed">
+          <Year>2017</Year>
+          <Month>5</Month>
+          <Day>19</Day>
+        </PubMedPubDate>
+      </History>
+      <PublicationStatus>ppublish</PublicationStatus>
+      <ArticleIdList>
+        <ArticleId IdType="pubmed">28520355</ArticleId>
+      </ArticleIdList>
+      <ObjectList>
+        <Object Type="keyword">
+          <Param Name="value">anatomy</Param>
+        </Object>
+      </ObjectList>
+    </PubmedBookData>
+  </PubmedBookArticle>
+"#;
+
+#[test]
+fn test_book_chapter_with_optional_elements() {
+    let xml = common::pubmed_article_set(&[BOOK_CHAPTER, &common::article(4, 1, "After")]);
+    let events = PubmedReader::new(BufReader::new(xml.as_bytes()), "books")
+        .collect::<Result<Vec<PubmedEvent>, String>>()
+        .unwrap();
+    assert_eq!(events.len(), 2);
+    assert!(matches!(&events[1], PubmedEvent::Article(article) if article.pubmed_id() == 4));
+
+    let book_article = match &events[0] {
+        PubmedEvent::BookArticle(book_article) => book_article,
+        _ => panic!("Expected a book article."),
+    };
+    assert_eq!(book_article.title(), Some("Anatomy, Head and Neck"));
+    assert_eq!(
+        book_article.vernacular_title(),
+        Some("Anatomie de la tête et du cou")
+    );
+    let pagination = book_article.pagination().unwrap();
+    assert_eq!(pagination.start_page(), Some("12"));
+    assert_eq!(pagination.end_page().as_deref(), Some("18"));
+    assert_eq!(pagination.medline_pgn(), Some("12-8"));
+    assert_eq!(
+        book_article.citation_string(),
+        Some("Treasure Island (FL): StatPearls Publishing; 2023 Jan.")
+    );
+    assert_eq!(
+        book_article
+            .article_ids()
+            .iter()
+            .map(|article_id| (article_id.id_type(), article_id.value()))
+            .collect::<Vec<_>>(),
+        [("bookaccession", "NBK430685"), ("pubmed", "28520355")]
+    );
+}
+
+#[test]
+fn test_parse_single_pubmed_skips_book_articles() {
+    let path = common::write_gz_fixture(
+        "book_articles",
+        &common::pubmed_article_set(&[BOOK_ARTICLE, &common::article(3, 1, "Article")]),
+    );
+    let articles = parse_single_pubmed(path).unwrap();
+    assert_eq!(articles.len(), 1);
+    assert_eq!(articles[0].pubmed_id(), 3);
+}
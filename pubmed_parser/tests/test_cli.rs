@@ -0,0 +1,394 @@
+use pubmed_parser::{ArticleStore, RunManifest};
+use std::process::{Command, Output};
+
+fn fixtures(directory: &str) -> String {
+    format!(
+        "{}/tests/fixtures/{}",
+        env!("CARGO_MANIFEST_DIR"),
+        directory
+    )
+}
+
+fn pubmed_parser(arguments: &[&str]) -> Output {
+    Command::new(env!("CARGO_BIN_EXE_pubmed-parser"))
+        .args(arguments)
+        .output()
+        .unwrap()
+}
+
+#[test]
+fn test_parse_writes_the_graph_to_the_output_directory() {
+    let output_directory = std::env::temp_dir().join("pubmed_parser_test_cli_parse");
+    let _ = std::fs::remove_dir_all(&output_directory);
+
+    let output = pubmed_parser(&[
+        "parse",
+        &fixtures("pubmed"),
+        "--output",
+        output_directory.to_str().unwrap(),
+        "--threads",
+        "2",
+    ]);
+    assert!(output.status.success());
+
+    for name in ["nodes.tsv", "edges.tsv"] {
+        assert_eq!(
+            std::fs::read_to_string(output_directory.join(name)).unwrap(),
+            std::fs::read_to_string(format!("{}/expected_{}", fixtures("pubmed"), name)).unwrap()
+        );
+    }
+}
+
+#[test]
+fn test_parse_filters_and_prefixes_the_graph() {
+    let output_directory = std::env::temp_dir().join("pubmed_parser_test_cli_parse_filters");
+
+    let output = pubmed_parser(&[
+        "parse",
+        &fixtures("pubmed"),
+        "--output",
+        output_directory.to_str().unwrap(),
+        "--edges-file",
+        "citations.tsv",
+        "--edge-types",
+        "Citation",
+        "--node-types",
+        "Paper",
+        "--prefix",
+        "Paper=pubmed:",
+    ]);
+    assert!(output.status.success());
+
+    assert_eq!(
+        std::fs::read_to_string(output_directory.join("citations.tsv")).unwrap(),
+        "subject\tedge_type\tobject\npubmed:PMID:100\tCitation\tpubmed:PMID:1\n"
+    );
+    assert_eq!(
+        std::fs::read_to_string(output_directory.join("nodes.tsv"))
+            .unwrap()
+            .lines()
+            .count(),
+        3
+    );
+
+    let output = pubmed_parser(&["parse", &fixtures("pubmed"), "--prefix", "Paper"]);
+    assert_eq!(output.status.code(), Some(2));
+}
+
+#[test]
+fn test_parse_keeps_only_the_matching_articles() {
+    let output_directory = std::env::temp_dir().join("pubmed_parser_test_cli_parse_articles");
+
+    let output = pubmed_parser(&[
+        "parse",
+        &fixtures("pubmed"),
+        "--output",
+        output_directory.to_str().unwrap(),
+        "--node-types",
+        "Paper",
+        "--years",
+        "2010:",
+        "--mesh",
+        "humans",
+        "--mesh-major-topic",
+        "--publication-type",
+        "Journal Article",
+        "--language",
+        "spa,fre",
+    ]);
+    assert!(output.status.success());
+    assert_eq!(
+        std::fs::read_to_string(output_directory.join("nodes.tsv"))
+            .unwrap()
+            .lines()
+            .map(|line| line.split('\t').next().unwrap())
+            .collect::<Vec<_>>(),
+        ["node_name", "PMID:100"]
+    );
+
+    let output = pubmed_parser(&[
+        "parse",
+        &fixtures("pubmed"),
+        "--output",
+        output_directory.to_str().unwrap(),
+        "--journal",
+        "Biochem Med",
+        "--years",
+        "1975",
+    ]);
+    assert!(output.status.success());
+    assert_eq!(
+        std::fs::read_to_string(output_directory.join("edges.tsv"))
+            .unwrap()
+            .lines()
+            .skip(1)
+            .map(|line| line.split('\t').next().unwrap())
+            .collect::<std::collections::BTreeSet<_>>(),
+        std::collections::BTreeSet::from(["PMID:1"])
+    );
+
+    let output = pubmed_parser(&[
+        "parse",
+        &fixtures("pubmed"),
+        "--output",
+        output_directory.to_str().unwrap(),
+        "--node-types",
+        "Paper",
+        "--query",
+        "\"Formates\"[majr] OR (humans[mh] AND 2021[dp])",
+    ]);
+    assert!(output.status.success());
+    assert_eq!(
+        std::fs::read_to_string(output_directory.join("nodes.tsv"))
+            .unwrap()
+            .lines()
+            .map(|line| line.split('\t').next().unwrap())
+            .collect::<Vec<_>>(),
+        ["node_name", "PMID:1"]
+    );
+
+    let output = pubmed_parser(&["parse", &fixtures("pubmed"), "--years", "twenty:"]);
+    assert_eq!(output.status.code(), Some(2));
+    let output = pubmed_parser(&["parse", &fixtures("pubmed"), "--query", "cancer[au]"]);
+    assert_eq!(output.status.code(), Some(2));
+}
+
+#[test]
+fn test_parse_writes_several_formats_in_a_single_pass() {
+    let output_directory = std::env::temp_dir().join("pubmed_parser_test_cli_parse_formats");
+    let _ = std::fs::remove_dir_all(&output_directory);
+
+    let output = pubmed_parser(&[
+        "parse",
+        &fixtures("pubmed"),
+        "--output",
+        output_directory.to_str().unwrap(),
+        "--format",
+        "tsv,jsonl,xml,store,stats",
+    ]);
+    assert!(output.status.success());
+
+    assert_eq!(
+        std::fs::read_to_string(output_directory.join("edges.tsv")).unwrap(),
+        std::fs::read_to_string(format!("{}/expected_edges.tsv", fixtures("pubmed"))).unwrap()
+    );
+    assert_eq!(
+        std::fs::read_to_string(output_directory.join("articles.jsonl"))
+            .unwrap()
+            .lines()
+            .count(),
+        2
+    );
+    assert!(
+        std::fs::read_to_string(output_directory.join("articles.xml"))
+            .unwrap()
+            .ends_with("</PubmedArticleSet>\n")
+    );
+    assert_eq!(
+        ArticleStore::from_path(output_directory.join("articles.bin").to_str().unwrap())
+            .unwrap()
+            .pubmed_ids()
+            .collect::<Vec<_>>(),
+        [1, 100]
+    );
+    assert!(
+        std::fs::read_to_string(output_directory.join("statistics.txt"))
+            .unwrap()
+            .starts_with("articles\t2\n")
+    );
+    assert!(output_directory.join("statistics.json").exists());
+    let manifest =
+        RunManifest::from_path(output_directory.join("manifest.json").to_str().unwrap()).unwrap();
+    assert_eq!(manifest.arguments()[1..3], ["parse", &fixtures("pubmed")]);
+    assert_eq!(manifest.inputs().len(), 2);
+    let rows = |name: &str| {
+        std::fs::read_to_string(output_directory.join(name))
+            .unwrap()
+            .lines()
+            .count() as u64
+            - 1
+    };
+    assert_eq!(
+        manifest.rows().iter().collect::<Vec<_>>(),
+        [
+            (&"articles.bin".to_string(), &2),
+            (&"articles.jsonl".to_string(), &2),
+            (&"articles.xml".to_string(), &2),
+            (&"edges.tsv".to_string(), &rows("edges.tsv")),
+            (&"nodes.tsv".to_string(), &rows("nodes.tsv")),
+        ]
+    );
+
+    // The graph left by the previous run is not counted again.
+    let output = pubmed_parser(&[
+        "parse",
+        &fixtures("pubmed"),
+        "--output",
+        output_directory.to_str().unwrap(),
+        "--format",
+        "jsonl",
+    ]);
+    assert!(output.status.success());
+    let manifest =
+        RunManifest::from_path(output_directory.join("manifest.json").to_str().unwrap()).unwrap();
+    assert_eq!(
+        manifest.rows().iter().collect::<Vec<_>>(),
+        [(&"articles.jsonl".to_string(), &2)]
+    );
+
+    let output = pubmed_parser(&["parse", &fixtures("pubmed"), "--format", "csv"]);
+    assert_eq!(output.status.code(), Some(2));
+}
+
+#[test]
+fn test_dedup_nodes_and_filter_edges() {
+    let nodes = std::env::temp_dir().join("pubmed_parser_test_cli_nodes.tsv");
+    let edges = std::env::temp_dir().join("pubmed_parser_test_cli_edges.tsv");
+
+    let output = pubmed_parser(&[
+        "dedup-nodes",
+        &format!("{}/nodes.tsv", fixtures("tsv")),
+        nodes.to_str().unwrap(),
+    ]);
+    assert!(output.status.success());
+    assert_eq!(
+        std::fs::read_to_string(&nodes).unwrap(),
+        std::fs::read_to_string(format!("{}/cleaned_nodes.tsv", fixtures("tsv"))).unwrap()
+    );
+
+    let output = pubmed_parser(&[
+        "filter-edges",
+        nodes.to_str().unwrap(),
+        &format!("{}/cleaned_edges.tsv", fixtures("tsv")),
+        edges.to_str().unwrap(),
+    ]);
+    assert!(output.status.success());
+    assert_eq!(
+        std::fs::read_to_string(&edges).unwrap(),
+        std::fs::read_to_string(format!("{}/really_cleaned_edges.tsv", fixtures("tsv"))).unwrap()
+    );
+}
+
+#[test]
+fn test_stats_counts_the_records_of_each_file() {
+    let output = pubmed_parser(&["stats", &fixtures("pubmed")]);
+    assert!(output.status.success());
+
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    let lines = stdout.lines().collect::<Vec<&str>>();
+    assert_eq!(lines.len(), 4);
+    assert_eq!(lines[0], "file\tarticles\tbook_articles\tdeletions");
+    assert!(lines[1].ends_with("pubmed23n0001.xml.gz\t1\t0\t0"));
+    assert!(lines[2].ends_with("pubmed23n0002.xml.gz\t1\t1\t2"));
+    assert_eq!(lines[3], "total\t2\t1\t2");
+}
+
+#[test]
+fn test_index_and_get_articles() {
+    let index = std::env::temp_dir().join("pubmed_parser_test_cli_index.tsv");
+    let output = pubmed_parser(&[
+        "index",
+        &fixtures("pubmed"),
+        "--output",
+        index.to_str().unwrap(),
+    ]);
+    assert!(output.status.success());
+
+    let output = pubmed_parser(&["get", index.to_str().unwrap(), "100"]);
+    assert!(output.status.success());
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    assert!(stdout.starts_with("<PubmedArticle>"));
+    assert!(stdout.contains("<PMID Version=\"2\">100</PMID>"));
+
+    let output = pubmed_parser(&["get", index.to_str().unwrap(), "1", "100", "--json"]);
+    assert!(output.status.success());
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    assert_eq!(
+        stdout
+            .lines()
+            .map(
+                |line| serde_json::from_str::<serde_json::Value>(line).unwrap()["pubmed_id"]
+                    .clone()
+            )
+            .collect::<Vec<_>>(),
+        [1, 100]
+    );
+
+    let output = pubmed_parser(&["get", index.to_str().unwrap(), "1", "200"]);
+    assert_eq!(output.status.code(), Some(1));
+    assert_eq!(
+        String::from_utf8(output.stdout)
+            .unwrap()
+            .lines()
+            .filter(|line| line.starts_with("<PubmedArticle>"))
+            .count(),
+        1
+    );
+
+    // The files within a tar archive cannot be read again through the index.
+    let archive = std::env::temp_dir().join("pubmed_parser_test_cli_index.tar");
+    std::fs::write(&archive, b"").unwrap();
+    let output = pubmed_parser(&[
+        "index",
+        archive.to_str().unwrap(),
+        "--output",
+        index.to_str().unwrap(),
+    ]);
+    assert_eq!(output.status.code(), Some(1));
+    assert!(String::from_utf8(output.stderr)
+        .unwrap()
+        .contains("cannot be read as files"));
+}
+
+#[test]
+fn test_validate_exit_code() {
+    let output = pubmed_parser(&["validate", &fixtures("pubmed")]);
+    assert!(output.status.success());
+    assert!(output.stdout.is_empty());
+
+    let invalid_directory = std::env::temp_dir().join("pubmed_parser_test_cli_invalid");
+    std::fs::create_dir_all(&invalid_directory).unwrap();
+    let mut encoder = flate2::write::GzEncoder::new(
+        std::fs::File::create(invalid_directory.join("pubmed23n0001.xml.gz")).unwrap(),
+        flate2::Compression::default(),
+    );
+    std::io::Write::write_all(
+        &mut encoder,
+        b"<PubmedArticleSet>\n<PubmedArticle>\n<MedlineCitation>\n<Unknown/>\n",
+    )
+    .unwrap();
+    encoder.finish().unwrap();
+
+    let output = pubmed_parser(&["validate", invalid_directory.to_str().unwrap()]);
+    assert_eq!(output.status.code(), Some(1));
+    assert!(!output.stdout.is_empty());
+
+    // The same PMID within the baseline twice is a violation, reported with its location.
+    let duplicate_directory = std::env::temp_dir().join("pubmed_parser_test_cli_duplicate");
+    std::fs::create_dir_all(&duplicate_directory).unwrap();
+    for name in ["pubmed23n0001.xml.gz", "pubmed23n0002.xml.gz"] {
+        std::fs::copy(
+            format!("{}/pubmed23n0001.xml.gz", fixtures("pubmed")),
+            duplicate_directory.join(name),
+        )
+        .unwrap();
+    }
+    let output = pubmed_parser(&["validate", duplicate_directory.to_str().unwrap()]);
+    assert_eq!(output.status.code(), Some(1));
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    assert_eq!(stdout.lines().count(), 1);
+    assert!(stdout.contains("pubmed23n0002.xml.gz:"));
+    assert!(stdout.contains(" PMID 1: The PMID was already read at "));
+}
+
+#[test]
+fn test_errors_exit_with_failure() {
+    let output = pubmed_parser(&["stats", "missing_directory"]);
+    assert_eq!(output.status.code(), Some(1));
+    assert!(String::from_utf8(output.stderr)
+        .unwrap()
+        .contains("missing_directory"));
+
+    let output = pubmed_parser(&["unknown-command"]);
+    assert_eq!(output.status.code(), Some(2));
+}
@@ -0,0 +1,158 @@
+extern crate pubmed_parser;
+mod common;
+
+use pubmed_parser::*;
+use std::collections::BTreeMap;
+
+fn fixtures() -> String {
+    format!("{}/tests/fixtures/pubmed", env!("CARGO_MANIFEST_DIR"))
+}
+
+/// Counts the rows of each type, the second column, of a nodes or edges TSV.
+fn count_types(path: &str) -> BTreeMap<String, u64> {
+    let mut counts = BTreeMap::new();
+    for line in std::fs::read_to_string(path).unwrap().lines().skip(1) {
+        *counts
+            .entry(line.split('\t').nth(1).unwrap().to_string())
+            .or_insert(0) += 1;
+    }
+    counts
+}
+
+#[test]
+fn test_statistics_of_the_fixtures() {
+    let mut statistics = DatasetStatistics::new(&GraphExportConfig::new());
+    for name in ["pubmed23n0001.xml.gz", "pubmed23n0002.xml.gz"] {
+        statistics.start_file(name);
+        for article in parse_single_pubmed(format!("{}/{}", fixtures(), name)).unwrap() {
+            statistics.add_article(&article);
+        }
+    }
+
+    assert_eq!(statistics.articles(), 2);
+    assert_eq!(
+        statistics.articles_per_file().iter().collect::<Vec<_>>(),
+        [
+            (&"pubmed23n0001.xml.gz".to_string(), &1),
+            (&"pubmed23n0002.xml.gz".to_string(), &1)
+        ]
+    );
+    assert_eq!(
+        statistics.articles_per_year().iter().collect::<Vec<_>>(),
+        [(&1975, &1), (&2020, &1)]
+    );
+    assert_eq!(statistics.articles_without_year(), 0);
+    assert_eq!(statistics.articles_with_title(), 2);
+    assert_eq!(statistics.articles_with_abstract(), 1);
+    assert_eq!(statistics.articles_with_mesh(), 2);
+    assert_eq!(statistics.share(statistics.articles_with_abstract()), 0.5);
+    assert_eq!(
+        statistics.top_journals(),
+        [
+            ("Biochemical medicine", 1),
+            ("Journal of Synthetic Fixtures", 1)
+        ]
+    );
+    assert_eq!(
+        statistics.node_types(),
+        &count_types(&format!("{}/expected_nodes.tsv", fixtures()))
+    );
+    assert_eq!(
+        statistics.edge_types(),
+        &count_types(&format!("{}/expected_edges.tsv", fixtures()))
+    );
+
+    let statistics = statistics.with_top_journals(1);
+    assert_eq!(statistics.top_journals(), [("Biochemical medicine", 1)]);
+}
+
+#[test]
+fn test_statistics_follow_the_export_configuration() {
+    let config = GraphExportConfig::new()
+        .with_node_types(&["Paper"])
+        .with_edge_types(&["Citation"]);
+    let mut statistics = DatasetStatistics::new(&config);
+    for article in parse_single_pubmed(format!("{}/pubmed23n0002.xml.gz", fixtures())).unwrap() {
+        statistics.add_article(&article);
+    }
+    assert_eq!(
+        statistics.node_types().iter().collect::<Vec<_>>(),
+        [(&"Paper".to_string(), &1)]
+    );
+    assert_eq!(
+        statistics.edge_types().iter().collect::<Vec<_>>(),
+        [(&"Citation".to_string(), &1)]
+    );
+    // Articles read outside of any file are not attributed to one.
+    assert!(statistics.articles_per_file().is_empty());
+
+    let empty = DatasetStatistics::new(&config);
+    assert_eq!(empty.share(empty.articles_with_title()), 0.0);
+}
+
+#[test]
+fn test_statistics_sink_writes_the_reports() {
+    let directory = common::fixture_directory("dataset_statistics");
+    let config = GraphExportConfig::new().with_output_directory(&directory);
+    parse_pubmed_into(
+        &fixtures(),
+        &TextNormalization::default(),
+        Box::new(
+            FanOutSink::new()
+                .with_sink(Box::new(TsvGraphSink::new(&config).unwrap()))
+                .with_sink(Box::new(StatisticsSink::new(&config))),
+        ),
+    )
+    .unwrap();
+
+    let json: serde_json::Value =
+        serde_json::from_str(&std::fs::read_to_string(directory.join("statistics.json")).unwrap())
+            .unwrap();
+    assert_eq!(json["articles"], 2);
+    assert_eq!(
+        json["articles_per_file"][format!("{}/pubmed23n0002.xml.gz", fixtures())],
+        1
+    );
+    assert_eq!(json["articles_per_year"]["1975"], 1);
+    assert_eq!(json["articles_with_abstract"]["count"], 1);
+    assert_eq!(json["articles_with_abstract"]["share"], 0.5);
+    assert_eq!(json["top_journals"][0]["journal"], "Biochemical medicine");
+    assert_eq!(
+        json["node_types"]["Mesh"],
+        count_types(config.nodes_path().to_str().unwrap())["Mesh"]
+    );
+
+    let text = std::fs::read_to_string(directory.join("statistics.txt")).unwrap();
+    assert!(text.starts_with("articles\t2\n"));
+    assert!(text.contains("with abstract\t1\t50.00%\n"));
+    assert!(text.contains("\narticles per year\n1975\t1\n2020\t1\n"));
+    assert!(text.contains("\nedges per type\nCitation\t1\n"));
+}
+
+#[test]
+fn test_merged_statistics_are_attributed_to_files() {
+    let directory = common::fixture_directory("dataset_statistics_merged");
+    let updates = directory.join("updates");
+    std::fs::create_dir_all(&updates).unwrap();
+    common::write_gz(
+        &updates.join("pubmed23n0003.xml.gz"),
+        &common::pubmed_article_set(&[&common::article(101, 1, "Added by an update")]),
+    );
+    let config = GraphExportConfig::new().with_output_directory(&directory);
+    parse_pubmed_merged_into(
+        &fixtures(),
+        updates.to_str().unwrap(),
+        &TextNormalization::default(),
+        Box::new(StatisticsSink::new(&config)),
+    )
+    .unwrap();
+
+    let json: serde_json::Value =
+        serde_json::from_str(&std::fs::read_to_string(directory.join("statistics.json")).unwrap())
+            .unwrap();
+    assert_eq!(json["articles"], 3);
+    assert_eq!(
+        json["articles_per_file"][updates.join("pubmed23n0003.xml.gz").to_str().unwrap()],
+        1
+    );
+}
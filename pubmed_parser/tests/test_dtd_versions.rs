@@ -0,0 +1,127 @@
+extern crate pubmed_parser;
+
+use pubmed_parser::*;
+use std::fs::File;
+use std::io::{BufReader, Cursor};
+
+fn read_fixture(dtd_version: DtdVersion) -> (Option<DtdVersion>, Vec<PubmedEvent>) {
+    let path = format!(
+        "tests/fixtures/dtd/{}",
+        dtd_version.file_name().replace(".dtd", ".xml")
+    );
+    let mut reader = PubmedReader::new(BufReader::new(File::open(&path).unwrap()), &path);
+    let events = reader
+        .by_ref()
+        .collect::<Result<Vec<PubmedEvent>, String>>()
+        .unwrap();
+    (reader.dtd_version(), events)
+}
+
+fn articles(events: &[PubmedEvent]) -> Vec<&Article> {
+    events
+        .iter()
+        .filter_map(|event| match event {
+            PubmedEvent::Article(article) => Some(article.as_ref()),
+            _ => None,
+        })
+        .collect()
+}
+
+#[test]
+fn test_every_supported_dtd_version_has_a_fixture() {
+    for dtd_version in DtdVersion::all() {
+        let (detected_version, events) = read_fixture(*dtd_version);
+        assert_eq!(detected_version, Some(*dtd_version));
+        assert!(!articles(&events).is_empty());
+    }
+}
+
+#[test]
+fn test_pubmed_2019() {
+    let (_, events) = read_fixture(DtdVersion::Pubmed2019);
+    let articles = articles(&events);
+    assert_eq!(articles.len(), 1);
+    assert_eq!(articles[0].pubmed_id(), 1);
+    assert_eq!(articles[0].indexing_method(), None);
+    assert_eq!(articles[0].doi(), Some("10.1016/0006-2944(75)90147-7"));
+    assert_eq!(
+        articles[0].pagination().unwrap().end_page(),
+        Some("126".to_string())
+    );
+    assert_eq!(articles[0].other_abstract_texts().len(), 1);
+    assert_eq!(
+        articles[0].other_abstract_texts()[0].abstract_type(),
+        Some("PIP")
+    );
+    assert_eq!(
+        articles[0]
+            .keywords()
+            .iter()
+            .map(|keyword| (keyword.owner(), keyword.name(), keyword.is_major_topic()))
+            .collect::<Vec<_>>(),
+        [("PIP", "Methanol", true)]
+    );
+    assert!(events.iter().any(
+        |event| matches!(event, PubmedEvent::Deletion(deletion) if deletion.pubmed_id() == 2)
+    ));
+}
+
+#[test]
+fn test_pubmed_2023() {
+    let (_, events) = read_fixture(DtdVersion::Pubmed2023);
+    let articles = articles(&events);
+    assert_eq!(articles.len(), 1);
+    assert_eq!(articles[0].pubmed_id(), 36000000);
+    assert_eq!(articles[0].indexing_method(), Some("Automated"));
+    assert!(articles[0].is_automatically_indexed());
+    assert_eq!(
+        articles[0]
+            .references()
+            .iter()
+            .map(|reference| reference.citation())
+            .collect::<Vec<_>>(),
+        [
+            Some("Sleep and cognition. Lancet Neurol. 2020;19:1-2."),
+            Some("Sleep in older adults. Sleep. 2021;44:3-9.")
+        ]
+    );
+}
+
+#[test]
+fn test_pubmed_2025() {
+    let (_, events) = read_fixture(DtdVersion::Pubmed2025);
+    let articles = articles(&events);
+    assert_eq!(articles.len(), 1);
+    assert_eq!(articles[0].pubmed_id(), 39700000);
+    assert_eq!(articles[0].indexing_method(), Some("Curated"));
+    assert!(!articles[0].is_automatically_indexed());
+    assert_eq!(articles[0].doi(), Some("10.1038/s41598-024-81234-5"));
+    assert!(articles[0].keywords().is_empty());
+}
+
+#[test]
+fn test_dtd_version_without_a_fixture() {
+    let xml = concat!(
+        "<?xml version=\"1.0\" ?>\n",
+        "<!DOCTYPE PubmedArticleSet PUBLIC \"-//NLM//DTD PubMedArticle, 1st January 2024//EN\" ",
+        "\"https://dtd.nlm.nih.gov/ncbi/pubmed/out/pubmed_240101.dtd\">\n",
+        "<PubmedArticleSet>\n",
+        "</PubmedArticleSet>\n",
+    );
+    let mut reader = PubmedReader::new(Cursor::new(xml), "pubmed24n0001.xml");
+    assert!(reader.by_ref().all(|event| event.is_ok()));
+    assert_eq!(reader.dtd_version(), Some(DtdVersion::Pubmed2023));
+
+    assert_eq!(
+        DtdVersion::from_doctype(&xml.replace("240101", "150101")),
+        Some(DtdVersion::Pubmed2019)
+    );
+    assert_eq!(
+        DtdVersion::from_doctype(&xml.replace("240101", "260101")),
+        Some(DtdVersion::Pubmed2025)
+    );
+    assert_eq!(
+        DtdVersion::from_doctype("<!DOCTYPE PubmedArticleSet SYSTEM \"articles.dtd\">"),
+        None
+    );
+}
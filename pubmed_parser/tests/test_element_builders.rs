@@ -0,0 +1,75 @@
+extern crate pubmed_parser;
+mod common;
+
+use pubmed_parser::*;
+use std::io::Cursor;
+
+/// Counts the `GeneralNote` elements of an article, whatever their owner.
+#[derive(Default)]
+struct GeneralNoteCounter {
+    count: usize,
+}
+
+impl ElementBuilder for GeneralNoteCounter {
+    fn parse(&mut self, line: &str) -> Result<bool, String> {
+        if line.starts_with("<GeneralNote") {
+            self.count += 1;
+            return Ok(true);
+        }
+        Ok(false)
+    }
+
+    fn can_build(&self) -> bool {
+        self.count > 0
+    }
+
+    fn build(self: Box<Self>) -> Result<Vec<String>, String> {
+        Ok(vec![self.count.to_string()])
+    }
+}
+
+fn article_with(pubmed_id: u32, content: &str) -> String {
+    common::article(pubmed_id, 1, "Title").replace(
+        "    </MedlineCitation>\n",
+        &format!("{}    </MedlineCitation>\n", content),
+    )
+}
+
+#[test]
+fn test_registered_element_builders() {
+    let xml = common::pubmed_article_set(&[
+        &article_with(
+            1,
+            concat!(
+                "      <OtherID Source=\"NASA\">97606508</OtherID>\n",
+                "      <OtherID Source=\"NLM\">PMC1234</OtherID>\n",
+                "      <SpaceFlightMission>STS-51</SpaceFlightMission>\n",
+                "      <GeneralNote Owner=\"NASA\">KSC</GeneralNote>\n",
+                "      <GeneralNote Owner=\"NLM\">Note</GeneralNote>\n",
+            ),
+        ),
+        &common::article(2, 1, "Second"),
+    ]);
+
+    let articles = PubmedReader::new(Cursor::new(xml), "element_builders")
+        .with_element_builder("OtherID", || TextElementBuilder::new("OtherID"))
+        .with_element_builder("SpaceFlightMission", || {
+            TextElementBuilder::new("SpaceFlightMission")
+        })
+        .with_element_builder("GeneralNoteCount", GeneralNoteCounter::default)
+        .map(|event| match event.unwrap() {
+            PubmedEvent::Article(article) => *article,
+            _ => panic!("Expected an article."),
+        })
+        .collect::<Vec<Article>>();
+
+    assert_eq!(articles.len(), 2);
+    let extras = articles[0].extras();
+    assert_eq!(
+        extras["OtherID"],
+        vec!["97606508".to_string(), "PMC1234".to_string()]
+    );
+    assert_eq!(extras["SpaceFlightMission"], vec!["STS-51".to_string()]);
+    assert_eq!(extras["GeneralNoteCount"], vec!["2".to_string()]);
+    assert!(articles[1].extras().is_empty());
+}
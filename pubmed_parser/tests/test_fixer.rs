@@ -1,38 +1,28 @@
 extern crate pubmed_parser;
-use indicatif::ProgressBar;
-use indicatif::ProgressIterator;
-use std::{
-    collections::HashSet,
-    io::{BufRead, Write},
-};
+use pubmed_parser::*;
 
 #[test]
 fn test_fixer() {
-    let mut unique_nodes: HashSet<String> = HashSet::new();
+    let fixtures = format!("{}/tests/fixtures/tsv", env!("CARGO_MANIFEST_DIR"));
+    let output_path = std::env::temp_dir().join("pubmed_parser_cleaned_nodes.tsv");
 
-    let source = std::fs::File::open("/bfd/pubmed/tsv/nodes.tsv").unwrap();
-    let source = std::io::BufReader::new(source);
+    let rows = dedup_nodes(
+        &format!("{}/nodes.tsv", fixtures),
+        output_path.to_str().unwrap(),
+    )
+    .unwrap();
 
-    let destination = std::fs::File::create("/bfd/pubmed/tsv/cleaned_nodes.tsv").unwrap();
-    let mut destination = std::io::BufWriter::new(destination);
+    let expected = std::fs::read_to_string(format!("{}/cleaned_nodes.tsv", fixtures)).unwrap();
+    assert_eq!(rows, expected.lines().count());
+    assert_eq!(std::fs::read_to_string(&output_path).unwrap(), expected);
+}
 
-    let pb = ProgressBar::new(512_762_623 as u64);
+#[test]
+fn test_fixer_rejects_malformed_rows() {
+    let source = std::env::temp_dir().join("pubmed_parser_malformed_nodes.tsv");
+    let output_path = std::env::temp_dir().join("pubmed_parser_malformed_cleaned_nodes.tsv");
+    std::fs::write(&source, "node_name\tnode_type\tdescription\nPMID:1\n").unwrap();
 
-    source.lines().progress_with(pb).for_each(|line| {
-        let line = line.unwrap();
-        let splits = line.splitn(3, '\t').collect::<Vec<&str>>();
-        if unique_nodes.insert(splits[0].to_string()) {
-            destination
-                .write(
-                    format!(
-                        "{}\t{}\t{}\n",
-                        splits[0],
-                        splits[1],
-                        splits[2].replace('\t', " ").trim()
-                    )
-                    .as_bytes(),
-                )
-                .unwrap();
-        }
-    });
+    assert!(dedup_nodes(source.to_str().unwrap(), output_path.to_str().unwrap()).is_err());
+    assert!(dedup_nodes("missing_nodes.tsv", output_path.to_str().unwrap()).is_err());
 }
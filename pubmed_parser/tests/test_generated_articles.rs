@@ -0,0 +1,60 @@
+extern crate pubmed_parser;
+mod common;
+
+use common::generator::*;
+use proptest::prelude::*;
+use pubmed_parser::*;
+use std::io::Cursor;
+
+fn read(xml: &str) -> Vec<Result<PubmedEvent, String>> {
+    PubmedReader::new(Cursor::new(xml.to_string()), "generated").collect()
+}
+
+proptest! {
+    #[test]
+    fn test_generated_article_round_trip(generated in generated_article()) {
+        let events = read(&generated.xml);
+        prop_assert_eq!(events.len(), 1);
+        let article = match events.into_iter().next().unwrap() {
+            Ok(PubmedEvent::Article(article)) => article,
+            Ok(_) => panic!("Expected an article."),
+            Err(err) => panic!("{}\n{}", err, generated.xml),
+        };
+        prop_assert_eq!(
+            ExpectedArticle::from_article(&article),
+            generated.expected.normalized()
+        );
+    }
+
+    #[test]
+    fn test_truncated_documents_never_panic(
+        generated in generated_article(),
+        length in any::<prop::sample::Index>(),
+    ) {
+        let mut length = length.index(generated.xml.len());
+        while !generated.xml.is_char_boundary(length) {
+            length -= 1;
+        }
+        read(&generated.xml[..length]);
+    }
+
+    #[test]
+    fn test_shuffled_lines_never_panic(
+        generated in generated_article(),
+        swaps in prop::collection::vec((any::<prop::sample::Index>(), any::<prop::sample::Index>()), 1..8),
+    ) {
+        let mut lines = generated.xml.lines().collect::<Vec<&str>>();
+        for (left, right) in swaps {
+            let (left, right) = (left.index(lines.len()), right.index(lines.len()));
+            lines.swap(left, right);
+        }
+        read(&lines.join("\n"));
+    }
+
+    #[test]
+    fn test_arbitrary_text_never_panics(
+        lines in prop::collection::vec("[<>/ =\"a-zA-Z&#;0-9]{0,40}", 0..40),
+    ) {
+        read(&lines.join("\n"));
+    }
+}
@@ -0,0 +1,133 @@
+extern crate pubmed_parser;
+
+use pubmed_parser::*;
+
+fn fixtures() -> String {
+    format!("{}/tests/fixtures/pubmed", env!("CARGO_MANIFEST_DIR"))
+}
+
+fn rows(path: std::path::PathBuf) -> Vec<String> {
+    std::fs::read_to_string(path)
+        .unwrap()
+        .lines()
+        .map(|line| line.to_string())
+        .collect()
+}
+
+#[test]
+fn test_citation_and_mesh_graphs_from_the_same_directory() {
+    let output_directory = std::env::temp_dir().join("pubmed_parser_test_graph_export");
+
+    let citations = GraphExportConfig::new()
+        .with_output_directory(&output_directory)
+        .with_nodes_file_name("citation_nodes.tsv")
+        .with_edges_file_name("citation_edges.tsv")
+        .with_node_types(&["Paper"])
+        .with_edge_types(&["Citation"]);
+    let mesh = GraphExportConfig::new()
+        .with_output_directory(&output_directory)
+        .with_nodes_file_name("mesh_nodes.tsv")
+        .with_edges_file_name("mesh_edges.tsv")
+        .with_node_types(&["Paper", "Mesh"])
+        .with_edge_types(&["PaperToMesh"])
+        .with_prefix("Mesh", "MESH:");
+
+    parse_pubmed(&fixtures(), &citations).unwrap();
+    parse_pubmed(&fixtures(), &mesh).unwrap();
+
+    assert_eq!(
+        rows(citations.edges_path()),
+        ["subject\tedge_type\tobject", "PMID:100\tCitation\tPMID:1"]
+    );
+    assert_eq!(
+        rows(citations.nodes_path())
+            .iter()
+            .map(|row| row.split('\t').take(2).collect::<Vec<_>>().join("\t"))
+            .collect::<Vec<_>>(),
+        ["node_name\tnode_type", "PMID:1\tPaper", "PMID:100\tPaper"]
+    );
+
+    assert_eq!(
+        rows(mesh.edges_path()),
+        [
+            "subject\tedge_type\tobject",
+            "PMID:1\tPaperToMesh\tMESH:D000445",
+            "PMID:1\tPaperToMesh\tMESH:Q000378",
+            "PMID:1\tPaperToMesh\tMESH:D000818",
+            "PMID:1\tPaperToMesh\tMESH:D005561",
+            "PMID:1\tPaperToMesh\tMESH:Q000097",
+            "PMID:1\tPaperToMesh\tMESH:Q000506",
+            "PMID:100\tPaperToMesh\tMESH:D006801",
+        ]
+    );
+    assert_eq!(
+        rows(mesh.nodes_path())
+            .iter()
+            .map(|row| row.split('\t').take(2).collect::<Vec<_>>().join("\t"))
+            .collect::<Vec<_>>(),
+        [
+            "node_name\tnode_type",
+            "PMID:1\tPaper",
+            "MESH:D000445\tMesh",
+            "MESH:Q000378\tMesh",
+            "MESH:D000818\tMesh",
+            "MESH:D005561\tMesh",
+            "MESH:Q000097\tMesh",
+            "MESH:Q000506\tMesh",
+            "PMID:100\tPaper",
+            "MESH:D006801\tMesh",
+        ]
+    );
+}
+
+#[test]
+fn test_default_config() {
+    let config = GraphExportConfig::default();
+    assert_eq!(config.nodes_path(), std::path::Path::new("./nodes.tsv"));
+    assert_eq!(config.edges_path(), std::path::Path::new("./edges.tsv"));
+    assert!(config.includes_node_type("Paper"));
+    assert!(config.includes_edge_type("Citation"));
+    assert_eq!(config.node_name("Mesh", "D000001"), "D000001");
+    assert_eq!(config.export_options(), &ExportOptions::default());
+}
+
+#[test]
+fn test_edge_end_types() {
+    let articles = parse_single_pubmed(format!("{}/pubmed23n0002.xml.gz", fixtures())).unwrap();
+    let edges = articles[0].to_edges();
+    assert_eq!(
+        edges
+            .iter()
+            .map(|edge| (edge.subject_type(), edge.edge_type(), edge.object_type()))
+            .collect::<Vec<_>>(),
+        [
+            ("Paper", "PaperToChemical", "Chemical"),
+            ("Paper", "PaperToGene", "Gene"),
+            ("Paper", "PaperToGene", "Gene"),
+            ("Paper", "PaperToMesh", "Mesh"),
+            ("Paper", "PaperToDisease", "Disease"),
+            ("Paper", "PaperToKeyword", "Keyword"),
+            ("Paper", "PaperToKeyword", "Keyword"),
+            ("Paper", "Citation", "Paper"),
+        ]
+    );
+}
+
+#[test]
+fn test_errors_are_returned() {
+    let output_directory = std::env::temp_dir().join("pubmed_parser_test_graph_export_errors");
+    let config = GraphExportConfig::new().with_output_directory(&output_directory);
+
+    assert!(parse_pubmed("missing_directory", &config).is_err());
+    assert!(parse_single_pubmed("missing_file.xml.gz".to_string()).is_err());
+    assert!(parse_pubmed_merged(&fixtures(), "missing_directory", &config).is_err());
+
+    let invalid_directory = output_directory.join("invalid");
+    std::fs::create_dir_all(&invalid_directory).unwrap();
+    std::fs::write(
+        invalid_directory.join("pubmed23n0001.xml.gz"),
+        b"not gzipped",
+    )
+    .unwrap();
+    assert!(parse_pubmed(invalid_directory.to_str().unwrap(), &config).is_err());
+}
@@ -0,0 +1,93 @@
+extern crate pubmed_parser;
+mod common;
+
+use pubmed_parser::*;
+
+fn article(pubmed_id: u32, languages: &[&str], vernacular_title: Option<&str>) -> String {
+    format!(
+        r#"<PubmedArticle>
+    <MedlineCitation Status="MEDLINE" Owner="NLM">
+      <PMID Version="1">{}</PMID>
+      <Article PubModel="Print">
+        <Journal>
+          <JournalIssue CitedMedium="Print">
+            <PubDate>
+              <Year>1980</Year>
+            </PubDate>
+          </JournalIssue>
+          <Title>Journal</Title>
+        </Journal>
+        <ArticleTitle>Title</ArticleTitle>
+        <Abstract>
+          <AbstractText>Abstract</AbstractText>
+        </Abstract>
+{}{}      </Article>
+    </MedlineCitation>
+  </PubmedArticle>
+"#,
+        pubmed_id,
+        languages
+            .iter()
+            .map(|language| format!("        <Language>{}</Language>\n", language))
+            .collect::<String>(),
+        vernacular_title
+            .map(|title| format!("        <VernacularTitle>{}</VernacularTitle>\n", title))
+            .unwrap_or_default(),
+    )
+}
+
+#[test]
+fn test_languages_and_vernacular_title() {
+    let path = common::write_gz_fixture(
+        "languages",
+        &common::pubmed_article_set(&[
+            &article(1, &["eng"], None),
+            &article(2, &["fre"], Some("Titre fran&#xe7;ais")),
+            &article(3, &["ger", "eng"], Some("Deutscher Titel")),
+            &article(4, &[], None),
+        ]),
+    );
+    let articles = parse_single_pubmed(path).unwrap();
+    assert_eq!(articles.len(), 4);
+
+    assert_eq!(articles[0].languages(), ["eng"]);
+    assert_eq!(articles[0].vernacular_title(), None);
+    assert!(articles[0].is_english());
+
+    assert_eq!(articles[1].languages(), ["fre"]);
+    assert_eq!(articles[1].vernacular_title(), Some("Titre français"));
+    assert!(!articles[1].is_english());
+
+    assert_eq!(articles[2].languages(), ["ger", "eng"]);
+    assert_eq!(articles[2].vernacular_title(), Some("Deutscher Titel"));
+    assert!(articles[2].is_english());
+
+    assert!(articles[3].languages().is_empty());
+    assert!(articles[3].is_english());
+
+    assert_eq!(
+        articles[1].paper_description(NonEnglishDescription::Keep),
+        "Title|Abstract"
+    );
+    assert_eq!(
+        articles[1].paper_description(NonEnglishDescription::Omit),
+        ""
+    );
+    assert_eq!(
+        articles[1].paper_description(NonEnglishDescription::Tag),
+        "[fre] Title|Abstract"
+    );
+    for non_english_description in [NonEnglishDescription::Omit, NonEnglishDescription::Tag] {
+        assert_eq!(
+            articles[2].paper_description(non_english_description),
+            "Title|Abstract"
+        );
+    }
+    assert_eq!(
+        articles[1].to_nodes_with_options(
+            &ExportOptions::new().with_non_english_description(NonEnglishDescription::Omit)
+        )[0]
+        .description(),
+        ""
+    );
+}
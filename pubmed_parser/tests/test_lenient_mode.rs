@@ -0,0 +1,54 @@
+extern crate pubmed_parser;
+mod common;
+
+use pubmed_parser::*;
+use std::io::Cursor;
+
+fn document_with_unknown_element() -> String {
+    let article = common::article(1, 1, "Title").replace(
+        "        <ArticleTitle>Title</ArticleTitle>\n",
+        concat!(
+            "        <ArticleTitle>Title</ArticleTitle>\n",
+            "        <NewElement Type=\"x\">\n",
+            "          <Child>first &amp; only</Child>\n",
+            "          <Empty/>\n",
+            "        </NewElement>\n",
+        ),
+    );
+    common::pubmed_article_set(&[&article, &common::article(2, 1, "Second")])
+}
+
+fn read(xml: &str, lenient: bool) -> Vec<Result<PubmedEvent, String>> {
+    PubmedReader::new(Cursor::new(xml.to_string()), "lenient")
+        .with_lenient(lenient)
+        .collect()
+}
+
+#[test]
+fn test_strict_mode_rejects_unknown_elements() {
+    let events = read(&document_with_unknown_element(), false);
+    assert!(events.iter().any(|event| event
+        .as_ref()
+        .err()
+        .is_some_and(|err| err.contains("was not handled by any parser"))));
+}
+
+#[test]
+fn test_lenient_mode_collects_unknown_elements() {
+    let articles = read(&document_with_unknown_element(), true)
+        .into_iter()
+        .map(|event| match event.unwrap() {
+            PubmedEvent::Article(article) => *article,
+            _ => panic!("Expected an article."),
+        })
+        .collect::<Vec<Article>>();
+
+    assert_eq!(articles.len(), 2);
+    assert_eq!(articles[0].title(), Some("Title"));
+    let extras = articles[0].extras();
+    assert_eq!(extras["NewElement/@Type"], vec!["x".to_string()]);
+    assert_eq!(extras["NewElement/Child"], vec!["first & only".to_string()]);
+    assert!(!extras.contains_key("NewElement/Empty"));
+    assert!(articles[1].extras().is_empty());
+    assert_eq!(articles[1].title(), Some("Second"));
+}
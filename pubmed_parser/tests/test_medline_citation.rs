@@ -0,0 +1,52 @@
+extern crate pubmed_parser;
+mod common;
+
+use pubmed_parser::*;
+
+#[test]
+fn test_medline_citation_attributes_and_subsets() {
+    let automated = common::article(1, 1, "Automated")
+        .replace(
+            "<MedlineCitation Status=\"MEDLINE\" Owner=\"NLM\">",
+            "<MedlineCitation Status=\"MEDLINE\" IndexingMethod=\"Automated\" Owner=\"NLM\">",
+        )
+        .replace(
+            "    </MedlineCitation>",
+            concat!(
+                "      <CitationSubset>AIM</CitationSubset>\n",
+                "      <CitationSubset>IM</CitationSubset>\n",
+                "    </MedlineCitation>"
+            ),
+        );
+    let in_process = common::article(2, 1, "In process").replace(
+        "<MedlineCitation Status=\"MEDLINE\" Owner=\"NLM\">",
+        "<MedlineCitation Status=\"In-Process\" Owner=\"NLM\">",
+    );
+    let publisher = common::article(3, 1, "Publisher").replace(
+        "<MedlineCitation Status=\"MEDLINE\" Owner=\"NLM\">",
+        "<MedlineCitation Status=\"PubMed-not-MEDLINE\" Owner=\"NASA\">",
+    );
+    let path = common::write_gz_fixture(
+        "medline_citation",
+        &common::pubmed_article_set(&[&automated, &in_process, &publisher]),
+    );
+    let articles = parse_single_pubmed(path).unwrap();
+    assert_eq!(articles.len(), 3);
+
+    assert_eq!(articles[0].status(), Some("MEDLINE"));
+    assert_eq!(articles[0].owner(), Some("NLM"));
+    assert_eq!(articles[0].indexing_method(), Some("Automated"));
+    assert_eq!(articles[0].citation_subsets(), ["AIM", "IM"]);
+    assert!(articles[0].is_mesh_indexed());
+    assert!(articles[0].is_automatically_indexed());
+
+    assert_eq!(articles[1].status(), Some("In-Process"));
+    assert_eq!(articles[1].indexing_method(), None);
+    assert!(articles[1].citation_subsets().is_empty());
+    assert!(!articles[1].is_mesh_indexed());
+    assert!(!articles[1].is_automatically_indexed());
+
+    assert_eq!(articles[2].status(), Some("PubMed-not-MEDLINE"));
+    assert_eq!(articles[2].owner(), Some("NASA"));
+    assert!(!articles[2].is_mesh_indexed());
+}
@@ -0,0 +1,139 @@
+extern crate pubmed_parser;
+mod common;
+
+use pubmed_parser::*;
+
+#[test]
+fn test_sort_by_sequence() {
+    let mut paths = vec![
+        "updates/pubmed23n1168.xml.gz".to_string(),
+        "baseline/pubmed23n0010.xml.gz".to_string(),
+        "updates/pubmed23n1167.xml.gz".to_string(),
+        "baseline/pubmed23n0002.xml.gz".to_string(),
+        "baseline/pubmed23n0001.xml.gz".to_string(),
+    ];
+    sort_by_sequence(&mut paths);
+    assert_eq!(
+        paths,
+        [
+            "baseline/pubmed23n0001.xml.gz",
+            "baseline/pubmed23n0002.xml.gz",
+            "baseline/pubmed23n0010.xml.gz",
+            "updates/pubmed23n1167.xml.gz",
+            "updates/pubmed23n1168.xml.gz",
+        ]
+    );
+}
+
+#[test]
+fn test_merge_pubmed() {
+    let baseline = common::fixture_directory("merge_baseline");
+    let updates = common::fixture_directory("merge_updates");
+    common::write_gz(
+        &baseline.join("pubmed23n0001.xml.gz"),
+        &common::pubmed_article_set(&[
+            &common::article(1, 1, "One"),
+            &common::article(2, 1, "Two"),
+            &common::article(3, 1, "Three"),
+        ]),
+    );
+    common::write_gz(
+        &baseline.join("pubmed23n0002.xml.gz"),
+        &common::pubmed_article_set(&[
+            &common::article(4, 1, "Four"),
+            &common::article(5, 2, "Five, second version"),
+        ]),
+    );
+    // The second update is written first, to check that the files are sorted.
+    common::write_gz(
+        &updates.join("pubmed23n0004.xml.gz"),
+        &common::pubmed_article_set(&[
+            &common::article(3, 1, "Three, revised again"),
+            &common::delete_citation(&[(4, 1)]),
+            &common::article(6, 1, "Six"),
+        ]),
+    );
+    common::write_gz(
+        &updates.join("pubmed23n0003.xml.gz"),
+        &common::pubmed_article_set(&[
+            &common::article(3, 1, "Three, revised"),
+            &common::delete_citation(&[(2, 1), (5, 1)]),
+            &common::article(5, 1, "Five, first version"),
+            &common::article(6, 1, "Six, replaced later"),
+        ]),
+    );
+
+    let mut paths = std::fs::read_dir(&baseline)
+        .unwrap()
+        .chain(std::fs::read_dir(&updates).unwrap())
+        .map(|entry| entry.unwrap().path().display().to_string())
+        .collect::<Vec<String>>();
+    paths.reverse();
+
+    let articles = merge_pubmed(paths, &TextNormalization::default())
+        .unwrap()
+        .collect::<Result<Vec<Article>, String>>()
+        .unwrap();
+
+    assert_eq!(
+        articles
+            .iter()
+            .map(|article| (
+                article.pubmed_id(),
+                article.pmid_version(),
+                article.title().unwrap()
+            ))
+            .collect::<Vec<_>>(),
+        [
+            (1, 1, "One"),
+            (5, 2, "Five, second version"),
+            (3, 1, "Three, revised again"),
+            (6, 1, "Six"),
+        ]
+    );
+}
+
+#[test]
+fn test_merge_reads_only_the_pmids_of_the_articles() {
+    let baseline = common::fixture_directory("merge_comments_baseline");
+    let updates = common::fixture_directory("merge_comments_updates");
+    // The PMID of a comment must not be taken for the PMID of the article.
+    let commented = common::article(1, 1, "Commented").replace(
+        "      <Article PubModel",
+        concat!(
+            "      <CommentsCorrectionsList>\n",
+            "        <CommentsCorrections RefType=\"CommentIn\">\n",
+            "          <RefSource>Fixture comment.</RefSource>\n",
+            "          <PMID Version=\"1\">2</PMID>\n",
+            "        </CommentsCorrections>\n",
+            "      </CommentsCorrectionsList>\n",
+            "      <Article PubModel"
+        ),
+    );
+    common::write_gz(
+        &baseline.join("pubmed23n0001.xml.gz"),
+        &common::pubmed_article_set(&[&commented, &common::article(2, 1, "Comment")]),
+    );
+    common::write_gz(
+        &updates.join("pubmed23n0002.xml.gz"),
+        &common::pubmed_article_set(&[&common::delete_citation(&[(2, 1)])]),
+    );
+
+    let articles = merge_pubmed(
+        vec![
+            baseline.join("pubmed23n0001.xml.gz").display().to_string(),
+            updates.join("pubmed23n0002.xml.gz").display().to_string(),
+        ],
+        &TextNormalization::default(),
+    )
+    .unwrap()
+    .collect::<Result<Vec<Article>, String>>()
+    .unwrap();
+    assert_eq!(
+        articles
+            .iter()
+            .map(|article| (article.pubmed_id(), article.title().unwrap()))
+            .collect::<Vec<_>>(),
+        [(1, "Commented")]
+    );
+}
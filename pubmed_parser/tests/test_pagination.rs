@@ -0,0 +1,93 @@
+extern crate pubmed_parser;
+mod common;
+
+use pubmed_parser::*;
+
+fn article_with(pubmed_id: u32, content: &str, article_ids: &str) -> String {
+    common::article(pubmed_id, 1, "Title")
+        .replace(
+            "        <ArticleTitle>Title</ArticleTitle>\n",
+            &format!("        <ArticleTitle>Title</ArticleTitle>\n{}", content),
+        )
+        .replace(
+            "    </MedlineCitation>\n",
+            &format!(
+                "    </MedlineCitation>\n    <PubmedData>\n      <ArticleIdList>\n{}      </ArticleIdList>\n    </PubmedData>\n",
+                article_ids
+            ),
+        )
+}
+
+#[test]
+fn test_pagination_and_elocation_ids() {
+    let explicit = article_with(
+        1,
+        concat!(
+            "        <Pagination>\n",
+            "          <StartPage>117</StartPage>\n",
+            "          <EndPage>126</EndPage>\n",
+            "          <MedlinePgn>117-26</MedlinePgn>\n",
+            "        </Pagination>\n",
+            "        <ELocationID EIdType=\"doi\" ValidYN=\"Y\">10.1016/0006-2944(75)90147-7</ELocationID>\n",
+        ),
+        concat!(
+            "        <ArticleId IdType=\"pubmed\">1</ArticleId>\n",
+            "        <ArticleId IdType=\"doi\">10.1016/0006-2944(75)90147-7</ArticleId>\n",
+        ),
+    );
+    let abbreviated = article_with(
+        2,
+        concat!(
+            "        <Pagination>\n",
+            "          <MedlinePgn>S1287-92; discussion 1293</MedlinePgn>\n",
+            "        </Pagination>\n",
+            "        <ELocationID EIdType=\"pii\" ValidYN=\"Y\">S0002-9343(05)00001-2</ELocationID>\n",
+            "        <ELocationID EIdType=\"doi\" ValidYN=\"Y\">10.1016/j.amjmed.2005.01.002</ELocationID>\n",
+        ),
+        "        <ArticleId IdType=\"pubmed\">2</ArticleId>\n",
+    );
+    let electronic = article_with(
+        3,
+        concat!(
+            "        <Pagination>\n",
+            "          <MedlinePgn>e0123456</MedlinePgn>\n",
+            "        </Pagination>\n",
+            "        <ELocationID EIdType=\"doi\" ValidYN=\"N\">10.1371/invalid</ELocationID>\n",
+        ),
+        "        <ArticleId IdType=\"pubmed\">3</ArticleId>\n",
+    );
+    let path = common::write_gz_fixture(
+        "pagination",
+        &common::pubmed_article_set(&[&explicit, &abbreviated, &electronic]),
+    );
+    let articles = parse_single_pubmed(path).unwrap();
+    assert_eq!(articles.len(), 3);
+
+    let pagination = articles[0].pagination().unwrap();
+    assert_eq!(pagination.start_page(), Some("117"));
+    assert_eq!(pagination.end_page().as_deref(), Some("126"));
+    assert_eq!(pagination.medline_pgn(), Some("117-26"));
+    assert_eq!(articles[0].doi(), Some("10.1016/0006-2944(75)90147-7"));
+    assert_eq!(articles[0].article_ids().len(), 2);
+    assert_eq!(articles[0].elocation_ids().len(), 1);
+
+    let pagination = articles[1].pagination().unwrap();
+    assert_eq!(pagination.start_page(), Some("S1287"));
+    assert_eq!(pagination.end_page().as_deref(), Some("S1292"));
+    assert_eq!(articles[1].doi(), Some("10.1016/j.amjmed.2005.01.002"));
+    assert_eq!(articles[1].pii(), Some("S0002-9343(05)00001-2"));
+    assert_eq!(
+        articles[1]
+            .article_ids()
+            .iter()
+            .map(|article_id| article_id.id_type())
+            .collect::<Vec<_>>(),
+        ["pubmed", "pii", "doi"]
+    );
+
+    let pagination = articles[2].pagination().unwrap();
+    assert_eq!(pagination.start_page(), Some("e0123456"));
+    assert_eq!(pagination.end_page(), None);
+    assert_eq!(articles[2].doi(), None);
+    assert!(articles[2].elocation_ids().is_empty());
+}
@@ -1,8 +1,292 @@
 extern crate pubmed_parser;
 
+use pubmed_parser::*;
+
+fn fixture(name: &str) -> String {
+    format!(
+        "{}/tests/fixtures/pubmed/{}",
+        env!("CARGO_MANIFEST_DIR"),
+        name
+    )
+}
+
+fn summarize_date(date: Option<&Date>) -> Option<(Option<u16>, Option<&str>, Option<u8>)> {
+    date.map(|date| (date.year(), date.month(), date.day()))
+}
+
+fn summarize_ids(article_ids: &[ArticleId]) -> Vec<(&str, &str)> {
+    article_ids
+        .iter()
+        .map(|article_id| (article_id.id_type(), article_id.value()))
+        .collect()
+}
+
+type MeshSummary<'a> = (&'a str, &'a str, bool, Vec<(&'a str, &'a str, bool)>);
+
+fn summarize_mesh(mesh: &Mesh) -> MeshSummary<'_> {
+    (
+        mesh.descriptor().code(),
+        mesh.descriptor().name(),
+        mesh.descriptor().is_major_topic(),
+        mesh.qualifiers()
+            .iter()
+            .map(|qualifier| {
+                (
+                    qualifier.code(),
+                    qualifier.name(),
+                    qualifier.is_major_topic(),
+                )
+            })
+            .collect(),
+    )
+}
+
+#[test]
+fn test_real_excerpt_article() {
+    let articles = parse_single_pubmed(fixture("pubmed23n0001.xml.gz")).unwrap();
+    assert_eq!(articles.len(), 1);
+    let article = &articles[0];
+
+    assert_eq!(article.pubmed_id(), 1);
+    assert_eq!(article.pmid_version(), 1);
+    assert_eq!(article.status(), Some("MEDLINE"));
+    assert_eq!(article.owner(), Some("NLM"));
+    assert_eq!(article.indexing_method(), None);
+    assert_eq!(article.citation_subsets(), ["IM"]);
+    assert_eq!(
+        summarize_date(article.completion_date()),
+        Some((Some(1976), Some("01"), Some(16)))
+    );
+    assert_eq!(
+        summarize_date(article.revision_date()),
+        Some((Some(2019), Some("02"), Some(8)))
+    );
+    assert_eq!(
+        summarize_ids(article.article_ids()),
+        [("pubmed", "1"), ("doi", "10.1016/0006-2944(75)90147-7")]
+    );
+    assert!(article.elocation_ids().is_empty());
+
+    let journal = article.journal();
+    assert_eq!(journal.title(), "Biochemical medicine");
+    assert_eq!(journal.issn(), Some("0006-2944"));
+    assert_eq!(journal.iso_abbreviation(), Some("Biochem Med"));
+    assert_eq!(journal.journal_issue().volume(), Some("13"));
+    assert_eq!(journal.journal_issue().issue(), Some("2"));
+    assert_eq!(
+        summarize_date(Some(journal.journal_issue().pubblication_date())),
+        Some((Some(1975), Some("Jun"), None))
+    );
+
+    assert_eq!(
+        article.title(),
+        Some("Formate assay in body fluids: application in methanol poisoning.")
+    );
+    assert_eq!(article.vernacular_title(), None);
+    assert_eq!(article.languages(), ["eng"]);
+    assert!(article.abstract_text().is_none());
+    assert!(article.other_abstract_texts().is_empty());
+
+    let pagination = article.pagination().unwrap();
+    assert_eq!(pagination.medline_pgn(), Some("117-26"));
+    assert_eq!(pagination.start_page(), Some("117"));
+    assert_eq!(pagination.end_page(), Some("126".to_string()));
+
+    assert_eq!(
+        article
+            .chemical_list()
+            .iter()
+            .map(|chemical| (
+                chemical.registry_number(),
+                chemical.code(),
+                chemical.name_of_substance()
+            ))
+            .collect::<Vec<_>>(),
+        [
+            ("0", "D005561", "Formates"),
+            ("Y4S76JWI15", "D000432", "Methanol")
+        ]
+    );
+    assert_eq!(
+        article
+            .mesh_list()
+            .iter()
+            .map(summarize_mesh)
+            .collect::<Vec<_>>(),
+        [
+            (
+                "D000445",
+                "Aldehyde Oxidoreductases",
+                false,
+                vec![("Q000378", "metabolism", false)]
+            ),
+            ("D000818", "Animals", false, vec![]),
+            (
+                "D005561",
+                "Formates",
+                false,
+                vec![("Q000097", "blood", true), ("Q000506", "poisoning", false)]
+            ),
+        ]
+    );
+    assert!(article.gene_symbol_list().is_empty());
+    assert!(article.suppl_mesh_list().is_empty());
+    assert!(article.references().is_empty());
+    assert!(article.keywords().is_empty());
+    assert!(article.extras().is_empty());
+}
+
+#[test]
+fn test_synthetic_article() {
+    let articles = parse_single_pubmed(fixture("pubmed23n0002.xml.gz")).unwrap();
+    assert_eq!(articles.len(), 1);
+    let article = &articles[0];
+
+    assert_eq!(article.pubmed_id(), 100);
+    assert_eq!(article.pmid_version(), 2);
+    assert_eq!(article.indexing_method(), Some("Automated"));
+    assert_eq!(article.citation_subsets(), ["IM", "S"]);
+    assert_eq!(
+        summarize_ids(article.article_ids()),
+        [
+            ("pubmed", "100"),
+            ("doi", "10.5555/FIXTURE.100"),
+            ("pmc", "PMC100"),
+            ("pii", "e100")
+        ]
+    );
+    assert_eq!(
+        summarize_ids(article.elocation_ids()),
+        [("pii", "e100"), ("doi", "10.5555/fixture.100")]
+    );
+    assert_eq!(
+        article.title(),
+        Some("Parsing <b>every</b> builder & its edge cases.")
+    );
+    assert_eq!(
+        article.vernacular_title(),
+        Some("Analyse de chaque constructeur.")
+    );
+    assert_eq!(article.languages(), ["eng", "fre"]);
+    assert_eq!(
+        article.abstract_text().unwrap().text(),
+        "Fixtures make tests reproducible. Every builder is covered."
+    );
+    let other_abstract = &article.other_abstract_texts()[0];
+    assert_eq!(other_abstract.text(), "Chaque constructeur est couvert.");
+    assert_eq!(other_abstract.abstract_type(), Some("Publisher"));
+    assert_eq!(other_abstract.language(), Some("fre"));
+
+    let pagination = article.pagination().unwrap();
+    assert_eq!(pagination.start_page(), Some("e100"));
+    assert_eq!(pagination.end_page(), None);
+
+    assert_eq!(article.gene_symbol_list(), ["BRCA1", "TP53"]);
+    let suppl_mesh = &article.suppl_mesh_list()[0];
+    assert_eq!(
+        (suppl_mesh.code(), suppl_mesh.name(), suppl_mesh.mesh_type()),
+        ("C000001", "Fixture syndrome", "Disease")
+    );
+    assert_eq!(
+        article
+            .mesh_list()
+            .iter()
+            .map(summarize_mesh)
+            .collect::<Vec<_>>(),
+        [("D006801", "Humans", true, vec![])]
+    );
+    // The NOTNLM keywords are not part of the parsed keywords.
+    assert_eq!(
+        article
+            .keywords()
+            .iter()
+            .map(|keyword| (keyword.name(), keyword.is_major_topic()))
+            .collect::<Vec<_>>(),
+        [("fixtures", true), ("parsing", false)]
+    );
+    assert_eq!(
+        article
+            .references()
+            .iter()
+            .map(|reference| (reference.pubmed_id(), reference.doi(), reference.citation()))
+            .collect::<Vec<_>>(),
+        [
+            (
+                Some(1),
+                None,
+                Some("Makar AB, et al. Formate assay in body fluids. Biochem Med. 1975;13:117-26.")
+            ),
+            (
+                None,
+                Some("10.5555/cited"),
+                Some("A reference known only by its DOI.")
+            ),
+            (None, None, Some("A reference without identifiers.")),
+        ]
+    );
+}
+
+#[test]
+fn test_synthetic_book_article_and_deletions() {
+    let events = PubmedReader::from_path(&fixture("pubmed23n0002.xml.gz"))
+        .unwrap()
+        .collect::<Result<Vec<PubmedEvent>, String>>()
+        .unwrap();
+    assert_eq!(events.len(), 4);
+
+    let book_article = match &events[1] {
+        PubmedEvent::BookArticle(book_article) => book_article,
+        _ => panic!("Expected a book article."),
+    };
+    assert_eq!(book_article.pubmed_id(), 200);
+    assert_eq!(
+        summarize_ids(book_article.article_ids()),
+        [("bookaccession", "NBK200"), ("pubmed", "200")]
+    );
+    assert_eq!(book_article.book().title(), Some("Fixtures Handbook"));
+    assert_eq!(book_article.book().publisher_name(), Some("Fixture Press"));
+    assert_eq!(
+        book_article.book().publisher_location(),
+        Some("Bethesda (MD)")
+    );
+    assert_eq!(
+        summarize_date(book_article.book().pubblication_date()),
+        Some((Some(2010), None, None))
+    );
+    assert_eq!(book_article.location_label(), Some("Chapter 1"));
+    assert_eq!(book_article.title(), Some("Writing fixtures"));
+    assert_eq!(book_article.sections(), ["Introduction"]);
+    assert_eq!(
+        summarize_date(book_article.contribution_date()),
+        Some((Some(2010), Some("01"), Some(1)))
+    );
+
+    let deletions = events[2..]
+        .iter()
+        .map(|event| match event {
+            PubmedEvent::Deletion(deletion) => (deletion.pubmed_id(), deletion.version()),
+            _ => panic!("Expected a deletion."),
+        })
+        .collect::<Vec<_>>();
+    assert_eq!(deletions, [(300, 1), (301, 1)]);
+}
+
 #[test]
 fn test_pubmed_parser() {
+    let output_directory = std::env::temp_dir().join("pubmed_parser_test_pubmed_parser");
+
     pubmed_parser::parse_pubmed(
-        "../data/"
+        &fixture(""),
+        &GraphExportConfig::new().with_output_directory(&output_directory),
+    )
+    .unwrap();
+
+    assert_eq!(
+        std::fs::read_to_string(output_directory.join("nodes.tsv")).unwrap(),
+        std::fs::read_to_string(fixture("expected_nodes.tsv")).unwrap()
+    );
+    assert_eq!(
+        std::fs::read_to_string(output_directory.join("edges.tsv")).unwrap(),
+        std::fs::read_to_string(fixture("expected_edges.tsv")).unwrap()
     );
 }
@@ -0,0 +1,157 @@
+extern crate pubmed_parser;
+mod common;
+
+use pubmed_parser::*;
+
+fn fixtures() -> String {
+    format!("{}/tests/fixtures/pubmed", env!("CARGO_MANIFEST_DIR"))
+}
+
+fn titles(articles: &[Article]) -> Vec<(u32, String)> {
+    articles
+        .iter()
+        .map(|article| {
+            (
+                article.pubmed_id(),
+                article.title().unwrap_or_default().to_string(),
+            )
+        })
+        .collect()
+}
+
+/// Returns the distinct checkpoints of the rows of an index file.
+fn checkpoints(path: &std::path::Path) -> std::collections::BTreeSet<u64> {
+    std::fs::read_to_string(path)
+        .unwrap()
+        .lines()
+        .skip(1)
+        .map(|line| line.split('\t').nth(3).unwrap().parse().unwrap())
+        .collect()
+}
+
+#[test]
+fn test_get_article_from_the_fixtures() {
+    let index = PubmedIndex::build(&FileSource::from_directory(&fixtures()).unwrap()).unwrap();
+    // Book articles are not indexed.
+    assert_eq!(index.pubmed_ids(), [1, 100]);
+
+    for name in ["pubmed23n0001.xml.gz", "pubmed23n0002.xml.gz"] {
+        let path = format!("{}/{}", fixtures(), name);
+        let expected = parse_single_pubmed(path.clone()).unwrap().remove(0);
+        assert_eq!(index.file(expected.pubmed_id()), Some(path.as_str()));
+        assert_eq!(
+            index.get_article(expected.pubmed_id()).unwrap(),
+            Some(expected)
+        );
+    }
+    let xml = index.get_xml(1).unwrap().unwrap();
+    assert!(xml.starts_with("<PubmedArticle>"));
+    assert!(xml.ends_with("</PubmedArticle>"));
+
+    assert!(!index.contains(200));
+    assert_eq!(index.get_article(200).unwrap(), None);
+    assert_eq!(index.get_xml(300).unwrap(), None);
+}
+
+#[test]
+fn test_index_is_written_and_read() {
+    let directory = common::fixture_directory("pubmed_index_file");
+    let path = directory.join("index.tsv");
+    let index = PubmedIndex::build(&FileSource::from_directory(&fixtures()).unwrap()).unwrap();
+    index.write(path.to_str().unwrap()).unwrap();
+
+    let read = PubmedIndex::from_path(path.to_str().unwrap()).unwrap();
+    assert_eq!(read.pubmed_ids(), index.pubmed_ids());
+    for pubmed_id in index.pubmed_ids() {
+        assert_eq!(read.file(pubmed_id), index.file(pubmed_id));
+        assert_eq!(
+            read.get_article(pubmed_id).unwrap(),
+            index.get_article(pubmed_id).unwrap()
+        );
+    }
+    // The baseline files are made of a single gzip member.
+    assert_eq!(checkpoints(&path).into_iter().collect::<Vec<_>>(), [0]);
+
+    std::fs::write(&path, "pubmed_id\tfile\n1\tpubmed23n0001.xml.gz\n").unwrap();
+    assert!(PubmedIndex::from_path(path.to_str().unwrap()).is_err());
+    assert!(PubmedIndex::from_path("missing_index.tsv").is_err());
+}
+
+#[test]
+fn test_checkpointed_files() {
+    let directory = common::fixture_directory("pubmed_index_checkpoints");
+    let articles = (1..=25)
+        .map(|pubmed_id| common::article(pubmed_id, 1, &format!("Article {}", pubmed_id)))
+        .collect::<Vec<_>>();
+    let original = directory.join("original.xml.gz");
+    common::write_gz(
+        &original,
+        &common::pubmed_article_set(&articles.iter().map(String::as_str).collect::<Vec<_>>()),
+    );
+    let checkpointed = directory.join("pubmed23n0001.xml.gz");
+    write_checkpointed(
+        original.to_str().unwrap(),
+        checkpointed.to_str().unwrap(),
+        4,
+    )
+    .unwrap();
+
+    // The checkpointed file is still read as a whole by the parser.
+    let expected = parse_single_pubmed(original.display().to_string()).unwrap();
+    assert_eq!(
+        parse_single_pubmed(checkpointed.display().to_string()).unwrap(),
+        expected
+    );
+
+    let index = PubmedIndex::build(&FileSource::from_files(vec![checkpointed
+        .display()
+        .to_string()]))
+    .unwrap();
+    assert_eq!(index.len(), 25);
+    for article in expected {
+        assert_eq!(
+            titles(&[index.get_article(article.pubmed_id()).unwrap().unwrap()]),
+            titles(&[article])
+        );
+    }
+
+    let path = directory.join("index.tsv");
+    index.write(path.to_str().unwrap()).unwrap();
+    assert_eq!(checkpoints(&path).len(), 7);
+}
+
+#[test]
+fn test_updates_replace_and_delete_articles() {
+    let directory = common::fixture_directory("pubmed_index_updates");
+    std::fs::write(
+        directory.join("pubmed23n0003.xml"),
+        common::pubmed_article_set(&[
+            &common::article(1, 2, "Updated"),
+            &common::delete_citation(&[(100, 2)]),
+        ]),
+    )
+    .unwrap();
+    std::fs::write(
+        directory.join("pubmed23n0004.xml"),
+        common::pubmed_article_set(&[&common::article(1, 1, "Older version")]),
+    )
+    .unwrap();
+
+    let source = FileSource::from_directory(&fixtures())
+        .unwrap()
+        .with_source(FileSource::from_input(directory.to_str().unwrap()).unwrap());
+    let index = PubmedIndex::build(&source).unwrap();
+    assert_eq!(index.pubmed_ids(), [1]);
+    assert_eq!(
+        titles(&[index.get_article(1).unwrap().unwrap()]),
+        [(1, "Updated".to_string())]
+    );
+
+    // Reading a file changed since it was indexed is an error.
+    std::fs::write(
+        directory.join("pubmed23n0003.xml"),
+        common::pubmed_article_set(&[]),
+    )
+    .unwrap();
+    assert!(index.get_article(1).is_err());
+}
@@ -0,0 +1,119 @@
+extern crate pubmed_parser;
+mod common;
+
+use pubmed_parser::*;
+use std::io::Cursor;
+
+fn fixtures() -> String {
+    format!("{}/tests/fixtures/pubmed", env!("CARGO_MANIFEST_DIR"))
+}
+
+/// Returns the PMIDs of the fixture articles found by the query.
+fn search(query: &str) -> Vec<u32> {
+    let filter = parse_query(query).unwrap();
+    ["pubmed23n0001.xml.gz", "pubmed23n0002.xml.gz"]
+        .iter()
+        .flat_map(|name| parse_single_pubmed(format!("{}/{}", fixtures(), name)).unwrap())
+        .filter(|article| filter.matches(article))
+        .map(|article| article.pubmed_id())
+        .collect()
+}
+
+#[test]
+fn test_parse_query() {
+    assert_eq!(
+        parse_query("\"neoplasms\"[MeSH Major Topic] AND 2015:2020[dp] AND review[pt]").unwrap(),
+        ArticleFilter::mesh_major_topic(&["neoplasms"])
+            .and(ArticleFilter::publication_years(Some(2015), Some(2020)))
+            .and(ArticleFilter::publication_types(&["review"]))
+    );
+    assert_eq!(
+        parse_query("(cancer [tiab] OR tumor*) NOT english[la]").unwrap(),
+        ArticleFilter::title_abstract(&["cancer"])
+            .or(ArticleFilter::title_abstract(&["tumor*"]))
+            .and(ArticleFilter::languages(&["eng"]).negate())
+    );
+    assert_eq!(
+        parse_query("2015/06/01:[dp] \"Biochem Med\"[ta]").unwrap(),
+        ArticleFilter::publication_years(Some(2015), None)
+            .and(ArticleFilter::journals(&["Biochem Med"]))
+    );
+}
+
+#[test]
+fn test_operators_are_applied_from_left_to_right() {
+    assert_eq!(
+        parse_query("a OR b AND c").unwrap(),
+        ArticleFilter::title_abstract(&["a"])
+            .or(ArticleFilter::title_abstract(&["b"]))
+            .and(ArticleFilter::title_abstract(&["c"]))
+    );
+    assert_eq!(
+        parse_query("a OR (b AND c)").unwrap(),
+        ArticleFilter::title_abstract(&["a"])
+            .or(ArticleFilter::title_abstract(&["b"]).and(ArticleFilter::title_abstract(&["c"])))
+    );
+}
+
+#[test]
+fn test_search_the_fixtures() {
+    assert_eq!(search("humans[majr]"), [100]);
+    assert_eq!(search("formates[mh] OR D006801[mh]"), [1, 100]);
+    assert_eq!(search("formates[majr] AND 1970:1980[dp]"), [1]);
+    assert_eq!(search("1975[dp]"), [1]);
+    assert_eq!(search("\"journal article\"[pt] NOT french[la]"), [1]);
+    assert_eq!(
+        search("\"Research Support, U.S. Gov't, P.H.S.\"[Publication Type]"),
+        [1]
+    );
+    assert_eq!(
+        search("\"biochemical medicine\"[Journal] OR 1234-5678[ta]"),
+        [1, 100]
+    );
+
+    assert_eq!(search("\"methanol poisoning\"[tiab]"), [1]);
+    assert!(search("\"poisoning methanol\"[tiab]").is_empty());
+    assert_eq!(search("reproduc*[tiab]"), [100]);
+    assert!(search("reproduc[tiab]").is_empty());
+    // Other abstracts and keywords are searched too.
+    assert_eq!(search("constructeur"), [100]);
+    assert_eq!(search("fixtures[Title/Abstract] parsing"), [100]);
+}
+
+#[test]
+fn test_search_the_publication_date_of_a_medline_date() {
+    let xml = common::pubmed_article_set(&[&common::article(5, 1, "Winter issue").replace(
+        "<Year>2020</Year>",
+        "<MedlineDate>1998 Dec-1999 Jan</MedlineDate>",
+    )]);
+    let article = match PubmedReader::new(Cursor::new(xml), "medline date").next() {
+        Some(Ok(PubmedEvent::Article(article))) => article,
+        _ => panic!("Expected an article."),
+    };
+    for query in ["1998[dp]", "1990:1998[dp]", "1998/12[Publication Date]"] {
+        assert!(parse_query(query).unwrap().matches(&article), "{}", query);
+    }
+    assert!(!parse_query("1999[dp]").unwrap().matches(&article));
+}
+
+#[test]
+fn test_invalid_queries() {
+    for query in [
+        "",
+        "cancer[au]",
+        "\"cancer",
+        "cancer[tiab",
+        "cancer]",
+        "(cancer OR tumor",
+        "cancer OR tumor)",
+        "cancer AND",
+        "AND cancer",
+        "()",
+        "[mh]",
+        "twenty[dp]",
+        ":[dp]",
+    ] {
+        let err = parse_query(query).unwrap_err();
+        assert!(err.starts_with("Unable to parse"), "{}", err);
+    }
+}
@@ -0,0 +1,184 @@
+extern crate pubmed_parser;
+mod common;
+
+use common::generator::*;
+use proptest::prelude::*;
+use pubmed_parser::*;
+use std::io::{Cursor, Write};
+use std::process::{Command, Stdio};
+
+fn fixture(name: &str) -> String {
+    format!(
+        "{}/tests/fixtures/pubmed/{}",
+        env!("CARGO_MANIFEST_DIR"),
+        name
+    )
+}
+
+fn read_articles(xml: Vec<u8>) -> Vec<Article> {
+    PubmedReader::new(Cursor::new(xml), "written")
+        .map(|event| match event.unwrap() {
+            PubmedEvent::Article(article) => *article,
+            _ => panic!("Expected an article."),
+        })
+        .collect()
+}
+
+fn write_articles(articles: &[Article]) -> Vec<u8> {
+    let mut writer = PubmedWriter::new(Vec::new()).unwrap();
+    for article in articles {
+        writer.write_article(article).unwrap();
+    }
+    writer.finish().unwrap()
+}
+
+#[test]
+fn test_fixtures_round_trip() {
+    for name in ["pubmed23n0001.xml.gz", "pubmed23n0002.xml.gz"] {
+        let articles = parse_single_pubmed(fixture(name)).unwrap();
+        assert!(!articles.is_empty());
+        let path = std::env::temp_dir().join(format!("pubmed_parser_written_{}", name));
+        write_pubmed(path.to_str().unwrap(), &articles).unwrap();
+        assert_eq!(
+            parse_single_pubmed(path.to_str().unwrap().to_string()).unwrap(),
+            articles
+        );
+        std::fs::remove_file(path).unwrap();
+    }
+}
+
+#[test]
+fn test_written_document_declares_the_latest_dtd() {
+    let mut reader = PubmedReader::new(Cursor::new(write_articles(&[])), "written");
+    assert!(reader.next().is_none());
+    assert_eq!(reader.dtd_version(), Some(DtdVersion::Pubmed2025));
+}
+
+#[test]
+fn test_text_is_escaped() {
+    let articles = parse_single_pubmed(fixture("pubmed23n0002.xml.gz")).unwrap();
+    let xml = String::from_utf8(write_articles(&articles)).unwrap();
+    assert!(xml.contains(
+        "<ArticleTitle>Parsing &lt;b&gt;every&lt;/b&gt; builder &amp; its edge cases.</ArticleTitle>"
+    ));
+    assert_eq!(read_articles(xml.into_bytes()), articles);
+}
+
+/// Returns the output of xmllint validating the document against the bundled DTD,
+/// or `None` when xmllint is not installed.
+fn validate(xml: &[u8]) -> Option<std::process::Output> {
+    let dtd = format!(
+        "{}/tests/fixtures/dtd/pubmed_250101.dtd",
+        env!("CARGO_MANIFEST_DIR")
+    );
+    let mut child = Command::new("xmllint")
+        .args(["--noout", "--nonet", "--dtdvalid", &dtd, "-"])
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .ok()?;
+    child.stdin.take().unwrap().write_all(xml).unwrap();
+    Some(child.wait_with_output().unwrap())
+}
+
+#[test]
+fn test_written_document_is_valid_against_the_dtd() {
+    let mut articles = Vec::new();
+    for name in ["pubmed23n0001.xml.gz", "pubmed23n0002.xml.gz"] {
+        articles.extend(parse_single_pubmed(fixture(name)).unwrap());
+    }
+    for dtd_version in DtdVersion::all() {
+        let path = format!(
+            "{}/tests/fixtures/dtd/{}",
+            env!("CARGO_MANIFEST_DIR"),
+            dtd_version.file_name().replace(".dtd", ".xml")
+        );
+        articles.extend(
+            PubmedReader::new(Cursor::new(std::fs::read(path).unwrap()), "fixture").filter_map(
+                |event| match event.unwrap() {
+                    PubmedEvent::Article(article) => Some(*article),
+                    _ => None,
+                },
+            ),
+        );
+    }
+    let xml = write_articles(&articles);
+    let output = match validate(&xml) {
+        Some(output) => output,
+        None => {
+            eprintln!("xmllint is not installed, the written document is not validated.");
+            return;
+        }
+    };
+    assert!(
+        output.status.success(),
+        "{}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+    assert_eq!(read_articles(xml), articles);
+}
+
+#[test]
+fn test_article_missing_a_required_element_is_not_written() {
+    let sparse =
+        read_articles(common::pubmed_article_set(&[&common::article(7, 1, "Sparse")]).into_bytes());
+    let mut decoded = String::new();
+    std::io::Read::read_to_string(
+        &mut flate2::read::MultiGzDecoder::new(
+            std::fs::File::open(fixture("pubmed23n0002.xml.gz")).unwrap(),
+        ),
+        &mut decoded,
+    )
+    .unwrap();
+    let without_status = PubmedReader::new(
+        Cursor::new(decoded.replace(" Status=\"MEDLINE\"", "")),
+        "without status",
+    )
+    .filter_map(|event| match event.unwrap() {
+        PubmedEvent::Article(article) => Some(*article),
+        _ => None,
+    })
+    .collect::<Vec<_>>();
+    assert_eq!(without_status[0].status(), None);
+
+    for (article, element) in [
+        (&sparse[0], "Pagination or ELocationID"),
+        (&without_status[0], "MedlineCitation Status"),
+    ] {
+        let mut writer = PubmedWriter::new(Vec::new()).unwrap();
+        assert_eq!(
+            writer.write_article(article),
+            Err(format!(
+                "Unable to write the article {}: its {} is missing.",
+                article.pubmed_id(),
+                element
+            ))
+        );
+        assert_eq!(writer.finish().unwrap(), write_articles(&[]));
+    }
+}
+
+#[test]
+fn test_written_document_keeps_the_required_elements() {
+    let articles = parse_single_pubmed(fixture("pubmed23n0002.xml.gz")).unwrap();
+    let xml = String::from_utf8(write_articles(&articles)).unwrap();
+    for element in [
+        "<Article PubModel=\"Print-Electronic\">",
+        "<ISSN IssnType=\"Electronic\">1234-5678</ISSN>",
+        "<JournalIssue CitedMedium=\"Internet\">",
+        "<MedlineTA>J Synth Fixtures</MedlineTA>",
+        "<KeywordList Owner=\"KIE\">",
+        "<PublicationStatus>ppublish</PublicationStatus>",
+    ] {
+        assert!(xml.contains(element), "{} is missing from {}", element, xml);
+    }
+}
+
+proptest! {
+    #[test]
+    fn test_generated_articles_round_trip(generated in generated_article()) {
+        let articles = read_articles(generated.xml.into_bytes());
+        prop_assert_eq!(read_articles(write_articles(&articles)), articles);
+    }
+}
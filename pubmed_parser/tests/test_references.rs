@@ -0,0 +1,143 @@
+extern crate pubmed_parser;
+mod common;
+
+use pubmed_parser::*;
+
+const REFERENCE_LIST: &str = r#"    <PubmedData>
+      <ArticleIdList>
+        <ArticleId IdType="pubmed">1</ArticleId>
+      </ArticleIdList>
+      <ReferenceList>
+        <Title>References</Title>
+        <Reference>
+          <Citation>Smith J, et al. Nature. 2000;405:1-2.</Citation>
+          <ArticleIdList>
+            <ArticleId IdType="doi">10.1038/35011000</ArticleId>
+            <ArticleId IdType="pubmed">10830948</ArticleId>
+          </ArticleIdList>
+        </Reference>
+        <Reference>
+          <Citation>Doe A. Cell. 2019;1:3-4.</Citation>
+          <ArticleIdList>
+            <ArticleId IdType="doi">10.1016/j.cell.2019.01.001</ArticleId>
+            <ArticleId IdType="pmc">PMC6400000</ArticleId>
+          </ArticleIdList>
+        </Reference>
+        <Reference>
+          <Citation>Roe B. Science. 2018;2:5-6.</Citation>
+          <ArticleIdList>
+            <ArticleId IdType="pmc">PMC6300000</ArticleId>
+          </ArticleIdList>
+        </Reference>
+        <Reference>
+          <Citation>World Health Organization. Annual report
+          on tuberculosis. Geneva; 2017.</Citation>
+        </Reference>
+      </ReferenceList>
+    </PubmedData>
+"#;
+
+fn article() -> Article {
+    let xml = common::article(1, 1, "Citing").replace(
+        "    </MedlineCitation>\n",
+        &format!("    </MedlineCitation>\n{}", REFERENCE_LIST),
+    );
+    let path = common::write_gz_fixture("references", &common::pubmed_article_set(&[&xml]));
+    parse_single_pubmed(path).unwrap().pop().unwrap()
+}
+
+fn edges(article: &Article, options: &ExportOptions) -> Vec<String> {
+    article
+        .to_edges_with_options(options)
+        .iter()
+        .filter(|edge| edge.edge_type() == "Citation")
+        .map(|edge| edge.object().to_string())
+        .collect()
+}
+
+#[test]
+fn test_references() {
+    let article = article();
+    let references = article.references();
+    assert_eq!(references.len(), 4);
+
+    assert_eq!(
+        references[0].citation(),
+        Some("Smith J, et al. Nature. 2000;405:1-2.")
+    );
+    assert_eq!(references[0].pubmed_id(), Some(10830948));
+    assert_eq!(references[0].doi(), Some("10.1038/35011000"));
+
+    assert_eq!(references[1].pubmed_id(), None);
+    assert_eq!(references[1].doi(), Some("10.1016/j.cell.2019.01.001"));
+    assert_eq!(references[1].pmcid(), Some("PMC6400000"));
+
+    assert_eq!(references[2].pmcid(), Some("PMC6300000"));
+
+    assert_eq!(
+        references[3].citation(),
+        Some("World Health Organization. Annual report on tuberculosis. Geneva; 2017.")
+    );
+    assert!(references[3].article_ids().is_empty());
+}
+
+#[test]
+fn test_reference_export() {
+    let article = article();
+
+    assert_eq!(
+        edges(&article, &ExportOptions::default()),
+        ["PMID:10830948"]
+    );
+    assert_eq!(
+        edges(&article, &ExportOptions::new().with_references_by_doi(true)),
+        ["PMID:10830948", "DOI:10.1016/j.cell.2019.01.001"]
+    );
+    assert_eq!(
+        edges(
+            &article,
+            &ExportOptions::new().with_references_by_pmcid(true)
+        ),
+        ["PMID:10830948", "PMCID:PMC6400000", "PMCID:PMC6300000"]
+    );
+
+    let options = ExportOptions::new()
+        .with_references_by_doi(true)
+        .with_references_by_pmcid(true)
+        .with_unresolved_references(true);
+    assert_eq!(
+        edges(&article, &options),
+        [
+            "PMID:10830948",
+            "DOI:10.1016/j.cell.2019.01.001",
+            "PMCID:PMC6300000",
+            "REF:1:4"
+        ]
+    );
+    let nodes = article.to_nodes_with_options(&options);
+    let reference_nodes = nodes
+        .iter()
+        .filter(|node| node.node_type() != "Paper")
+        .map(|node| (node.node_name(), node.node_type(), node.description()))
+        .collect::<Vec<_>>();
+    assert_eq!(
+        reference_nodes,
+        [
+            (
+                "DOI:10.1016/j.cell.2019.01.001",
+                "Reference",
+                "Doe A. Cell. 2019;1:3-4."
+            ),
+            (
+                "PMCID:PMC6300000",
+                "Reference",
+                "Roe B. Science. 2018;2:5-6."
+            ),
+            (
+                "REF:1:4",
+                "UnresolvedReference",
+                "World Health Organization. Annual report on tuberculosis. Geneva; 2017."
+            ),
+        ]
+    );
+}
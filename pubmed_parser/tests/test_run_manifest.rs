@@ -0,0 +1,142 @@
+extern crate pubmed_parser;
+mod common;
+
+use pubmed_parser::*;
+
+fn fixtures() -> String {
+    format!("{}/tests/fixtures/pubmed", env!("CARGO_MANIFEST_DIR"))
+}
+
+/// Returns the number of lines of a TSV file, without its header.
+fn rows(path: &str) -> u64 {
+    std::fs::read_to_string(path).unwrap().lines().count() as u64 - 1
+}
+
+#[test]
+fn test_input_file_checksum() {
+    let directory = common::fixture_directory("run_manifest_checksum");
+    let path = directory.join("abc.xml");
+    std::fs::write(&path, "abc").unwrap();
+
+    let input = InputFile::from_path(path.to_str().unwrap()).unwrap();
+    assert_eq!(input.path(), path.to_str().unwrap());
+    assert_eq!(input.size(), 3);
+    assert_eq!(
+        input.sha256(),
+        "ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad"
+    );
+    assert!(InputFile::from_path("missing_file.xml").is_err());
+}
+
+#[test]
+fn test_parse_pubmed_writes_the_manifest() {
+    let directory = common::fixture_directory("run_manifest");
+    let config = GraphExportConfig::new()
+        .with_output_directory(&directory)
+        .with_node_types(&["Paper", "Mesh"]);
+    parse_pubmed(&fixtures(), &config).unwrap();
+
+    let manifest = RunManifest::from_path(config.manifest_path().to_str().unwrap()).unwrap();
+    assert_eq!(manifest.crate_version(), env!("CARGO_PKG_VERSION"));
+    assert_eq!(manifest.config(), &config);
+    assert_eq!(manifest.normalization(), &TextNormalization::default());
+    assert!(manifest.arguments().is_empty());
+
+    let names = ["pubmed23n0001.xml.gz", "pubmed23n0002.xml.gz"];
+    assert_eq!(manifest.inputs().len(), names.len());
+    for (input, name) in manifest.inputs().iter().zip(names) {
+        let path = format!("{}/{}", fixtures(), name);
+        assert_eq!(input, &InputFile::from_path(&path).unwrap());
+        assert_eq!(input.size(), std::fs::metadata(&path).unwrap().len());
+        assert_eq!(input.sha256().len(), 64);
+    }
+
+    assert_eq!(
+        manifest.rows().iter().collect::<Vec<_>>(),
+        [
+            (
+                &"edges.tsv".to_string(),
+                &rows(config.edges_path().to_str().unwrap())
+            ),
+            (&"nodes.tsv".to_string(), &9),
+        ]
+    );
+    // RFC 3339 timestamps in UTC sort chronologically.
+    assert!(manifest.started_at().ends_with('Z'));
+    assert!(manifest.finished_at().unwrap() >= manifest.started_at());
+}
+
+#[test]
+fn test_merged_manifest_lists_the_updates() {
+    let directory = common::fixture_directory("run_manifest_merged");
+    let updates = directory.join("updates");
+    std::fs::create_dir_all(&updates).unwrap();
+    let update = updates.join("pubmed23n0003.xml.gz");
+    common::write_gz(
+        &update,
+        &common::pubmed_article_set(&[&common::article(101, 1, "Added by an update")]),
+    );
+    let config = GraphExportConfig::new().with_output_directory(directory.join("graph"));
+    parse_pubmed_merged(&fixtures(), updates.to_str().unwrap(), &config).unwrap();
+
+    let manifest = RunManifest::from_path(config.manifest_path().to_str().unwrap()).unwrap();
+    assert_eq!(
+        manifest
+            .inputs()
+            .iter()
+            .map(|input| input.path())
+            .collect::<Vec<_>>(),
+        [
+            format!("{}/pubmed23n0001.xml.gz", fixtures()),
+            format!("{}/pubmed23n0002.xml.gz", fixtures()),
+            update.display().to_string(),
+        ]
+    );
+    assert_eq!(
+        manifest.rows()["nodes.tsv"],
+        rows(config.nodes_path().to_str().unwrap())
+    );
+}
+
+#[test]
+fn test_manifest_of_a_tar_source_lists_the_archive() {
+    let directory = common::fixture_directory("run_manifest_tar");
+    let archive = directory.join("baseline.tar");
+    let mut builder = tar::Builder::new(std::fs::File::create(&archive).unwrap());
+    builder
+        .append_path_with_name(
+            format!("{}/pubmed23n0001.xml.gz", fixtures()),
+            "pubmed23n0001.xml.gz",
+        )
+        .unwrap();
+    builder.finish().unwrap();
+
+    let source = CombinedSource::new()
+        .with_source(open_source(archive.to_str().unwrap()).unwrap())
+        .with_source(open_source(&format!("{}/pubmed23n0002.xml.gz", fixtures())).unwrap());
+    let manifest = RunManifest::start(&GraphExportConfig::new(), &TextNormalization::all())
+        .with_arguments(vec!["pubmed-parser".to_string(), "parse".to_string()])
+        .with_inputs(&source)
+        .unwrap()
+        .with_rows("articles.jsonl", 2);
+    assert_eq!(
+        manifest
+            .inputs()
+            .iter()
+            .map(|input| input.path().to_string())
+            .collect::<Vec<_>>(),
+        [
+            archive.display().to_string(),
+            format!("{}/pubmed23n0002.xml.gz", fixtures())
+        ]
+    );
+    assert_eq!(manifest.finished_at(), None);
+    assert_eq!(manifest.rows()["articles.jsonl"], 2);
+
+    let path = directory.join("manifest.json");
+    manifest.write(&path).unwrap();
+    assert_eq!(
+        RunManifest::from_path(path.to_str().unwrap()).unwrap(),
+        manifest
+    );
+}
@@ -0,0 +1,104 @@
+extern crate pubmed_parser;
+mod common;
+
+use pubmed_parser::*;
+
+const ARTICLE: &str = r#"<PubmedArticle>
+    <MedlineCitation Status="MEDLINE" Owner="NLM">
+      <PMID Version="1">100</PMID>
+      <Article PubModel="Print">
+        <Journal>
+          <ISSN IssnType="Print">0006-2944</ISSN>
+          <JournalIssue CitedMedium="Print">
+            <Volume>13</Volume>
+            <PubDate>
+              <Year>1975</Year>
+            </PubDate>
+          </JournalIssue>
+          <Title>Research &amp; development</Title>
+        </Journal>
+        <ArticleTitle>Effects of &#x3b1;-tocopherol on  &lt;i&gt;E.&#160;coli&lt;/i&gt;&#7;</ArticleTitle>
+        <Abstract>
+          <AbstractText>The ﬁrst   line
+          and &quot;the second&quot; one.</AbstractText>
+        </Abstract>
+      </Article>
+      <ChemicalList>
+        <Chemical>
+          <RegistryNumber>0</RegistryNumber>
+          <NameOfSubstance UI="D000001">Salts &amp; esters</NameOfSubstance>
+        </Chemical>
+      </ChemicalList>
+      <MeshHeadingList>
+        <MeshHeading>
+          <DescriptorName UI="D000002" MajorTopicYN="N">Ca&#178;&#x207a; signaling</DescriptorName>
+        </MeshHeading>
+      </MeshHeadingList>
+    </MedlineCitation>
+  </PubmedArticle>
+"#;
+
+#[test]
+fn test_entities_are_always_decoded() {
+    let path = common::write_gz_fixture("entities", &common::pubmed_article_set(&[ARTICLE]));
+    let articles = parse_single_pubmed(path).unwrap();
+    assert_eq!(articles.len(), 1);
+    let article = &articles[0];
+    assert_eq!(
+        article.title(),
+        Some("Effects of α-tocopherol on  <i>E.\u{a0}coli</i>\u{7}")
+    );
+    assert_eq!(article.journal().title(), "Research & development");
+    assert_eq!(
+        article.abstract_text().unwrap().text(),
+        "The ﬁrst   line and \"the second\" one."
+    );
+    assert_eq!(
+        article.chemical_list()[0].name_of_substance(),
+        "Salts & esters"
+    );
+    assert_eq!(article.mesh_list()[0].descriptor().name(), "Ca²⁺ signaling");
+    assert!(article
+        .to_nodes()
+        .iter()
+        .all(|node| !node.description().contains("&amp;")));
+}
+
+#[test]
+fn test_optional_normalization() {
+    let path = common::write_gz_fixture("normalization", &common::pubmed_article_set(&[ARTICLE]));
+    let articles = parse_single_pubmed_with_normalization(path, &TextNormalization::all()).unwrap();
+    let article = &articles[0];
+    assert_eq!(
+        article.title(),
+        Some("Effects of α-tocopherol on <i>E. coli</i>")
+    );
+    assert_eq!(
+        article.abstract_text().unwrap().text(),
+        "The first line and \"the second\" one."
+    );
+    assert_eq!(article.mesh_list()[0].descriptor().name(), "Ca2+ signaling");
+}
+
+#[test]
+fn test_normalization_steps() {
+    let text = "A\tB\u{0}  ﬁ\u{2003}C ";
+    assert_eq!(TextNormalization::new().normalize(text), text);
+    assert_eq!(
+        TextNormalization::new()
+            .with_remove_control_characters(true)
+            .normalize(text),
+        "A B  ﬁ\u{2003}C "
+    );
+    assert_eq!(
+        TextNormalization::new().with_nfkc(true).normalize(text),
+        "A\tB\u{0}  fi C "
+    );
+    assert_eq!(
+        TextNormalization::new()
+            .with_collapse_whitespace(true)
+            .normalize(text),
+        "A B\u{0} ﬁ C"
+    );
+    assert_eq!(TextNormalization::all().normalize(text), "A B fi C");
+}
@@ -0,0 +1,80 @@
+extern crate pubmed_parser;
+mod common;
+
+use pubmed_parser::*;
+use std::io::BufReader;
+
+const DELETIONS: &str = r#"<DeleteCitation>
+<PMID Version="1">30000001</PMID>
+<PMID Version="2">30000002</PMID>
+<PMID>30000003</PMID>
+</DeleteCitation>
+"#;
+
+#[test]
+fn test_deletions_and_versions() {
+    let xml = common::pubmed_article_set(&[
+        &common::article(10, 1, "First"),
+        &common::article(11, 2, "Second"),
+        DELETIONS,
+    ]);
+    let events = PubmedReader::new(BufReader::new(xml.as_bytes()), "update")
+        .collect::<Result<Vec<PubmedEvent>, String>>()
+        .unwrap();
+    assert_eq!(events.len(), 5);
+
+    match &events[0] {
+        PubmedEvent::Article(article) => {
+            assert_eq!(article.pubmed_id(), 10);
+            assert_eq!(article.pmid_version(), 1);
+        }
+        _ => panic!("Expected an article."),
+    }
+    match &events[1] {
+        PubmedEvent::Article(article) => {
+            assert_eq!(article.pubmed_id(), 11);
+            assert_eq!(article.pmid_version(), 2);
+            assert_eq!(article.title(), Some("Second"));
+        }
+        _ => panic!("Expected an article."),
+    }
+    let deletions = events[2..]
+        .iter()
+        .map(|event| match event {
+            PubmedEvent::Deletion(deletion) => (deletion.pubmed_id(), deletion.version()),
+            _ => panic!("Expected a deletion."),
+        })
+        .collect::<Vec<(u32, u16)>>();
+    assert_eq!(deletions, [(30000001, 1), (30000002, 2), (30000003, 1)]);
+}
+
+#[test]
+fn test_parse_single_pubmed_skips_deletions() {
+    let path = common::write_gz_fixture(
+        "update_file",
+        &common::pubmed_article_set(&[DELETIONS, &common::article(12, 1, "Only")]),
+    );
+    let articles = parse_single_pubmed(path).unwrap();
+    assert_eq!(articles.len(), 1);
+    assert_eq!(articles[0].pubmed_id(), 12);
+}
+
+#[test]
+fn test_reader_reports_errors_with_location() {
+    let xml = common::pubmed_article_set(&[
+        &common::article(13, 1, "Broken").replace(
+            "<PMID Version=\"1\">13</PMID>",
+            "<PMID Version=\"x\">13</PMID>",
+        ),
+        &common::article(14, 1, "Recovered"),
+    ]);
+    let events = PubmedReader::new(BufReader::new(xml.as_bytes()), "broken.xml")
+        .collect::<Vec<Result<PubmedEvent, String>>>();
+    assert_eq!(events.len(), 2);
+    let error = events[0].as_ref().err().unwrap();
+    assert!(error.contains("broken.xml:"), "{}", error);
+    match events[1].as_ref().unwrap() {
+        PubmedEvent::Article(article) => assert_eq!(article.pubmed_id(), 14),
+        _ => panic!("Expected an article."),
+    }
+}